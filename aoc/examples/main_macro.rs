@@ -0,0 +1,23 @@
+// Checks that aoc::main! expands to a working fn main in a bin crate.
+use aoc::Problem;
+
+struct Length;
+impl Problem for Length {
+    type Solution1 = usize;
+    type Solution2 = usize;
+    type Parsed = String;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input.trim_end().to_string()
+    }
+
+    fn part_1(input: &Self::Parsed) -> Self::Solution1 {
+        input.len()
+    }
+
+    fn part_2(input: &Self::Parsed) -> Self::Solution2 {
+        input.lines().count()
+    }
+}
+
+aoc::main!(Length, "../samples/mixed.txt");
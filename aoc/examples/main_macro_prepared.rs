@@ -0,0 +1,39 @@
+// Checks that aoc::main!(prepared ..) expands to a working fn main for a PreparedProblem.
+use aoc::{PreparedProblem, Problem};
+
+struct Words;
+impl Problem for Words {
+    type Solution1 = usize;
+    type Solution2 = usize;
+    type Parsed = String;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input.trim_end().to_string()
+    }
+
+    fn part_1(input: &Self::Parsed) -> Self::Solution1 {
+        Self::part_1_prepared(&Self::prepare(input))
+    }
+
+    fn part_2(input: &Self::Parsed) -> Self::Solution2 {
+        Self::part_2_prepared(&Self::prepare(input))
+    }
+}
+
+impl PreparedProblem for Words {
+    type Intermediate = Vec<String>;
+
+    fn prepare(input: &Self::Parsed) -> Self::Intermediate {
+        input.split_whitespace().map(String::from).collect()
+    }
+
+    fn part_1_prepared(words: &Self::Intermediate) -> Self::Solution1 {
+        words.len()
+    }
+
+    fn part_2_prepared(words: &Self::Intermediate) -> Self::Solution2 {
+        words.iter().map(String::len).max().unwrap_or(0)
+    }
+}
+
+aoc::main!(prepared Words, "../samples/mixed.txt");
@@ -0,0 +1,254 @@
+/// Allocations made while running some code, see [measure].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AllocStats {
+    pub allocations: usize,
+    pub bytes: usize,
+}
+
+impl std::fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} allocations, {} bytes", self.allocations, self.bytes)
+    }
+}
+
+/// Runs `f`, returning its result and the allocations it made on the current thread.
+///
+/// The stats are only counted with the `count-allocs` feature, and are `None` without it.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    #[cfg(feature = "count-allocs")]
+    {
+        let before = counting::current();
+        let result = f();
+        let after = counting::current();
+        let stats = AllocStats {
+            allocations: after.allocations - before.allocations,
+            bytes: after.bytes - before.bytes,
+        };
+        (result, Some(stats))
+    }
+    #[cfg(not(feature = "count-allocs"))]
+    {
+        (f(), None)
+    }
+}
+
+/// Runs `f`, returning its result and the peak memory in bytes used while it ran.
+///
+/// With the `count-allocs` feature this is the high-water mark of heap memory allocated by the
+/// current thread, above what was already live when `f` started. Otherwise, with the `mem-stats`
+/// feature on Linux, it's the peak resident set size of the whole process, reset before `f`
+/// runs where the kernel allows it. Without either feature it's `None`.
+pub fn measure_peak<T>(f: impl FnOnce() -> T) -> (T, Option<usize>) {
+    #[cfg(feature = "count-allocs")]
+    {
+        let base = counting::reset_peak();
+        let result = f();
+        (result, Some(counting::peak() - base))
+    }
+    #[cfg(all(not(feature = "count-allocs"), feature = "mem-stats", target_os = "linux"))]
+    {
+        rss::reset_peak();
+        let result = f();
+        (result, rss::peak())
+    }
+    #[cfg(not(any(feature = "count-allocs", all(feature = "mem-stats", target_os = "linux"))))]
+    {
+        (f(), None)
+    }
+}
+
+/// Formats a number of bytes with a binary unit, e.g. `1.50 MiB`.
+///
+/// # Example
+///
+/// ```
+/// # use aoc::allocs::format_bytes;
+/// assert_eq!(format_bytes(512), "512 B");
+/// assert_eq!(format_bytes(3 << 19), "1.50 MiB");
+/// ```
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.2} {}", UNITS[unit])
+}
+
+#[cfg(all(not(feature = "count-allocs"), feature = "mem-stats", target_os = "linux"))]
+mod rss {
+    /// Resets the process's peak resident set size to its current size. Needs Linux 4.0, and
+    /// is ignored if `/proc` isn't writable.
+    pub(super) fn reset_peak() {
+        let _ = std::fs::write("/proc/self/clear_refs", "5");
+    }
+
+    /// Returns the process's peak resident set size, from `VmHWM` in `/proc/self/status`.
+    pub(super) fn peak() -> Option<usize> {
+        let status = std::fs::read_to_string("/proc/self/status").ok()?;
+        let kib = status.lines().find_map(|line| line.strip_prefix("VmHWM:"))?;
+        let kib: usize = kib.trim().strip_suffix("kB")?.trim().parse().ok()?;
+        Some(kib * 1024)
+    }
+}
+
+#[cfg(feature = "count-allocs")]
+mod counting {
+    use std::{alloc::{GlobalAlloc, Layout, System}, cell::Cell};
+
+    use super::AllocStats;
+
+    // Counted per thread, so parts running concurrently don't see each other's allocations
+    thread_local! {
+        static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+        static BYTES: Cell<usize> = const { Cell::new(0) };
+        // Bytes currently allocated, and the most that have been since the last reset
+        static LIVE: Cell<usize> = const { Cell::new(0) };
+        static PEAK: Cell<usize> = const { Cell::new(0) };
+    }
+
+    pub(super) fn current() -> AllocStats {
+        AllocStats { allocations: ALLOCATIONS.get(), bytes: BYTES.get() }
+    }
+
+    /// Resets the high-water mark to the bytes currently live, and returns them.
+    pub(super) fn reset_peak() -> usize {
+        let live = LIVE.get();
+        PEAK.set(live);
+        live
+    }
+
+    pub(super) fn peak() -> usize {
+        PEAK.get()
+    }
+
+    fn record(size: usize) {
+        // Ignore allocations made while the thread's locals are being torn down
+        let _ = ALLOCATIONS.try_with(|n| n.set(n.get() + 1));
+        let _ = BYTES.try_with(|n| n.set(n.get() + size));
+    }
+
+    fn grow(size: usize) {
+        let _ = LIVE.try_with(|live| {
+            live.set(live.get() + size);
+            let _ = PEAK.try_with(|peak| peak.set(peak.get().max(live.get())));
+        });
+    }
+
+    // Memory freed on another thread than the one that allocated it saturates at zero
+    fn shrink(size: usize) {
+        let _ = LIVE.try_with(|live| live.set(live.get().saturating_sub(size)));
+    }
+
+    /// Wraps the system allocator to count allocations and allocated bytes.
+    struct CountingAllocator;
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            record(layout.size());
+            grow(layout.size());
+            System.alloc(layout)
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            record(layout.size());
+            grow(layout.size());
+            System.alloc_zeroed(layout)
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            record(new_size);
+            match new_size >= layout.size() {
+                true => grow(new_size - layout.size()),
+                false => shrink(layout.size() - new_size),
+            }
+            System.realloc(ptr, layout, new_size)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            shrink(layout.size());
+            System.dealloc(ptr, layout)
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: CountingAllocator = CountingAllocator;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "count-allocs")]
+    fn test_measure_counts_allocations() {
+        let (v, stats) = measure(|| (0..100u64).collect::<Vec<_>>());
+        assert_eq!(v.len(), 100);
+        let stats = stats.unwrap();
+        assert!(stats.allocations > 0);
+        assert!(stats.bytes >= 800, "{stats}");
+
+        let (_, stats) = measure(|| 1 + 1);
+        assert_eq!(stats, Some(AllocStats::default()));
+    }
+
+    #[test]
+    #[cfg(feature = "count-allocs")]
+    fn test_measure_peak() {
+        const MIB: usize = 1 << 20;
+
+        // Freed before the measurement ends, but still the high-water mark
+        let (len, peak) = measure_peak(|| vec![0u8; MIB].len());
+        assert_eq!(len, MIB);
+        assert!(peak.unwrap() >= MIB, "{peak:?}");
+
+        // Memory held across the measurement raises the peak
+        let (held, peak) = measure_peak(|| vec![1u8; 2 * MIB]);
+        assert!(peak.unwrap() >= 2 * MIB, "{peak:?}");
+
+        // The next measurement starts from what's live, so the held Vec doesn't count again
+        let (_, peak) = measure_peak(|| vec![0u8; 1024].len());
+        let peak = peak.unwrap();
+        assert!((1024..MIB).contains(&peak), "{peak}");
+
+        let (_, peak) = measure_peak(|| 1 + 1);
+        assert_eq!(peak, Some(0));
+        drop(held);
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1024), "1.00 KiB");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(410 << 20), "410.00 MiB");
+        assert_eq!(format_bytes(5 << 30), "5.00 GiB");
+    }
+
+    #[test]
+    #[cfg(all(not(feature = "count-allocs"), feature = "mem-stats", target_os = "linux"))]
+    fn test_measure_peak_rss() {
+        let (v, peak) = measure_peak(|| vec![1u8; 1 << 20]);
+        assert!(peak.unwrap() >= v.len(), "{peak:?}");
+    }
+
+    #[test]
+    #[cfg(not(any(feature = "count-allocs", all(feature = "mem-stats", target_os = "linux"))))]
+    fn test_measure_peak_without_feature() {
+        assert_eq!(measure_peak(|| vec![0u8; 1024]).1, None);
+    }
+
+    #[test]
+    #[cfg(not(feature = "count-allocs"))]
+    fn test_measure_without_feature() {
+        let (v, stats) = measure(|| vec![1, 2, 3]);
+        assert_eq!(v, vec![1, 2, 3]);
+        assert_eq!(stats, None);
+    }
+}
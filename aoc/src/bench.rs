@@ -0,0 +1,54 @@
+use std::hint::black_box;
+
+use criterion::Criterion;
+
+use crate::Problem;
+
+/// Registers criterion benchmarks for parsing `input` and for each part of the problem.
+///
+/// The parts are benchmarked against input parsed once up front, so they don't include the
+/// time spent in `parse`. Part 2 is skipped if the problem doesn't implement it.
+pub fn criterion_benches<P: Problem>(c: &mut Criterion, name: &str, input: &str) {
+    let mut group = c.benchmark_group(name);
+    group.bench_function("parse", |b| b.iter(|| P::parse(black_box(input))));
+
+    let parsed = P::parse(input);
+    group.bench_function("part_1", |b| b.iter(|| P::part_1(black_box(&parsed))));
+    if P::has_part_2() {
+        group.bench_function("part_2", |b| b.iter(|| P::part_2(black_box(&parsed))));
+    }
+    group.finish();
+}
+
+/// Generates the body of a day crate's `benches/bench.rs`, benchmarking the problem on the
+/// crate's `src/input.txt` with [criterion_benches]. Needs the `criterion` feature and a
+/// `[[bench]]` target with `harness = false`.
+///
+/// Another input can be given as a path relative to `src/`, like the second argument of
+/// [main!](crate::main!).
+///
+/// ```ignore
+/// use day_17::Day17;
+///
+/// aoc::criterion_day!(Day17);
+/// ```
+#[macro_export]
+macro_rules! criterion_day {
+    ($t:ty) => {
+        $crate::criterion_day!($t, "input.txt");
+    };
+    ($t:ty, $path:literal) => {
+        fn criterion_day(c: &mut $crate::criterion::Criterion) {
+            let path = concat!(env!("CARGO_MANIFEST_DIR"), "/src/", $path);
+            match std::fs::read_to_string(path) {
+                Ok(input) if !input.is_empty() => {
+                    $crate::bench::criterion_benches::<$t>(c, stringify!($t), &input);
+                },
+                _ => eprintln!("Skipping {}: no input at {path}", stringify!($t)),
+            }
+        }
+
+        $crate::criterion::criterion_group!(benches, criterion_day);
+        $crate::criterion::criterion_main!(benches);
+    };
+}
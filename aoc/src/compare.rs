@@ -0,0 +1,251 @@
+//! Checks that two implementations of a day agree, e.g. while refactoring one of them.
+
+use std::{fmt::{Debug, Display}, time::{Duration, Instant}};
+
+use crate::{day_name, Problem};
+
+/// How long two equivalent problems took over the same inputs, see [compare].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comparison {
+    pub first_name: &'static str,
+    pub second_name: &'static str,
+    pub first: Duration,
+    pub second: Duration,
+}
+
+impl Comparison {
+    /// Returns how many times longer the second problem took than the first.
+    pub fn speed_ratio(&self) -> f64 {
+        self.second.as_secs_f64() / self.first.as_secs_f64()
+    }
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} took {:.2?}, {} took {:.2?} ({:.2}x)",
+            self.first_name, self.first, self.second_name, self.second, self.speed_ratio()
+        )
+    }
+}
+
+/// The first answer two problems disagreed on, see [compare].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Divergence {
+    pub first_name: &'static str,
+    pub second_name: &'static str,
+    /// Index of the input in the slice given to [compare].
+    pub input_index: usize,
+    pub part: u8,
+    pub first: String,
+    pub second: String,
+}
+
+impl Display for Divergence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Part {} of input {} differs: {} answered {}, {} answered {}",
+            self.part, self.input_index, self.first_name, self.first, self.second_name, self.second
+        )
+    }
+}
+
+impl std::error::Error for Divergence {}
+
+/// Solves each input with both problems, returning their total times, or the first answer they
+/// disagree on. Part 2 is only compared if both problems implement it.
+pub fn compare<P1, P2>(inputs: &[&str]) -> Result<Comparison, Divergence>
+where
+    P1: Problem,
+    P2: Problem<Solution1 = P1::Solution1, Solution2 = P1::Solution2>,
+    P1::Solution1: PartialEq,
+    P1::Solution2: PartialEq,
+{
+    let mut comparison = Comparison {
+        first_name: day_name::<P1>(),
+        second_name: day_name::<P2>(),
+        first: Duration::ZERO,
+        second: Duration::ZERO,
+    };
+    let divergence = |input_index, part, first: &dyn Debug, second: &dyn Debug| Divergence {
+        first_name: comparison.first_name,
+        second_name: comparison.second_name,
+        input_index,
+        part,
+        first: format!("{first:?}"),
+        second: format!("{second:?}"),
+    };
+    for (i, input) in inputs.iter().enumerate() {
+        let ((first_parsed, second_parsed), (first, second)) = timed(|| P1::parse(input), || P2::parse(input));
+        comparison.first += first;
+        comparison.second += second;
+
+        let ((first_answer, second_answer), (first, second)) =
+            timed(|| P1::part_1(&first_parsed), || P2::part_1(&second_parsed));
+        comparison.first += first;
+        comparison.second += second;
+        if first_answer != second_answer {
+            return Err(divergence(i, 1, &first_answer, &second_answer));
+        }
+
+        if !(P1::has_part_2() && P2::has_part_2()) {
+            continue;
+        }
+        let ((first_answer, second_answer), (first, second)) =
+            timed(|| P1::part_2(&first_parsed), || P2::part_2(&second_parsed));
+        comparison.first += first;
+        comparison.second += second;
+        if first_answer != second_answer {
+            return Err(divergence(i, 2, &first_answer, &second_answer));
+        }
+    }
+    Ok(comparison)
+}
+
+/// Like [compare], but panics on the first divergence. Prints the speed ratio and returns it,
+/// for use in tests.
+///
+/// # Example
+///
+/// ```
+/// # use aoc::{compare::assert_equivalent, NoPart2, Problem};
+/// struct Sum;
+/// impl Problem for Sum {
+///     type Solution1 = u32;
+///     type Solution2 = NoPart2;
+///     type Parsed = Vec<u32>;
+///     fn parse(input: &str) -> Self::Parsed { input.split(',').map(|n| n.parse().unwrap()).collect() }
+///     fn part_1(numbers: &Self::Parsed) -> u32 { numbers.iter().sum() }
+///     fn part_2(_numbers: &Self::Parsed) -> NoPart2 { NoPart2 }
+/// }
+///
+/// struct Fold;
+/// impl Problem for Fold {
+///     type Solution1 = u32;
+///     type Solution2 = NoPart2;
+///     type Parsed = Vec<u32>;
+///     fn parse(input: &str) -> Self::Parsed { Sum::parse(input) }
+///     fn part_1(numbers: &Self::Parsed) -> u32 { numbers.iter().fold(0, |a, b| a + b) }
+///     fn part_2(_numbers: &Self::Parsed) -> NoPart2 { NoPart2 }
+/// }
+///
+/// assert_equivalent::<Sum, Fold>(&["1,2,3", "4"]);
+/// ```
+pub fn assert_equivalent<P1, P2>(inputs: &[&str]) -> Comparison
+where
+    P1: Problem,
+    P2: Problem<Solution1 = P1::Solution1, Solution2 = P1::Solution2>,
+    P1::Solution1: PartialEq,
+    P1::Solution2: PartialEq,
+{
+    let comparison = compare::<P1, P2>(inputs).unwrap_or_else(|e| panic!("{e}"));
+    println!("{comparison}");
+    comparison
+}
+
+fn timed<A, B>(first: impl FnOnce() -> A, second: impl FnOnce() -> B) -> ((A, B), (Duration, Duration)) {
+    let now = Instant::now();
+    let a = first();
+    let first = now.elapsed();
+    let now = Instant::now();
+    let b = second();
+    ((a, b), (first, now.elapsed()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct CountChars;
+    impl Problem for CountChars {
+        type Solution1 = usize;
+        type Solution2 = usize;
+        type Parsed = String;
+
+        fn parse(input: &str) -> Self::Parsed {
+            input.to_string()
+        }
+
+        fn part_1(input: &Self::Parsed) -> Self::Solution1 {
+            input.chars().count()
+        }
+
+        fn part_2(input: &Self::Parsed) -> Self::Solution2 {
+            input.chars().filter(|c| c.is_ascii_digit()).count()
+        }
+    }
+
+    /// Same answers as [CountChars], found a different way.
+    struct CountBytes;
+    impl Problem for CountBytes {
+        type Solution1 = usize;
+        type Solution2 = usize;
+        type Parsed = Vec<u8>;
+
+        fn parse(input: &str) -> Self::Parsed {
+            input.bytes().collect()
+        }
+
+        fn part_1(input: &Self::Parsed) -> Self::Solution1 {
+            input.len()
+        }
+
+        fn part_2(input: &Self::Parsed) -> Self::Solution2 {
+            input.iter().filter(|b| b.is_ascii_digit()).count()
+        }
+    }
+
+    /// Agrees with [CountChars] on ASCII inputs with as many letters as digits.
+    struct CountLetters;
+    impl Problem for CountLetters {
+        type Solution1 = usize;
+        type Solution2 = usize;
+        type Parsed = String;
+
+        fn parse(input: &str) -> Self::Parsed {
+            input.to_string()
+        }
+
+        fn part_1(input: &Self::Parsed) -> Self::Solution1 {
+            input.len()
+        }
+
+        fn part_2(input: &Self::Parsed) -> Self::Solution2 {
+            input.chars().filter(|c| c.is_alphabetic()).count()
+        }
+    }
+
+    #[test]
+    fn test_equivalent() {
+        let comparison = assert_equivalent::<CountChars, CountBytes>(&["", "abc", "a1b2c3"]);
+        assert_eq!((comparison.first_name, comparison.second_name), ("CountChars", "CountBytes"));
+        assert!(comparison.speed_ratio() > 0.0);
+        assert!(comparison.to_string().starts_with("CountChars took "), "{comparison}");
+    }
+
+    #[test]
+    fn test_divergence() {
+        let divergence = compare::<CountChars, CountLetters>(&["", "12ab", "abc"]).unwrap_err();
+        assert_eq!(divergence, Divergence {
+            first_name: "CountChars",
+            second_name: "CountLetters",
+            input_index: 2,
+            part: 2,
+            first: "0".to_string(),
+            second: "3".to_string(),
+        });
+        assert_eq!(divergence.to_string(), "Part 2 of input 2 differs: CountChars answered 0, CountLetters answered 3");
+
+        let divergence = compare::<CountChars, CountLetters>(&["1a", "é"]).unwrap_err();
+        assert_eq!((divergence.input_index, divergence.part), (1, 1));
+        assert_eq!((divergence.first.as_str(), divergence.second.as_str()), ("1", "2"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Part 1 of input 0 differs")]
+    fn test_assert_equivalent_panics() {
+        assert_equivalent::<CountChars, CountLetters>(&["é"]);
+    }
+}
@@ -0,0 +1,174 @@
+use std::{fs::OpenOptions, io::Write, path::Path, process::Command};
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::BenchmarkReport;
+
+/// Environment variable naming the file [crate::Problem::benchmark] appends its report to.
+pub const BENCH_OUT_VAR: &str = "AOC_BENCH_OUT";
+
+/// Columns written by [BenchmarkRecord::to_csv_row].
+pub const CSV_HEADER: &str = "day,label,parse_ns,part1_solution,part1_ns,part2_solution,part2_ns";
+
+/// A [BenchmarkReport] keyed by the day it's for and the revision it was run at.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BenchmarkRecord {
+    pub day: String,
+    /// `git describe` of the workspace, if available.
+    pub label: Option<String>,
+    pub report: BenchmarkReport,
+}
+
+impl BenchmarkRecord {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("records always serialize")
+    }
+
+    pub fn from_json(json: &str) -> anyhow::Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn to_csv_row(&self) -> String {
+        format!(
+            "{},{},{}",
+            csv_field(&self.day),
+            csv_field(self.label.as_deref().unwrap_or("")),
+            self.report.to_csv_row(),
+        )
+    }
+}
+
+/// Quotes `field` if it contains a comma, quote or newline, doubling any quotes.
+pub fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Returns `git describe --always --dirty` for the workspace, or `None` outside a git checkout.
+pub fn git_label() -> Option<String> {
+    let output = Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .current_dir(crate::input::workspace_root())
+        .output()
+        .ok()?;
+    let label = String::from_utf8(output.stdout).ok()?;
+    (output.status.success() && !label.trim().is_empty()).then(|| label.trim().to_string())
+}
+
+/// Appends `record` to `path`: as a CSV row if it ends in `.csv`, or a line of JSON otherwise.
+///
+/// New CSV files start with [CSV_HEADER].
+pub fn append_record(path: &Path, record: &BenchmarkRecord) -> anyhow::Result<()> {
+    let is_csv = path.extension().is_some_and(|ext| ext == "csv");
+    let is_new = !path.exists();
+    let mut file = OpenOptions::new().create(true).append(true).open(path)
+        .with_context(|| format!("Couldn't open {}", path.display()))?;
+    if is_csv {
+        if is_new {
+            writeln!(file, "{CSV_HEADER}")?;
+        }
+        writeln!(file, "{}", record.to_csv_row())?;
+    } else {
+        writeln!(file, "{}", record.to_json())?;
+    }
+    Ok(())
+}
+
+/// Appends the report to the file in [BENCH_OUT_VAR], if it's set. Failures are reported on
+/// stderr rather than interrupting the benchmark.
+pub(crate) fn append_from_env(day: &str, report: &BenchmarkReport) {
+    let Some(path) = std::env::var_os(BENCH_OUT_VAR) else { return };
+    let record = BenchmarkRecord { day: day.to_string(), label: git_label(), report: report.clone() };
+    if let Err(e) = append_record(Path::new(&path), &record) {
+        eprintln!("Couldn't export benchmark: {e:#}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{allocs::AllocStats, Parts};
+
+    use super::*;
+
+    fn record() -> BenchmarkRecord {
+        BenchmarkRecord {
+            day: "Day13".to_string(),
+            label: Some("v1.0-3-gabc123".to_string()),
+            report: BenchmarkReport {
+                parse: Duration::from_nanos(1500),
+                prepare: None,
+                parts: Parts::Both,
+                part1: Some(("(1, 2)".to_string(), Duration::from_micros(20))),
+                part2: Some(("\"a\"".to_string(), Duration::from_millis(3))),
+                part1_allocs: None,
+                part2_allocs: None,
+                part1_peak_bytes: None,
+                part2_peak_bytes: None,
+                part1_timeout: None,
+                part2_timeout: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let record = record();
+        assert_eq!(BenchmarkRecord::from_json(&record.to_json()).unwrap(), record);
+
+        let report = record.report;
+        assert_eq!(BenchmarkReport::from_json(&report.to_json()).unwrap(), report);
+        let report = BenchmarkReport { part2: None, ..report };
+        assert_eq!(BenchmarkReport::from_json(&report.to_json()).unwrap(), report);
+        let allocs = AllocStats { allocations: 3, bytes: 96 };
+        let report = BenchmarkReport { part1_allocs: Some(allocs), part1_peak_bytes: Some(3 << 19), ..report };
+        assert_eq!(BenchmarkReport::from_json(&report.to_json()).unwrap(), report);
+        assert!(report.to_string().contains("in 20.00µs (3 allocations, 96 bytes, peak 1.50 MiB)\n"), "{report}");
+
+        // Reports exported before allocation counting still load
+        let old = r#"{"parse":{"secs":0,"nanos":5},"part1":["1",{"secs":1,"nanos":0}],"part2":null}"#;
+        assert_eq!(BenchmarkReport::from_json(old).unwrap().part1_allocs, None);
+    }
+
+    #[test]
+    fn test_csv_escaping() {
+        assert_eq!(csv_field("42"), "42");
+        assert_eq!(csv_field("(1, 2)"), "\"(1, 2)\"");
+        assert_eq!(csv_field("\"a\""), "\"\"\"a\"\"\"");
+        assert_eq!(
+            record().to_csv_row(),
+            "Day13,v1.0-3-gabc123,1500,\"(1, 2)\",20000,\"\"\"a\"\"\",3000000",
+        );
+        let report = BenchmarkReport { part2: None, ..record().report };
+        assert_eq!(report.to_csv_row(), "1500,\"(1, 2)\",20000,,");
+    }
+
+    #[test]
+    fn test_append_record() {
+        let dir = std::env::temp_dir().join(format!("aoc_export_test_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let csv = dir.join("results.csv");
+        append_record(&csv, &record()).unwrap();
+        append_record(&csv, &record()).unwrap();
+        let contents = std::fs::read_to_string(&csv).unwrap();
+        let lines: Vec<_> = contents.lines().collect();
+        assert_eq!(lines, [CSV_HEADER, &record().to_csv_row(), &record().to_csv_row()]);
+
+        let json = dir.join("results.json");
+        append_record(&json, &record()).unwrap();
+        append_record(&json, &record()).unwrap();
+        let contents = std::fs::read_to_string(&json).unwrap();
+        for line in contents.lines() {
+            assert_eq!(BenchmarkRecord::from_json(line).unwrap(), record());
+        }
+        assert_eq!(contents.lines().count(), 2);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
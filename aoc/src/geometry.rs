@@ -0,0 +1,113 @@
+use crate::grid::Vector2D;
+
+/// Returns the area of a simple polygon with the shoelace formula. The polygon is closed
+/// implicitly, so the last vertex doesn't need to repeat the first.
+///
+/// # Example
+/// ```
+/// # use aoc::geometry::polygon_area;
+/// assert_eq!(polygon_area(&[(0, 0), (4, 0), (4, 3), (0, 3)]), 12);
+/// ```
+pub fn polygon_area(vertices: &[(i64, i64)]) -> i64 {
+    let twice_area: i64 = edges(vertices)
+        .map(|((x1, y1), (x2, y2))| x1 * y2 - x2 * y1)
+        .sum();
+    twice_area.abs() / 2
+}
+
+/// Returns the perimeter of an axis-aligned polygon, which is also the number of lattice points
+/// on its boundary.
+///
+/// # Example
+/// ```
+/// # use aoc::geometry::perimeter;
+/// assert_eq!(perimeter(&[(0, 0), (4, 0), (4, 3), (0, 3)]), 14);
+/// ```
+pub fn perimeter(vertices: &[(i64, i64)]) -> i64 {
+    edges(vertices)
+        .map(|((x1, y1), (x2, y2))| (x2 - x1).abs() + (y2 - y1).abs())
+        .sum()
+}
+
+/// Returns the number of lattice points strictly inside a polygon with the given area and number
+/// of lattice points on its boundary, using Pick's theorem.
+pub fn lattice_points_inside(area: i64, boundary: i64) -> i64 {
+    area - boundary / 2 + 1
+}
+
+/// Returns the number of lattice points on or inside the loop dug by following `steps` from the
+/// origin, counting each cell of the trench as a point.
+///
+/// Panics if the steps don't return to the origin.
+///
+/// # Example
+/// ```
+/// # use aoc::{geometry::dig_area, grid::Vector2D};
+/// let steps = [Vector2D { x: 2, y: 0 }, Vector2D { x: 0, y: 2 }, Vector2D { x: -2, y: 0 }, Vector2D { x: 0, y: -2 }];
+/// assert_eq!(dig_area(&steps), 9);
+/// ```
+pub fn dig_area(steps: &[Vector2D]) -> i64 {
+    let mut curr = Vector2D { x: 0, y: 0 };
+    let mut vertices = vec![];
+    for step in steps {
+        curr += *step;
+        vertices.push((curr.x as i64, curr.y as i64));
+    }
+    assert!(curr == Vector2D { x: 0, y: 0 }, "Steps end at {curr:?} instead of the origin");
+
+    let boundary = perimeter(&vertices);
+    lattice_points_inside(polygon_area(&vertices), boundary) + boundary
+}
+
+/// Pairs of consecutive vertices, including the edge from the last vertex back to the first.
+fn edges(vertices: &[(i64, i64)]) -> impl Iterator<Item = ((i64, i64), (i64, i64))> + '_ {
+    vertices.iter().copied().zip(vertices.iter().copied().cycle().skip(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UNIT_SQUARE: [(i64, i64); 4] = [(0, 0), (1, 0), (1, 1), (0, 1)];
+    const L_SHAPE: [(i64, i64); 6] = [(0, 0), (2, 0), (2, 1), (1, 1), (1, 2), (0, 2)];
+
+    #[test]
+    fn test_unit_square() {
+        assert_eq!(polygon_area(&UNIT_SQUARE), 1);
+        assert_eq!(perimeter(&UNIT_SQUARE), 4);
+        assert_eq!(lattice_points_inside(1, 4), 0);
+
+        // Winding direction doesn't matter
+        let mut reversed = UNIT_SQUARE;
+        reversed.reverse();
+        assert_eq!(polygon_area(&reversed), 1);
+    }
+
+    #[test]
+    fn test_l_shape() {
+        assert_eq!(polygon_area(&L_SHAPE), 3);
+        assert_eq!(perimeter(&L_SHAPE), 8);
+        assert_eq!(lattice_points_inside(3, 8), 0);
+
+        let big_l = L_SHAPE.map(|(x, y)| (x * 3, y * 3));
+        assert_eq!(polygon_area(&big_l), 27);
+        assert_eq!(perimeter(&big_l), 24);
+        // Interior points are those of the 2x5 and 2x2 blocks
+        assert_eq!(lattice_points_inside(27, 24), 16);
+    }
+
+    #[test]
+    fn test_dig_area() {
+        let steps: Vec<Vector2D> = [(1i8, 0i8), (0, 1), (-1, 0), (0, -1)].into_iter().map(Vector2D::from).collect();
+        assert_eq!(dig_area(&steps), 4);
+
+        let steps: Vec<Vector2D> = [(2i8, 0i8), (0, 1), (-1, 0), (0, 1), (-1, 0), (0, -2)].into_iter().map(Vector2D::from).collect();
+        assert_eq!(dig_area(&steps), 8);
+    }
+
+    #[test]
+    #[should_panic(expected = "instead of the origin")]
+    fn test_dig_area_open() {
+        dig_area(&[Vector2D { x: 1, y: 0 }]);
+    }
+}
@@ -0,0 +1,245 @@
+use std::{collections::VecDeque, fmt::Display, ops::Index};
+
+pub type NodeIndex = usize;
+
+/// A directed graph stored as adjacency lists, with nodes of type `N`.
+///
+/// Each node keeps both its outgoing and incoming edges, so successors and predecessors are
+/// equally cheap to look up.
+///
+/// # Example
+/// ```
+/// # use aoc::graph::DiGraph;
+/// let mut graph = DiGraph::new();
+/// let a = graph.add_node('a');
+/// let b = graph.add_node('b');
+/// let c = graph.add_node('c');
+/// graph.add_edge(a, c);
+/// graph.add_edge(b, c);
+/// assert_eq!(graph.predecessors(c).collect::<Vec<_>>(), vec![a, b]);
+/// assert_eq!(graph[graph.find_node(&'b').unwrap()], 'b');
+/// ```
+#[derive(Debug, Clone)]
+pub struct DiGraph<N> {
+    nodes: Vec<N>,
+    outgoing: Vec<Vec<NodeIndex>>,
+    incoming: Vec<Vec<NodeIndex>>,
+}
+
+/// An error returned when topologically sorting a graph with a cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle(pub NodeIndex);
+
+impl Display for Cycle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Graph has a cycle through node {}", self.0)
+    }
+}
+
+impl std::error::Error for Cycle {}
+
+impl<N> DiGraph<N> {
+    pub fn new() -> Self {
+        DiGraph { nodes: vec![], outgoing: vec![], incoming: vec![] }
+    }
+
+    pub fn add_node(&mut self, node: N) -> NodeIndex {
+        self.nodes.push(node);
+        self.outgoing.push(vec![]);
+        self.incoming.push(vec![]);
+        self.nodes.len() - 1
+    }
+
+    /// Adds an edge from `source` to `target`. Panics if either node doesn't exist.
+    pub fn add_edge(&mut self, source: NodeIndex, target: NodeIndex) {
+        assert!(target < self.nodes.len(), "Node {target} doesn't exist");
+        self.outgoing[source].push(target);
+        self.incoming[target].push(source);
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.outgoing.iter().map(Vec::len).sum()
+    }
+
+    pub fn node(&self, index: NodeIndex) -> Option<&N> {
+        self.nodes.get(index)
+    }
+
+    /// Returns an iterator over the nodes and their indices.
+    pub fn nodes(&self) -> impl Iterator<Item = (NodeIndex, &N)> + '_ {
+        self.nodes.iter().enumerate()
+    }
+
+    /// Returns the targets of edges out of `node`, in the order they were added.
+    pub fn successors(&self, node: NodeIndex) -> impl Iterator<Item = NodeIndex> + '_ {
+        self.outgoing[node].iter().copied()
+    }
+
+    /// Returns the sources of edges into `node`, in the order they were added.
+    pub fn predecessors(&self, node: NodeIndex) -> impl Iterator<Item = NodeIndex> + '_ {
+        self.incoming[node].iter().copied()
+    }
+
+    pub fn count_predecessors(&self, node: NodeIndex) -> usize {
+        self.incoming[node].len()
+    }
+
+    /// Returns the nodes ordered so that every edge goes from an earlier node to a later one,
+    /// or the [Cycle] preventing it.
+    ///
+    /// # Example
+    /// ```
+    /// # use aoc::graph::{Cycle, DiGraph};
+    /// let mut graph = DiGraph::new();
+    /// let shirt = graph.add_node("shirt");
+    /// let tie = graph.add_node("tie");
+    /// graph.add_edge(shirt, tie);
+    /// assert_eq!(graph.topological_sort(), Ok(vec![shirt, tie]));
+    ///
+    /// graph.add_edge(tie, shirt);
+    /// assert!(graph.topological_sort().is_err());
+    /// ```
+    pub fn topological_sort(&self) -> Result<Vec<NodeIndex>, Cycle> {
+        // Kahn's algorithm
+        let mut remaining: Vec<usize> = self.incoming.iter().map(Vec::len).collect();
+        let mut queue: VecDeque<_> = (0..self.nodes.len()).filter(|&i| remaining[i] == 0).collect();
+        let mut order = Vec::with_capacity(self.nodes.len());
+        while let Some(node) = queue.pop_front() {
+            order.push(node);
+            for successor in self.successors(node) {
+                remaining[successor] -= 1;
+                if remaining[successor] == 0 {
+                    queue.push_back(successor);
+                }
+            }
+        }
+        match remaining.iter().position(|&n| n > 0) {
+            Some(node) => Err(Cycle(node)),
+            None => Ok(order),
+        }
+    }
+
+    /// Returns an iterator over the nodes reachable from `start` in breadth-first order,
+    /// starting with `start` itself.
+    pub fn bfs(&self, start: NodeIndex) -> impl Iterator<Item = NodeIndex> + '_ {
+        let mut visited = vec![false; self.nodes.len()];
+        visited[start] = true;
+        let mut queue = VecDeque::from([start]);
+        std::iter::from_fn(move || {
+            let node = queue.pop_front()?;
+            for successor in self.successors(node) {
+                if !visited[successor] {
+                    visited[successor] = true;
+                    queue.push_back(successor);
+                }
+            }
+            Some(node)
+        })
+    }
+
+    /// Returns an iterator over the nodes reachable from `start` in depth-first preorder,
+    /// starting with `start` itself. Successors are visited in the order their edges were added.
+    pub fn dfs(&self, start: NodeIndex) -> impl Iterator<Item = NodeIndex> + '_ {
+        let mut visited = vec![false; self.nodes.len()];
+        let mut stack = vec![start];
+        std::iter::from_fn(move || {
+            loop {
+                let node = stack.pop()?;
+                if visited[node] {
+                    continue;
+                }
+                visited[node] = true;
+                stack.extend(self.outgoing[node].iter().rev().filter(|&&n| !visited[n]));
+                return Some(node);
+            }
+        })
+    }
+}
+
+impl<N: PartialEq> DiGraph<N> {
+    /// Returns the index of the first node equal to `node`.
+    pub fn find_node(&self, node: &N) -> Option<NodeIndex> {
+        self.nodes.iter().position(|n| n == node)
+    }
+}
+
+impl<N> Default for DiGraph<N> {
+    fn default() -> Self {
+        DiGraph::new()
+    }
+}
+
+impl<N> Index<NodeIndex> for DiGraph<N> {
+    type Output = N;
+
+    fn index(&self, index: NodeIndex) -> &Self::Output {
+        &self.nodes[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// a -> b -> d, a -> c -> d, d -> e
+    fn diamond() -> DiGraph<char> {
+        let mut graph = DiGraph::new();
+        for c in ['a', 'b', 'c', 'd', 'e'] {
+            graph.add_node(c);
+        }
+        for (source, target) in [(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)] {
+            graph.add_edge(source, target);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_topological_sort() {
+        let graph = diamond();
+        let order = graph.topological_sort().unwrap();
+        assert_eq!(order.len(), graph.node_count());
+        let position = |node| order.iter().position(|&n| n == node).unwrap();
+        for node in 0..graph.node_count() {
+            for successor in graph.successors(node) {
+                assert!(position(node) < position(successor), "{node} -> {successor} out of order");
+            }
+        }
+        assert_eq!(order, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_topological_sort_cycle() {
+        let mut graph = diamond();
+        graph.add_edge(4, 1);
+        assert_eq!(graph.topological_sort(), Err(Cycle(1)));
+
+        let mut graph = DiGraph::new();
+        let node = graph.add_node(());
+        graph.add_edge(node, node);
+        assert_eq!(graph.topological_sort(), Err(Cycle(node)));
+    }
+
+    #[test]
+    fn test_predecessors() {
+        let graph = diamond();
+        let counts: Vec<_> = (0..graph.node_count()).map(|n| graph.count_predecessors(n)).collect();
+        assert_eq!(counts, vec![0, 1, 1, 2, 1]);
+        assert_eq!(graph.predecessors(3).collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(graph.successors(0).collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(graph.edge_count(), 5);
+    }
+
+    #[test]
+    fn test_traversals() {
+        let graph = diamond();
+        assert_eq!(graph.bfs(0).map(|n| graph[n]).collect::<String>(), "abcde");
+        assert_eq!(graph.dfs(0).map(|n| graph[n]).collect::<String>(), "abdec");
+        assert_eq!(graph.bfs(2).map(|n| graph[n]).collect::<String>(), "cde");
+        assert_eq!(graph.find_node(&'d'), Some(3));
+        assert_eq!(graph.find_node(&'z'), None);
+    }
+}
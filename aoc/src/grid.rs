@@ -1,5 +1,5 @@
 use derive_more::{Add, AddAssign};
-use std::{collections::{BTreeSet, HashMap}, fmt::{Debug, Display}};
+use std::{collections::{hash_map::Entry, BTreeSet, HashMap, HashSet}, fmt::{Debug, Display}, hash::{Hash, Hasher}, str::FromStr};
 
 /// A point with non-negative x and y components
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Add, AddAssign)]
@@ -37,6 +37,174 @@ impl Point {
         })
     }
 
+    /// Returns a list of Points that are adjacent to `self`, including diagonally.
+    /// 
+    /// Points are yielded in reading order: the row above from left to right, then left and right,
+    /// then the row below from left to right.
+    /// Omits any points that would have negative components.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::Point;
+    /// let point = Point { x: 0, y: 1 };
+    /// let mut neighbors = point.neighbors8();
+    /// 
+    /// assert_eq!(neighbors.next(), Some(Point { x: 0, y: 0 }));
+    /// assert_eq!(neighbors.next(), Some(Point { x: 1, y: 0 }));
+    /// assert_eq!(neighbors.next(), Some(Point { x: 1, y: 1 }));
+    /// assert_eq!(neighbors.next(), Some(Point { x: 0, y: 2 }));
+    /// assert_eq!(neighbors.next(), Some(Point { x: 1, y: 2 }));
+    /// assert_eq!(neighbors.next(), None);
+    /// ```
+    pub fn neighbors8(&self) -> impl Iterator<Item = Point> {
+        const NEIGHBOR_VECS: [Vector2D; 8] = [
+            Vector2D { x: -1, y: -1 },
+            Vector2D { x: 0, y: -1 },
+            Vector2D { x: 1, y: -1 },
+            Vector2D { x: -1, y: 0 },
+            Vector2D { x: 1, y: 0 },
+            Vector2D { x: -1, y: 1 },
+            Vector2D { x: 0, y: 1 },
+            Vector2D { x: 1, y: 1 },
+        ];
+        let origin = *self;
+        NEIGHBOR_VECS.into_iter().filter_map(move |vec_2d| origin.offset_by(vec_2d))
+    }
+
+    /// Returns a list of Points that are adjacent to `self`, along with the [Direction] moved to reach them.
+    /// 
+    /// Follows the order of [Direction::DIRS].
+    /// Omits any points that would have negative components.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::{Direction, Point};
+    /// let point = Point { x: 0, y: 1 };
+    /// let mut neighbors = point.neighbors_with_dirs();
+    /// 
+    /// assert_eq!(neighbors.next(), Some((Direction::North, Point { x: 0, y: 0 })));
+    /// assert_eq!(neighbors.next(), Some((Direction::South, Point { x: 0, y: 2 })));
+    /// assert_eq!(neighbors.next(), Some((Direction::East, Point { x: 1, y: 1 })));
+    /// assert_eq!(neighbors.next(), None);
+    /// ```
+    pub fn neighbors_with_dirs(&self) -> impl Iterator<Item = (Direction, Point)> {
+        let origin = *self;
+        Direction::DIRS.into_iter()
+            .filter_map(move |d| Some((d, origin.offset_by(d.vector())?)))
+    }
+
+    /// Returns the Points at exactly manhattan distance `k` from `self`.
+    /// 
+    /// Points are yielded clockwise, starting with the point directly north of `self`.
+    /// Omits any points that would have negative components.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::Point;
+    /// let point = Point { x: 5, y: 5 };
+    /// let mut ring = point.ring(1);
+    /// 
+    /// assert_eq!(ring.next(), Some(Point { x: 5, y: 4 }));
+    /// assert_eq!(ring.next(), Some(Point { x: 6, y: 5 }));
+    /// assert_eq!(ring.next(), Some(Point { x: 5, y: 6 }));
+    /// assert_eq!(ring.next(), Some(Point { x: 4, y: 5 }));
+    /// assert_eq!(ring.next(), None);
+    /// ```
+    pub fn ring(&self, k: usize) -> impl Iterator<Item = Point> {
+        let origin = *self;
+        let k = k as isize;
+        let offsets = (0..k).flat_map(move |d| {
+            [
+                Vector2D { x: d, y: d - k },
+                Vector2D { x: k - d, y: d },
+                Vector2D { x: -d, y: k - d },
+                Vector2D { x: d - k, y: -d },
+            ]
+        });
+        // Reorder so each side of the diamond is yielded in turn
+        let ordered = (0..4).flat_map(move |side| offsets.clone().skip(side).step_by(4));
+        // A ring of size 0 is just the origin
+        let center = (k == 0).then_some(Vector2D { x: 0, y: 0 });
+        center.into_iter()
+            .chain(ordered)
+            .filter_map(move |offset| origin.offset_by(offset))
+    }
+
+    /// Returns the Points within manhattan distance `k` of `self`, including `self`.
+    /// 
+    /// Yields each [Point::ring] in turn, from distance 0 up to `k`.
+    /// Omits any points that would have negative components.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::Point;
+    /// let point = Point { x: 5, y: 5 };
+    /// 
+    /// assert_eq!(point.disk(2).count(), 13);
+    /// assert_eq!(point.disk(2).next(), Some(point));
+    /// ```
+    pub fn disk(&self, k: usize) -> impl Iterator<Item = Point> {
+        let origin = *self;
+        (0..=k).flat_map(move |ring| origin.ring(ring))
+    }
+
+    /// Returns true if `self` lies on the outer edge of `grid`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::{Grid, Point};
+    /// let grid: Grid<char> = "\
+    ///     abc\n\
+    ///     def\n\
+    ///     ghi".into();
+    /// 
+    /// assert!(Point { x: 0, y: 1 }.is_on_border(&grid));
+    /// assert!(Point { x: 2, y: 2 }.is_on_border(&grid));
+    /// assert!(!Point { x: 1, y: 1 }.is_on_border(&grid));
+    /// assert!(!Point { x: 3, y: 1 }.is_on_border(&grid));
+    /// ```
+    pub fn is_on_border<T>(&self, grid: &Grid<T>) -> bool {
+        grid.check_inbounds(*self)
+            && (self.x == 0 || self.y == 0 || self.x == grid.width() - 1 || self.y == grid.height() - 1)
+    }
+
+    /// Returns the [Direction] to move in to get from `self` to `other`, if they're orthogonally adjacent.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::{Direction, Point};
+    /// let point = Point { x: 1, y: 1 };
+    /// 
+    /// assert_eq!(point.direction_towards(Point { x: 1, y: 0 }), Some(Direction::North));
+    /// assert_eq!(point.direction_towards(Point { x: 2, y: 2 }), None);
+    /// assert_eq!(point.direction_towards(Point { x: 1, y: 3 }), None);
+    /// ```
+    pub fn direction_towards(&self, other: Point) -> Option<Direction> {
+        Direction::DIRS.into_iter().find(|dir| self.step(*dir) == Some(other))
+    }
+
+    /// Returns the Point one step away from `self` in direction `dir`, or `None` if it would have
+    /// negative components.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::{Direction, Point};
+    /// let point = Point { x: 0, y: 1 };
+    /// 
+    /// assert_eq!(point.step(Direction::East), Some(Point { x: 1, y: 1 }));
+    /// assert_eq!(point.step(Direction::West), None);
+    /// ```
+    pub fn step(&self, dir: Direction) -> Option<Point> {
+        self.offset_by(dir.vector())
+    }
+
     /// Returns the manhattan distance between `self` and `other`.
     /// 
     /// # Example
@@ -46,9 +214,9 @@ impl Point {
     /// let point_a = Point { x: 0, y: 0 };
     /// let point_b = Point { x: 10, y: 5 };
     /// 
-    /// assert_eq!(point_a.manhattan_distance(&point_b), 15);
+    /// assert_eq!(point_a.manhattan_distance(point_b), 15);
     /// ```
-    pub fn manhattan_distance(&self, other: &Point) -> usize {
+    pub fn manhattan_distance(&self, other: Point) -> usize {
         self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
     }
 
@@ -78,6 +246,58 @@ impl Point {
 }
 
 impl Vector2D {
+    /// Returns the manhattan length of the vector, the sum of the absolute values of its components.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::Vector2D;
+    /// assert_eq!(Vector2D { x: -3, y: 4 }.manhattan_len(), 7);
+    /// assert_eq!(Vector2D { x: 0, y: 0 }.manhattan_len(), 0);
+    /// ```
+    pub const fn manhattan_len(&self) -> usize {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+
+    /// Returns true if at most one component of the vector is nonzero.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::Vector2D;
+    /// assert!(Vector2D { x: 0, y: -5 }.is_axis_aligned());
+    /// assert!(Vector2D { x: 0, y: 0 }.is_axis_aligned());
+    /// assert!(!Vector2D { x: 1, y: 1 }.is_axis_aligned());
+    /// ```
+    pub const fn is_axis_aligned(&self) -> bool {
+        self.x == 0 || self.y == 0
+    }
+
+    /// Returns the componentwise sign of the vector, each component being `-1`, `0`, or `1`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::Vector2D;
+    /// assert_eq!(Vector2D { x: -3, y: 0 }.signum(), Vector2D { x: -1, y: 0 });
+    /// assert_eq!(Vector2D { x: 7, y: -2 }.signum(), Vector2D { x: 1, y: -1 });
+    /// ```
+    pub const fn signum(&self) -> Vector2D {
+        Vector2D { x: self.x.signum(), y: self.y.signum() }
+    }
+
+    /// Returns the componentwise absolute value of the vector.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::Vector2D;
+    /// assert_eq!(Vector2D { x: -3, y: 4 }.abs(), Vector2D { x: 3, y: 4 });
+    /// ```
+    pub const fn abs(&self) -> Vector2D {
+        Vector2D { x: self.x.abs(), y: self.y.abs() }
+    }
+
     /// Returns a list of Vector2D that are adjacent to `self`.
     /// 
     /// # Example
@@ -149,8 +369,33 @@ pub enum Direction {
 }
 
 impl Direction {
+    /// Every direction, in the order North, South, East, West.
+    /// 
+    /// This order is guaranteed to stay stable, since changing it changes which path searches find first.
+    /// It's the order of [Direction::index].
     pub const DIRS: [Direction; 4] = [Direction::North, Direction::South, Direction::East, Direction::West];
 
+    /// Every direction in clockwise order, starting from North.
+    /// 
+    /// Each direction is followed by its [Direction::right_hand] turn.
+    pub const DIRS_CLOCKWISE: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    /// Returns the position of `self` in [Direction::DIRS].
+    pub fn index(&self) -> usize {
+        use Direction as D;
+        match self {
+            D::North => 0,
+            D::South => 1,
+            D::East => 2,
+            D::West => 3,
+        }
+    }
+
+    /// Returns the direction at position `index` in [Direction::DIRS], if any.
+    pub fn from_index(index: usize) -> Option<Direction> {
+        Direction::DIRS.get(index).copied()
+    }
+
     /// Returns a unit [Vector2D] in the direction represented.
     pub fn vector(&self) -> Vector2D {
         use Direction as D;
@@ -201,20 +446,114 @@ impl Display for Direction {
     }
 }
 
+/// An error returned when a [Direction] can't be parsed from a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDirectionError(pub String);
+
+impl Display for ParseDirectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Cannot parse direction from {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseDirectionError {}
+
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    /// Parses a direction from its full name or its first letter, ignoring case.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::Direction;
+    /// assert_eq!("North".parse(), Ok(Direction::North));
+    /// assert_eq!("w".parse(), Ok(Direction::West));
+    /// assert!("Up".parse::<Direction>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Direction as D;
+        match s.to_ascii_lowercase().as_str() {
+            "north" | "n" => Ok(D::North),
+            "south" | "s" => Ok(D::South),
+            "east" | "e" => Ok(D::East),
+            "west" | "w" => Ok(D::West),
+            _ => Err(ParseDirectionError(s.to_string())),
+        }
+    }
+}
+
 /// A 2-dimension grid of elements with type `T`.
 /// 
 /// Not every position within a `Grid` area has to contain an element.
-#[derive(Debug, PartialEq, Eq)]
+/// 
+/// Two grids are equal when they have the same dimensions and the same elements at the same points,
+/// regardless of the order elements were inserted. [Hash] is consistent with this, so grids can be
+/// used as keys in a `HashMap` (e.g. for cycle detection). The [BoundsPolicy] isn't compared.
+#[derive(Debug)]
 pub struct Grid<T> {
     map: HashMap<Point, T>,
     width: usize,
     height: usize,
+    bounds: BoundsPolicy,
+}
+
+/// How a [Grid] handles an [insert](Grid::insert) outside of its current width and height.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BoundsPolicy {
+    /// The grid grows to fit the inserted point.
+    #[default]
+    Grow,
+    /// The insert is rejected with an [OutOfBounds] error, see [Grid::try_insert].
+    Strict,
+}
+
+/// An error returned when inserting a point outside of a [BoundsPolicy::Strict] grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutOfBounds(pub Point);
+
+impl Display for OutOfBounds {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Point {} is out of bounds", self.0)
+    }
 }
 
+impl std::error::Error for OutOfBounds {}
+
 impl<T> Grid<T> {
     /// Constructs a new, empty `Grid<T>`.
     pub fn new() -> Self {
-        Grid { map: HashMap::new(), width: 0, height: 0 }
+        Grid { map: HashMap::new(), width: 0, height: 0, bounds: BoundsPolicy::Grow }
+    }
+
+    /// Constructs a new, empty `Grid<T>` with a fixed `width` and `height`.
+    /// 
+    /// The grid uses [BoundsPolicy::Strict], so inserting outside of the declared size is an error:
+    /// [Grid::try_insert] returns it as [OutOfBounds], and [Grid::insert] panics with it.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::{Grid, OutOfBounds, Point};
+    /// let mut grid: Grid<char> = Grid::with_size(3, 2);
+    /// 
+    /// assert_eq!(grid.width(), 3);
+    /// assert_eq!(grid.try_insert(Point { x: 2, y: 1 }, 'a'), Ok(None));
+    /// assert_eq!(grid.try_insert(Point { x: 3, y: 0 }, 'b'), Err(OutOfBounds(Point { x: 3, y: 0 })));
+    /// ```
+    pub fn with_size(width: usize, height: usize) -> Self {
+        Grid { map: HashMap::new(), width, height, bounds: BoundsPolicy::Strict }
+    }
+
+    /// Returns the `Grid` with its [BoundsPolicy] replaced by `bounds`.
+    pub fn with_bounds_policy(mut self, bounds: BoundsPolicy) -> Self {
+        self.bounds = bounds;
+        self
+    }
+
+    /// Returns the [BoundsPolicy] of the `Grid`.
+    pub fn bounds_policy(&self) -> BoundsPolicy {
+        self.bounds
     }
 
     /// Constructs a new `Grid<T>` based on 2-dimenional `Vec<Vec<T>>`.
@@ -238,16 +577,61 @@ impl<T> Grid<T> {
     /// assert_eq!(data_grid, manual_grid);
     /// ```
     pub fn from_2d_vec(input: Vec<Vec<T>>) -> Self {
+        Grid::from_dense_rows(input)
+    }
+
+    /// Constructs a new `Grid<T>` from an iterator of rows, where `None` cells are left empty.
+    /// 
+    /// Ragged rows are allowed; the width is the length of the longest row.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::{Grid, Point};
+    /// let grid = Grid::from_rows("#.#\n.#".lines().map(|line| line.chars().map(|c| (c == '#').then_some(c))));
+    /// 
+    /// assert_eq!((grid.width(), grid.height()), (3, 2));
+    /// assert_eq!(grid.get(Point { x: 2, y: 0 }), Some(&'#'));
+    /// assert_eq!(grid.get(Point { x: 0, y: 1 }), None);
+    /// ```
+    pub fn from_rows<I, R>(rows: I) -> Self
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = Option<T>>,
+    {
         let mut map = HashMap::new();
-        let height = input.len();
-        let mut width = 0;
-        for (y, row) in input.into_iter().enumerate() {
-            width = width.max(row.len());
-            for (x, value) in row.into_iter().enumerate() {
-                map.insert(Point { x, y }, value);
+        let (mut width, mut height) = (0, 0);
+        for (y, row) in rows.into_iter().enumerate() {
+            height = y + 1;
+            for (x, cell) in row.into_iter().enumerate() {
+                width = width.max(x + 1);
+                if let Some(value) = cell {
+                    map.insert(Point { x, y }, value);
+                }
             }
         }
-        Grid { map, width, height }
+        Grid { map, width, height, bounds: BoundsPolicy::Grow }
+    }
+
+    /// Constructs a new `Grid<T>` from an iterator of rows with an element at every position.
+    /// 
+    /// Ragged rows are allowed; the width is the length of the longest row.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::{Grid, Point};
+    /// let grid = Grid::from_dense_rows((0..2).map(|y| (0..3).map(move |x| x + y)));
+    /// 
+    /// assert_eq!((grid.width(), grid.height()), (3, 2));
+    /// assert_eq!(grid.get(Point { x: 2, y: 1 }), Some(&3));
+    /// ```
+    pub fn from_dense_rows<I, R>(rows: I) -> Self
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+    {
+        Grid::from_rows(rows.into_iter().map(|row| row.into_iter().map(Some)))
     }
 
     /// Returns true if given point is within area of grid
@@ -270,6 +654,81 @@ impl<T> Grid<T> {
         point.x < self.width && point.y < self.height
     }
 
+    /// Returns true if the signed vector `v` is a point within the area of the grid.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::{Grid, Point, Vector2D};
+    /// let mut grid: Grid<u32> = Grid::new();
+    /// grid.insert(Point { x: 2, y: 2 }, 1);
+    /// 
+    /// assert!(grid.contains_vec(Vector2D { x: 1, y: 2 }));
+    /// assert!(!grid.contains_vec(Vector2D { x: -1, y: 2 }));
+    /// assert!(!grid.contains_vec(Vector2D { x: 1, y: 3 }));
+    /// ```
+    pub fn contains_vec(&self, v: Vector2D) -> bool {
+        Point::try_from(v).is_ok_and(|point| self.check_inbounds(point))
+    }
+
+    /// Returns `Some(&T)` if an element is in the grid at the signed vector `v`, otherwise `None`.
+    /// 
+    /// Vectors with negative components are never in the grid.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::{Grid, Point, Vector2D};
+    /// let mut grid: Grid<char> = Grid::new();
+    /// grid.insert(Point { x: 0, y: 1 }, 'a');
+    /// 
+    /// assert_eq!(grid.get_signed(Vector2D { x: 0, y: 1 }), Some(&'a'));
+    /// assert_eq!(grid.get_signed(Vector2D { x: -1, y: 1 }), None);
+    /// ```
+    pub fn get_signed(&self, v: Vector2D) -> Option<&T> {
+        self.map.get(&Point::try_from(v).ok()?)
+    }
+
+    /// Sets the width and height of the `Grid`, removing any elements outside of the new bounds.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::{Grid, Point};
+    /// let mut grid: Grid<char> = "abc\ndef".into();
+    /// grid.resize(2, 1);
+    /// 
+    /// assert_eq!(grid.to_display_string('.'), "ab\n");
+    /// assert!(!grid.check_inbounds(Point { x: 2, y: 0 }));
+    /// ```
+    pub fn resize(&mut self, width: usize, height: usize) {
+        if width < self.width || height < self.height {
+            self.map.retain(|point, _| point.x < width && point.y < height);
+        }
+        self.width = width;
+        self.height = height;
+    }
+
+    /// Shrinks the width and height of the `Grid` to just fit its elements.
+    /// 
+    /// The origin doesn't move, so empty rows and columns at the top and left are kept.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::{Grid, Point};
+    /// let mut grid: Grid<char> = Grid::with_size(5, 5);
+    /// grid.insert(Point { x: 1, y: 2 }, 'a');
+    /// grid.truncate_to_content();
+    /// 
+    /// assert_eq!((grid.width(), grid.height()), (2, 3));
+    /// ```
+    pub fn truncate_to_content(&mut self) {
+        let width = self.map.keys().map(|point| point.x + 1).max().unwrap_or(0);
+        let height = self.map.keys().map(|point| point.y + 1).max().unwrap_or(0);
+        self.resize(width, height);
+    }
+
     /// Returns the width of the `Grid`.
     pub fn width(&self) -> usize {
         self.width
@@ -303,6 +762,18 @@ impl<T> Grid<T> {
     /// Inserts element `T` into `Grid` at given point.
     /// Returns `Some(T)` if replacing a previous element at that point, otherwise `None`.
     /// 
+    /// Points outside the grid grow its width and height.
+    /// 
+    /// # Panics
+    /// 
+    /// If the grid's [BoundsPolicy] is `Strict` and `point` is outside of it. Use [Grid::try_insert]
+    /// to get that as an [OutOfBounds] error instead.
+    /// 
+    /// `insert` keeps returning a plain `Option` rather than a `Result`, so the many grids using the
+    /// default `Grow` policy, which can't fail, don't have to unwrap every insert. This follows
+    /// [Problem::try_parse](crate::Problem::try_parse) and the other `try_` methods, which hold the
+    /// fallible version of an infallible default.
+    /// 
     /// # Example
     /// 
     /// ```
@@ -313,25 +784,250 @@ impl<T> Grid<T> {
     /// assert_eq!(grid.insert(Point { x: 0, y: 0 }, 'b'), Some('a'));
     /// ```
     pub fn insert<P> (&mut self, point: P, value: T) -> Option<T>
+    where
+        P: Into<Point>,
+    {
+        self.try_insert(point, value).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Fallible version of [Grid::insert]. Returns `Ok(Some(T))` if replacing a previous element at
+    /// that point, otherwise `Ok(None)`.
+    /// 
+    /// Points outside the grid grow its width and height, unless the grid's [BoundsPolicy] is `Strict`,
+    /// in which case an [OutOfBounds] error is returned and the grid is left unchanged.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::{Grid, OutOfBounds, Point};
+    /// let mut grid: Grid<char> = Grid::with_size(2, 2);
+    /// 
+    /// assert_eq!(grid.try_insert(Point { x: 1, y: 1 }, 'a'), Ok(None));
+    /// assert_eq!(grid.try_insert(Point { x: 2, y: 0 }, 'b'), Err(OutOfBounds(Point { x: 2, y: 0 })));
+    /// ```
+    pub fn try_insert<P> (&mut self, point: P, value: T) -> Result<Option<T>, OutOfBounds>
     where
         P: Into<Point>,
     {
         let point = point.into();
-        self.width = self.width.max(point.x + 1);
-        self.height = self.height.max(point.y + 1);
-        self.map.insert(point, value)
+        if !self.check_inbounds(point) {
+            match self.bounds {
+                BoundsPolicy::Grow => {
+                    self.width = self.width.max(point.x + 1);
+                    self.height = self.height.max(point.y + 1);
+                },
+                BoundsPolicy::Strict => return Err(OutOfBounds(point)),
+            }
+        }
+        Ok(self.map.insert(point, value))
     }
 
-    /// Iterates over all elements in the `Grid`, left to right, then top to bottom.
-    /// Skips over empty positions in the `Grid`.
+    /// Fills the `width` by `height` rectangle with its top left corner at `top_left` with clones of `value`,
+    /// replacing any existing elements.
     /// 
-    /// # Examples
+    /// Grows the `Grid` to fit the rectangle, unless its [BoundsPolicy] is `Strict`, in which case an
+    /// [OutOfBounds] error is returned for the far corner and nothing is written. A rectangle too
+    /// large for its far corner to be a [Point] is out of bounds with either policy.
+    /// Zero-sized rectangles do nothing.
+    /// 
+    /// # Example
     /// 
     /// ```
     /// # use aoc::grid::{Grid, Point};
-    /// // Full grid
-    /// let input = "\
-    ///     ab\n\
+    /// let mut grid: Grid<char> = Grid::new();
+    /// grid.fill_rect(Point { x: 1, y: 0 }, 2, 2, '#').unwrap();
+    /// 
+    /// assert_eq!(grid.to_display_string('.'), ".##\n.##\n");
+    /// ```
+    pub fn fill_rect(&mut self, top_left: Point, width: usize, height: usize, value: T) -> Result<(), OutOfBounds>
+    where
+        T: Clone,
+    {
+        if width == 0 || height == 0 {
+            return Ok(());
+        }
+        let (Some(right), Some(bottom)) = (top_left.x.checked_add(width - 1), top_left.y.checked_add(height - 1)) else {
+            let corner = Point { x: top_left.x.saturating_add(width - 1), y: top_left.y.saturating_add(height - 1) };
+            return Err(OutOfBounds(corner));
+        };
+        let bottom_right = Point { x: right, y: bottom };
+        if self.bounds == BoundsPolicy::Strict && !self.check_inbounds(bottom_right) {
+            return Err(OutOfBounds(bottom_right));
+        }
+        for y in top_left.y..=bottom_right.y {
+            for x in top_left.x..=bottom_right.x {
+                self.map.insert(Point { x, y }, value.clone());
+            }
+        }
+        self.width = self.width.max(bottom_right.x + 1);
+        self.height = self.height.max(bottom_right.y + 1);
+        Ok(())
+    }
+
+    /// Removes all elements in the `width` by `height` rectangle with its top left corner at `top_left`.
+    /// 
+    /// The width and height of the `Grid` are unchanged, and the part of the rectangle outside of
+    /// them is ignored.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::{Grid, Point};
+    /// let mut grid: Grid<char> = "###\n###".into();
+    /// grid.clear_rect(Point { x: 1, y: 1 }, 2, 1);
+    /// 
+    /// assert_eq!(grid.to_display_string('.'), "###\n#..\n");
+    /// ```
+    pub fn clear_rect(&mut self, top_left: Point, width: usize, height: usize) {
+        // Every element is within the grid's bounds, so there's nothing to remove past them
+        let right = top_left.x.saturating_add(width).min(self.width);
+        let bottom = top_left.y.saturating_add(height).min(self.height);
+        for y in top_left.y..bottom {
+            for x in top_left.x..right {
+                self.map.remove(&Point { x, y });
+            }
+        }
+    }
+
+    /// Cyclically shifts the elements in `row` right by `by` positions, wrapping around the width of the `Grid`.
+    /// 
+    /// Negative shifts move elements left. Empty positions stay empty. Does nothing if `row` is out of bounds.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::Grid;
+    /// let mut grid: Grid<char> = "abc\ndef".into();
+    /// grid.shift_row(0, 1);
+    /// grid.shift_row(1, -1);
+    /// 
+    /// assert_eq!(grid.to_display_string('.'), "cab\nefd\n");
+    /// ```
+    pub fn shift_row(&mut self, row: usize, by: isize) {
+        if row >= self.height {
+            return;
+        }
+        let width = self.width;
+        let cells: Vec<_> = (0..width)
+            .filter_map(|x| self.map.remove_entry(&Point { x, y: row }))
+            .collect();
+        for (point, value) in cells {
+            let x = (point.x as isize + by).rem_euclid(width as isize) as usize;
+            self.map.insert(Point { x, y: row }, value);
+        }
+    }
+
+    /// Cyclically shifts the elements in `col` down by `by` positions, wrapping around the height of the `Grid`.
+    /// 
+    /// Negative shifts move elements up. Empty positions stay empty. Does nothing if `col` is out of bounds.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::Grid;
+    /// let mut grid: Grid<char> = "ab\ncd\nef".into();
+    /// grid.shift_col(0, 1);
+    /// grid.shift_col(1, -1);
+    /// 
+    /// assert_eq!(grid.to_display_string('.'), "ed\naf\ncb\n");
+    /// ```
+    pub fn shift_col(&mut self, col: usize, by: isize) {
+        if col >= self.width {
+            return;
+        }
+        let height = self.height;
+        let cells: Vec<_> = (0..height)
+            .filter_map(|y| self.map.remove_entry(&Point { x: col, y }))
+            .collect();
+        for (point, value) in cells {
+            let y = (point.y as isize + by).rem_euclid(height as isize) as usize;
+            self.map.insert(Point { x: col, y }, value);
+        }
+    }
+
+    /// Replaces the element at `point` with the result of calling `f` on the current element, if any.
+    /// Returning `None` from `f` leaves the position empty. The position is only looked up once.
+    /// 
+    /// New elements outside the grid grow it, like [Grid::insert].
+    /// 
+    /// # Panics
+    /// 
+    /// If the grid's [BoundsPolicy] is `Strict` and `f` returns an element for a point outside of
+    /// it. Use [Grid::try_update] to get that as an [OutOfBounds] error instead.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::{Grid, Point};
+    /// let mut grid: Grid<u32> = Grid::new();
+    /// let point = Point { x: 1, y: 0 };
+    /// 
+    /// grid.update(point, |count| Some(count.unwrap_or(&0) + 1));
+    /// grid.update(point, |count| Some(count.unwrap_or(&0) + 1));
+    /// assert_eq!(grid.get(point), Some(&2));
+    /// 
+    /// grid.update(point, |_| None);
+    /// assert_eq!(grid.get(point), None);
+    /// ```
+    pub fn update<P>(&mut self, point: P, f: impl FnOnce(Option<&T>) -> Option<T>)
+    where
+        P: Into<Point>,
+    {
+        self.try_update(point, f).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Fallible version of [Grid::update]. New elements outside the grid follow the same
+    /// [BoundsPolicy] rules as [Grid::try_insert], so on a `Strict` grid they're an [OutOfBounds]
+    /// error and the grid is left unchanged.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::{Grid, OutOfBounds, Point};
+    /// let mut grid: Grid<u32> = Grid::with_size(1, 1);
+    /// 
+    /// assert_eq!(grid.try_update(Point { x: 0, y: 0 }, |_| Some(1)), Ok(()));
+    /// assert_eq!(grid.try_update(Point { x: 1, y: 0 }, |_| Some(1)), Err(OutOfBounds(Point { x: 1, y: 0 })));
+    /// ```
+    pub fn try_update<P>(&mut self, point: P, f: impl FnOnce(Option<&T>) -> Option<T>) -> Result<(), OutOfBounds>
+    where
+        P: Into<Point>,
+    {
+        let point = point.into();
+        if !self.check_inbounds(point) {
+            return match f(None) {
+                Some(value) => self.try_insert(point, value).map(|_| ()),
+                None => Ok(()),
+            };
+        }
+        match self.map.entry(point) {
+            Entry::Occupied(mut entry) => match f(Some(entry.get())) {
+                Some(value) => {
+                    entry.insert(value);
+                },
+                None => {
+                    entry.remove();
+                },
+            },
+            Entry::Vacant(entry) => {
+                if let Some(value) = f(None) {
+                    entry.insert(value);
+                }
+            },
+        }
+        Ok(())
+    }
+
+    /// Iterates over all elements in the `Grid`, left to right, then top to bottom.
+    /// Skips over empty positions in the `Grid`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use aoc::grid::{Grid, Point};
+    /// // Full grid
+    /// let input = "\
+    ///     ab\n\
     ///     cd";
     /// let grid: Grid<_> = input.into();
     ///
@@ -352,11 +1048,176 @@ impl<T> Grid<T> {
     /// assert_eq!(grid_iter.next(), Some(&'Z'));
     /// assert_eq!(grid_iter.next(), None);
     /// ```
-    pub fn iter(&self) -> GridIter<T> {
+    pub fn iter(&self) -> GridIter<'_, T> {
         let next = Point { x: 0, y: 0 };
         GridIter { grid: self, next, current: next }
     }
 
+    /// Iterates over the points of all elements matching `pred`, left to right, then top to bottom.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::{Grid, Point};
+    /// let grid: Grid<u32> = Grid::from_digits("12\n34").unwrap();
+    /// let mut points = grid.points_where(|n| n % 2 == 0);
+    /// 
+    /// assert_eq!(points.next(), Some(Point { x: 1, y: 0 }));
+    /// assert_eq!(points.next(), Some(Point { x: 1, y: 1 }));
+    /// assert_eq!(points.next(), None);
+    /// ```
+    pub fn points_where<'a>(&'a self, pred: impl Fn(&T) -> bool + 'a) -> impl Iterator<Item = Point> + 'a {
+        self.iter().indexed()
+            .filter_map(move |(point, value)| if pred(value) { Some(point) } else { None })
+    }
+
+    /// Iterates over the points of all elements equal to `value`, left to right, then top to bottom.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::{Grid, Point};
+    /// let grid: Grid<char> = "ab\nba".into();
+    /// let mut points = grid.points_of(&'a');
+    /// 
+    /// assert_eq!(points.next(), Some(Point { x: 0, y: 0 }));
+    /// assert_eq!(points.next(), Some(Point { x: 1, y: 1 }));
+    /// assert_eq!(points.next(), None);
+    /// ```
+    pub fn points_of<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point> + 'a
+    where
+        T: PartialEq,
+    {
+        self.points_where(move |v| v == value)
+    }
+
+    /// Iterates over every point within the bounds of the `Grid` with its element, if any, left to right,
+    /// then top to bottom. Unlike [Grid::iter], empty positions are yielded as `None`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::{Grid, Point};
+    /// let mut grid: Grid<char> = Grid::new();
+    /// grid.insert(Point { x: 1, y: 0 }, 'a');
+    /// let mut cells = grid.iter_cells();
+    /// 
+    /// assert_eq!(cells.next(), Some((Point { x: 0, y: 0 }, None)));
+    /// assert_eq!(cells.next(), Some((Point { x: 1, y: 0 }, Some(&'a'))));
+    /// assert_eq!(cells.next(), None);
+    /// ```
+    pub fn iter_cells(&self) -> impl Iterator<Item = (Point, Option<&T>)> + '_ {
+        (0..self.height).flat_map(move |y| self.row_cells(y))
+    }
+
+    /// Iterates over every point in row `y` with its element, if any, left to right.
+    /// Yields nothing if `y` is out of bounds.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::{Grid, Point};
+    /// let mut grid: Grid<char> = Grid::with_size(2, 2);
+    /// grid.insert(Point { x: 0, y: 1 }, 'a');
+    /// let row: Vec<_> = grid.row_cells(1).map(|(_, cell)| cell).collect();
+    /// 
+    /// assert_eq!(row, vec![Some(&'a'), None]);
+    /// ```
+    pub fn row_cells(&self, y: usize) -> impl Iterator<Item = (Point, Option<&T>)> + '_ {
+        let width = if y < self.height { self.width } else { 0 };
+        (0..width).map(move |x| {
+            let point = Point { x, y };
+            (point, self.map.get(&point))
+        })
+    }
+
+    /// Iterates over every point in column `x` with its element, if any, top to bottom.
+    /// Yields nothing if `x` is out of bounds.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::{Grid, Point};
+    /// let mut grid: Grid<char> = Grid::with_size(2, 2);
+    /// grid.insert(Point { x: 0, y: 1 }, 'a');
+    /// let col: Vec<_> = grid.col_cells(0).map(|(_, cell)| cell).collect();
+    /// 
+    /// assert_eq!(col, vec![None, Some(&'a')]);
+    /// ```
+    pub fn col_cells(&self, x: usize) -> impl Iterator<Item = (Point, Option<&T>)> + '_ {
+        let height = if x < self.width { self.height } else { 0 };
+        (0..height).map(move |y| {
+            let point = Point { x, y };
+            (point, self.map.get(&point))
+        })
+    }
+
+    /// Iterates over every point within the bounds of the `Grid` that has no element, left to right,
+    /// then top to bottom.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::{Grid, Point};
+    /// let mut grid: Grid<char> = Grid::with_size(2, 2);
+    /// grid.fill_rect(Point { x: 0, y: 0 }, 2, 1, '#').unwrap();
+    /// let mut points = grid.empty_points();
+    /// 
+    /// assert_eq!(points.next(), Some(Point { x: 0, y: 1 }));
+    /// assert_eq!(points.next(), Some(Point { x: 1, y: 1 }));
+    /// assert_eq!(points.next(), None);
+    /// ```
+    pub fn empty_points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.height)
+            .flat_map(move |y| (0..self.width).map(move |x| Point { x, y }))
+            .filter(|point| !self.map.contains_key(point))
+    }
+
+    /// Returns true if `point` is within the bounds of the `Grid` and has no element.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::{Grid, Point};
+    /// let mut grid: Grid<char> = Grid::new();
+    /// grid.insert(Point { x: 1, y: 0 }, 'a');
+    /// 
+    /// assert!(grid.is_empty_at(Point { x: 0, y: 0 }));
+    /// assert!(!grid.is_empty_at(Point { x: 1, y: 0 }));
+    /// assert!(!grid.is_empty_at(Point { x: 2, y: 0 }));
+    /// ```
+    pub fn is_empty_at<P>(&self, point: P) -> bool
+    where
+        P: Into<Point>,
+    {
+        let point = point.into();
+        self.check_inbounds(point) && !self.map.contains_key(&point)
+    }
+
+    /// Iterates over the occupied orthogonal neighbors of `point`, yielding the [Direction] moved in,
+    /// the neighboring point, and its element.
+    /// 
+    /// Follows the order of [Direction::DIRS_CLOCKWISE], skipping empty and out of bounds positions.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::{Direction, Grid, Point};
+    /// let grid: Grid<char> = "ab\ncd".into();
+    /// let mut entries = grid.neighbor_entries(Point { x: 0, y: 0 });
+    /// 
+    /// assert_eq!(entries.next(), Some((Direction::East, Point { x: 1, y: 0 }, &'b')));
+    /// assert_eq!(entries.next(), Some((Direction::South, Point { x: 0, y: 1 }, &'c')));
+    /// assert_eq!(entries.next(), None);
+    /// ```
+    pub fn neighbor_entries(&self, point: Point) -> impl Iterator<Item = (Direction, Point, &T)> + '_ {
+        Direction::DIRS_CLOCKWISE.into_iter()
+            .filter_map(move |dir| {
+                let neighbor = point.step(dir)?;
+                Some((dir, neighbor, self.map.get(&neighbor)?))
+            })
+    }
+
     /// Iterates over neighboring elements to `point` in `Grid`.
     /// Skips over empty postions.
     /// 
@@ -371,7 +1232,7 @@ impl<T> Grid<T> {
     /// let grid: Grid<_> = input.into();
     /// 
     /// // Neighbors of center
-    /// let mut n_iter = grid.neighbors_iter(&Point { x: 1, y: 1});
+    /// let mut n_iter = grid.neighbors_iter(Point { x: 1, y: 1});
     /// assert_eq!(n_iter.next(), Some(&'b'));
     /// assert_eq!(n_iter.next(), Some(&'d'));
     /// assert_eq!(n_iter.next(), Some(&'f'));
@@ -379,12 +1240,16 @@ impl<T> Grid<T> {
     /// assert_eq!(n_iter.next(), None);
     /// 
     /// // Neighbors of corner
-    /// let mut n_iter = grid.neighbors_iter(&Point { x: 0, y: 2});
+    /// let mut n_iter = grid.neighbors_iter(Point { x: 0, y: 2});
     /// assert_eq!(n_iter.next(), Some(&'d'));
     /// assert_eq!(n_iter.next(), Some(&'h'));
     /// assert_eq!(n_iter.next(), None);
     /// ```
-    pub fn neighbors_iter(&self, point: &Point) -> GridNeighbors<T> {
+    pub fn neighbors_iter<P>(&self, point: P) -> GridNeighbors<'_, T>
+    where
+        P: Into<Point>,
+    {
+        let point = point.into();
         const NEIGHBOR_VECS: [Vector2D; 4] = [
             Vector2D { x: 0, y: -1 },
             Vector2D { x: -1, y: 0 },
@@ -409,7 +1274,7 @@ impl<T> Grid<T> {
     /// let grid: Grid<_> = input.into();
     /// 
     /// // Orthogonal neighbors of center
-    /// let mut n_iter = grid.ortho_iter(&Point { x: 1, y: 1});
+    /// let mut n_iter = grid.ortho_iter(Point { x: 1, y: 1});
     /// assert_eq!(n_iter.next(), Some(&'a'));
     /// assert_eq!(n_iter.next(), Some(&'b'));
     /// assert_eq!(n_iter.next(), Some(&'c'));
@@ -421,13 +1286,17 @@ impl<T> Grid<T> {
     /// assert_eq!(n_iter.next(), None);
     /// 
     /// // Orthogonal neighbors of corner
-    /// let mut n_iter = grid.ortho_iter(&Point { x: 2, y: 2});
+    /// let mut n_iter = grid.ortho_iter(Point { x: 2, y: 2});
     /// assert_eq!(n_iter.next(), Some(&'e'));
     /// assert_eq!(n_iter.next(), Some(&'f'));
     /// assert_eq!(n_iter.next(), Some(&'h'));
     /// assert_eq!(n_iter.next(), None);
     /// ```
-    pub fn ortho_iter(&self, point: &Point) -> GridNeighbors<T> {
+    pub fn ortho_iter<P>(&self, point: P) -> GridNeighbors<'_, T>
+    where
+        P: Into<Point>,
+    {
+        let point = point.into();
         const NEIGHBOR_VECS: [Vector2D; 8] = [
             Vector2D { x: -1, y: -1 },
             Vector2D { x: 0, y: -1 },
@@ -463,7 +1332,7 @@ impl<T> Grid<T> {
     /// assert_eq!(r_iter.next(), Some(&'d'));
     /// assert_eq!(r_iter.next(), None);
     /// ```
-    pub fn linear_iter(&self, start: Point, dir: Direction) -> GridLinearIter<T> {
+    pub fn linear_iter(&self, start: Point, dir: Direction) -> GridLinearIter<'_, T> {
         GridLinearIter {
             grid: self,
             next: Some(start),
@@ -498,7 +1367,7 @@ impl<T> Grid<T> {
     /// let mut r_iter = grid.row_iter(3);
     /// assert_eq!(r_iter.next(), None);
     /// ```
-    pub fn row_iter(&self, row: usize) -> GridLinearIter<T> {
+    pub fn row_iter(&self, row: usize) -> GridLinearIter<'_, T> {
         let next = Point { x: 0, y: row }; 
         GridLinearIter {
             grid: self,
@@ -533,7 +1402,7 @@ impl<T> Grid<T> {
     /// let mut r_iter = grid.col_iter(3);
     /// assert_eq!(r_iter.next(), None);
     /// ```
-    pub fn col_iter(&self, col: usize) -> GridLinearIter<T> {
+    pub fn col_iter(&self, col: usize) -> GridLinearIter<'_, T> {
         let next = Point { x: col, y: 0 }; 
         GridLinearIter {
             grid: self,
@@ -542,73 +1411,474 @@ impl<T> Grid<T> {
             current: next,
         }
     }
-}
 
-impl<T: Clone + Eq> Grid<T> {
-    /// Performs a flood fill, starting by inserting or replacing the object at the `start` position with
-    /// a clone of `value`, and then repeating on adjacent positions. Only replaces elements that match `replace`.
+    /// Mutably iterates over elements of the grid starting at Point in given Direction.
+    /// Skips over empty elements.
+    /// 
+    /// Unlike [Grid::linear_iter], this has to scan every element in the `Grid` up front.
+    /// It's implemented without any `unsafe`: the mutable references are split off the
+    /// underlying map's own mutable iterator, so each element is borrowed exactly once.
     /// 
     /// # Examples
+    /// 
     /// ```
-    /// # use aoc::grid::{Grid, Point};
-    /// // Replacing specific elements
-    /// let mut input_grid: Grid<char> = "\
-    ///     XXXX\n\
-    ///     X..X\n\
-    ///     X.XX\n\
-    ///     XXX.".into();
+    /// # use aoc::grid::{Direction, Grid, Point};
+    /// let mut grid: Grid<char> = "\
+    ///     abc\n\
+    ///     def\n\
+    ///     ghi".into();
     /// 
-    /// input_grid.flood_fill(Point { x: 1, y: 1 }, 'O', Some(&'.'));
+    /// for c in grid.linear_iter_mut(Point { x: 1, y: 1 }, Direction::West) {
+    ///     *c = c.to_ascii_uppercase();
+    /// }
+    /// assert_eq!(grid.get(Point { x: 0, y: 1 }), Some(&'D'));
+    /// assert_eq!(grid.get(Point { x: 1, y: 1 }), Some(&'E'));
+    /// assert_eq!(grid.get(Point { x: 2, y: 1 }), Some(&'f'));
+    /// ```
+    pub fn linear_iter_mut(&mut self, start: Point, dir: Direction) -> GridLinearIterMut<'_, T> {
+        let mut cells: Vec<(usize, &mut T)> = self.map.iter_mut()
+            .filter_map(|(point, value)| {
+                let distance = match dir {
+                    Direction::North if point.x == start.x && point.y <= start.y => start.y - point.y,
+                    Direction::South if point.x == start.x && point.y >= start.y => point.y - start.y,
+                    Direction::East if point.y == start.y && point.x >= start.x => point.x - start.x,
+                    Direction::West if point.y == start.y && point.x <= start.x => start.x - point.x,
+                    _ => return None,
+                };
+                Some((distance, value))
+            })
+            .collect();
+        cells.sort_unstable_by_key(|(distance, _)| *distance);
+        GridLinearIterMut { cells: cells.into_iter() }
+    }
+
+    /// Mutably iterates over elements of the grid with the given row index, from left to right.
+    /// Skips over empty elements.
     /// 
-    /// let output_grid: Grid<char> = "\
-    ///     XXXX\n\
-    ///     XOOX\n\
-    ///     XOXX\n\
-    ///     XXX.".into();
+    /// See [Grid::linear_iter_mut].
+    pub fn row_iter_mut(&mut self, row: usize) -> GridLinearIterMut<'_, T> {
+        self.linear_iter_mut(Point { x: 0, y: row }, Direction::East)
+    }
+
+    /// Mutably iterates over elements of the grid with the given column index, from top to bottom.
+    /// Skips over empty elements.
     /// 
-    /// assert_eq!(input_grid, output_grid);
+    /// See [Grid::linear_iter_mut].
+    pub fn col_iter_mut(&mut self, col: usize) -> GridLinearIterMut<'_, T> {
+        self.linear_iter_mut(Point { x: col, y: 0 }, Direction::South)
+    }
+
+    /// Iterates over every point on the outer edge of the `Grid`, each exactly once.
     /// 
-    /// // Replacing empty grid elements
-    /// let mut input_grid: Grid<char> = Grid::new();
-    /// input_grid.insert(Point { x: 2, y: 2}, 'X');
-    /// input_grid.insert(Point { x: 3, y: 3}, 'X');
-    /// input_grid.flood_fill(Point { x: 0, y: 0 }, 'O', None);
+    /// Points are yielded clockwise, starting from the top left corner.
     /// 
-    /// let output_grid: Grid<char> = "\
-    ///     OOOO\n\
-    ///     OOOO\n\
-    ///     OOXO\n\
-    ///     OOOX".into();
+    /// # Example
     /// 
-    /// assert_eq!(input_grid, output_grid);
     /// ```
-    pub fn flood_fill<P> (&mut self, start: P, value: T, replace: Option<&T>)
-    where
-        P: Into<Point>,
-    {
-        let start = start.into();
-        let mut frontier: BTreeSet<Point> = BTreeSet::new();
-        frontier.insert(start);
-        while let Some(point) = frontier.pop_first() {
-            if self.check_inbounds(point) && replace == self.get(point) {
-                self.insert(point, value.clone());
-                frontier.extend(point.neighbors())
-            }
+    /// # use aoc::grid::{Grid, Point};
+    /// let grid: Grid<char> = "\
+    ///     abc\n\
+    ///     def\n\
+    ///     ghi".into();
+    /// 
+    /// let border: Vec<Point> = grid.border_points().collect();
+    /// assert_eq!(border, vec![
+    ///     Point { x: 0, y: 0 }, Point { x: 1, y: 0 }, Point { x: 2, y: 0 },
+    ///     Point { x: 2, y: 1 }, Point { x: 2, y: 2 }, Point { x: 1, y: 2 },
+    ///     Point { x: 0, y: 2 }, Point { x: 0, y: 1 },
+    /// ]);
+    /// ```
+    pub fn border_points(&self) -> impl Iterator<Item = Point> {
+        let (width, height) = (self.width, self.height);
+        if width == 0 || height == 0 {
+            return None.into_iter().flatten();
         }
-    }
-}
+        // Only walk back along the bottom and left edges when they're distinct from the top and right
+        let bottom_len = if height > 1 { width - 1 } else { 0 };
+        let left_len = if width > 1 { height - 1 } else { 1 };
 
-impl<T> Default for Grid<T> {
-    fn default() -> Self {
-        Grid::new()
+        let top = (0..width).map(|x| Point { x, y: 0 });
+        let right = (1..height).map(move |y| Point { x: width - 1, y });
+        let bottom = (0..bottom_len).rev().map(move |x| Point { x, y: height - 1 });
+        let left = (1..left_len).rev().map(|y| Point { x: 0, y });
+        Some(top.chain(right).chain(bottom).chain(left)).into_iter().flatten()
     }
-}
 
-impl<T, S> From<S> for Grid<T>
-where
-    T: TryFrom<char>,
-    S: Into<String>,
-{
+    /// Iterates over every point on the outer edge of the `Grid` along with its element, if any.
+    /// 
+    /// Follows the same order as [Grid::border_points].
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::{Grid, Point};
+    /// let mut grid: Grid<char> = Grid::new();
+    /// grid.insert(Point { x: 1, y: 0 }, 'a');
+    /// 
+    /// let mut border_iter = grid.border_iter();
+    /// assert_eq!(border_iter.next(), Some((Point { x: 0, y: 0 }, None)));
+    /// assert_eq!(border_iter.next(), Some((Point { x: 1, y: 0 }, Some(&'a'))));
+    /// assert_eq!(border_iter.next(), None);
+    /// ```
+    pub fn border_iter(&self) -> impl Iterator<Item = (Point, Option<&T>)> {
+        self.border_points().map(|point| (point, self.get(point)))
+    }
+
+    /// Returns a zero-copy [GridView] of the `Grid`, which can be transposed and flipped.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::{Grid, Point};
+    /// let grid: Grid<char> = "\
+    ///     abc\n\
+    ///     def".into();
+    /// 
+    /// let view = grid.view().transposed();
+    /// assert_eq!((view.width(), view.height()), (2, 3));
+    /// assert_eq!(view.get(Point { x: 1, y: 0 }), Some(&'d'));
+    /// 
+    /// let view = grid.view().flipped_h();
+    /// assert_eq!(view.get(Point { x: 0, y: 0 }), Some(&'c'));
+    /// ```
+    pub fn view(&self) -> GridView<'_, T> {
+        GridView { grid: self, transposed: false, flip_x: false, flip_y: false }
+    }
+
+    /// Returns a [GridDisplay] for configuring how the `Grid` is printed.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::{Grid, Point};
+    /// let mut grid: Grid<char> = Grid::new();
+    /// grid.insert(Point { x: 0, y: 0 }, 'a');
+    /// grid.insert(Point { x: 1, y: 1 }, 'b');
+    /// 
+    /// assert_eq!(grid.display_options().to_string(), grid.to_string());
+    /// assert_eq!(grid.display_options().empty_char('.').separator(" ").to_string(), "a .\n. b\n");
+    /// ```
+    pub fn display_options(&self) -> GridDisplay<'_, T> {
+        GridDisplay { grid: self, empty: ' ', separator: "", coordinates: false }
+    }
+}
+
+impl<T: Display> Grid<T> {
+    /// Renders the `Grid` one character per position with no padding, using `empty` for empty positions.
+    /// Each row, including the last, ends with a newline.
+    /// 
+    /// Intended for elements that display as a single character. Longer renderings are trimmed to their
+    /// first character, and elements that render as an empty string are treated as empty positions.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::{Grid, Point};
+    /// let mut grid: Grid<char> = Grid::new();
+    /// grid.insert(Point { x: 2, y: 0 }, '#');
+    /// grid.insert(Point { x: 1, y: 1 }, '#');
+    /// 
+    /// assert_eq!(grid.to_display_string('.'), "..#\n.#.\n");
+    /// ```
+    pub fn to_display_string(&self, empty: char) -> String {
+        let mut output = String::with_capacity((self.width + 1) * self.height);
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let c = self.get(Point { x, y })
+                    .and_then(|value| value.to_string().chars().next())
+                    .unwrap_or(empty);
+                output.push(c);
+            }
+            output.push('\n');
+        }
+        output
+    }
+}
+
+impl<T> Grid<T> {
+    /// Constructs a new `Grid<T>` from ASCII bytes, one row per line.
+    /// 
+    /// Walks the input bytes directly, building the grid in a single pass without per-line allocations.
+    /// Carriage returns are ignored. Positions where `cell` returns `None` are left empty.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::{Grid, Point};
+    /// let grid: Grid<bool> = Grid::from_ascii(b"#.\r\n.#", |b| (b == b'#').then_some(true));
+    /// 
+    /// assert_eq!((grid.width(), grid.height()), (2, 2));
+    /// assert_eq!(grid.get(Point { x: 1, y: 1 }), Some(&true));
+    /// assert_eq!(grid.get(Point { x: 1, y: 0 }), None);
+    /// ```
+    pub fn from_ascii(input: &[u8], cell: impl Fn(u8) -> Option<T>) -> Self {
+        let mut map = HashMap::new();
+        let (mut x, mut y) = (0, 0);
+        let mut width = 0;
+        for &byte in input {
+            match byte {
+                b'\n' => {
+                    width = width.max(x);
+                    x = 0;
+                    y += 1;
+                },
+                b'\r' => (),
+                _ => {
+                    if let Some(value) = cell(byte) {
+                        map.insert(Point { x, y }, value);
+                    }
+                    x += 1;
+                },
+            }
+        }
+        width = width.max(x);
+        // Like `str::lines`, a trailing newline doesn't start another row
+        let height = if x > 0 { y + 1 } else { y };
+        Grid { map, width, height, bounds: BoundsPolicy::Grow }
+    }
+
+    /// Constructs a new `Grid<T>` from ASCII bytes using `T`'s [TryFrom<u8>] implementation.
+    /// 
+    /// Bytes that fail to convert are left as empty positions. See [Grid::from_ascii].
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::{Grid, Point};
+    /// let grid: Grid<char> = Grid::from_bytes(b"ab\ncd");
+    /// 
+    /// assert_eq!(grid.get(Point { x: 0, y: 1 }), Some(&'c'));
+    /// ```
+    pub fn from_bytes(input: &[u8]) -> Self
+    where
+        T: TryFrom<u8>,
+    {
+        Grid::from_ascii(input, |byte| T::try_from(byte).ok())
+    }
+}
+
+impl<T: From<u8>> Grid<T> {
+    /// Constructs a new `Grid<T>` from a string of decimal digits, one row per line.
+    /// 
+    /// Walks the input bytes directly, building the grid in a single pass. Carriage returns are ignored.
+    /// Returns a [GridParseError] for any other non-digit character.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::{Grid, GridParseError, Point};
+    /// let grid: Grid<u32> = Grid::from_digits("12\n34").unwrap();
+    /// assert_eq!(grid.get(Point { x: 1, y: 1 }), Some(&4));
+    /// 
+    /// let grid: Grid<u8> = Grid::from_digits("12\n34").unwrap();
+    /// assert_eq!(grid.get(Point { x: 0, y: 1 }), Some(&3));
+    /// 
+    /// let error = Grid::<u32>::from_digits("12\n3x").unwrap_err();
+    /// assert_eq!(error, GridParseError::InvalidChar { c: 'x', point: Point { x: 1, y: 1 } });
+    /// ```
+    pub fn from_digits(input: &str) -> Result<Self, GridParseError> {
+        let mut map = HashMap::with_capacity(input.len());
+        let (mut x, mut y) = (0, 0);
+        let mut width = 0;
+        for (i, byte) in input.bytes().enumerate() {
+            match byte {
+                b'0'..=b'9' => {
+                    map.insert(Point { x, y }, T::from(byte - b'0'));
+                    x += 1;
+                },
+                b'\n' => {
+                    width = width.max(x);
+                    x = 0;
+                    y += 1;
+                },
+                b'\r' => (),
+                _ => {
+                    let c = input[i..].chars().next().unwrap_or(char::REPLACEMENT_CHARACTER);
+                    return Err(GridParseError::InvalidChar { c, point: Point { x, y } });
+                },
+            }
+        }
+        width = width.max(x);
+        // Like `str::lines`, a trailing newline doesn't start another row
+        let height = if x > 0 { y + 1 } else { y };
+        Ok(Grid { map, width, height, bounds: BoundsPolicy::Grow })
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// Finds every axis the `Grid` is mirrored across, where the two sides differ in exactly `smudges` positions.
+    /// 
+    /// Positions that fall outside the grid when reflected are ignored. Empty positions only match other empty
+    /// positions. Vertical axes are returned first, then horizontal axes, each in increasing order.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::{Grid, Reflection};
+    /// let grid: Grid<char> = "\
+    ///     #..#.\n\
+    ///     .##..\n\
+    ///     .##.#".into();
+    /// 
+    /// assert_eq!(grid.mirror_axes(0), vec![Reflection::Vertical(2)]);
+    /// assert_eq!(grid.mirror_axes(1), vec![Reflection::Horizontal(2)]);
+    /// ```
+    pub fn mirror_axes(&self, smudges: usize) -> Vec<Reflection> {
+        let vertical = (1..self.width)
+            .filter(|&axis| {
+                let reach = axis.min(self.width - axis);
+                self.count_mirror_diffs(smudges, (0..self.height).flat_map(|y| {
+                    (0..reach).map(move |d| (Point { x: axis - 1 - d, y }, Point { x: axis + d, y }))
+                }))
+            })
+            .map(Reflection::Vertical);
+        let horizontal = (1..self.height)
+            .filter(|&axis| {
+                let reach = axis.min(self.height - axis);
+                self.count_mirror_diffs(smudges, (0..self.width).flat_map(|x| {
+                    (0..reach).map(move |d| (Point { x, y: axis - 1 - d }, Point { x, y: axis + d }))
+                }))
+            })
+            .map(Reflection::Horizontal);
+        vertical.chain(horizontal).collect()
+    }
+
+    /// Compares `self` against `other` and returns every point whose elements differ, in reading order.
+    /// 
+    /// Only elements are compared, not the width or height of either grid.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use aoc::grid::{CellDiff, Grid, Point};
+    /// let left: Grid<char> = "ab\ncd".into();
+    /// let right: Grid<char> = "ax\ncd".into();
+    /// let diff = left.diff(&right);
+    /// 
+    /// assert_eq!(diff.len(), 1);
+    /// assert_eq!(diff[0].point, Point { x: 1, y: 0 });
+    /// assert_eq!(diff[0].change, CellDiff::Changed(&'b', &'x'));
+    /// assert_eq!(diff[0].to_string(), "(1, 0): 'b' -> 'x'");
+    /// ```
+    pub fn diff<'a>(&'a self, other: &'a Grid<T>) -> Vec<GridDiff<'a, T>> {
+        let mut diffs: Vec<_> = self.map.iter()
+            .filter_map(|(point, left)| {
+                let change = match other.map.get(point) {
+                    Some(right) if right == left => return None,
+                    Some(right) => CellDiff::Changed(left, right),
+                    None => CellDiff::OnlyLeft(left),
+                };
+                Some(GridDiff { point: *point, change })
+            })
+            .chain(other.map.iter()
+                .filter(|(point, _)| !self.map.contains_key(point))
+                .map(|(point, right)| GridDiff { point: *point, change: CellDiff::OnlyRight(right) }))
+            .collect();
+        diffs.sort_unstable_by_key(|diff| (diff.point.y, diff.point.x));
+        diffs
+    }
+
+    // True if exactly `smudges` of the mirrored pairs differ, stopping early once there are too many
+    fn count_mirror_diffs(&self, smudges: usize, pairs: impl Iterator<Item = (Point, Point)>) -> bool {
+        let mut diffs = 0;
+        for (a, b) in pairs {
+            if self.get(a) != self.get(b) {
+                diffs += 1;
+                if diffs > smudges {
+                    return false;
+                }
+            }
+        }
+        diffs == smudges
+    }
+}
+
+impl<T: Clone + Eq> Grid<T> {
+    /// Performs a flood fill, starting by inserting or replacing the object at the `start` position with
+    /// a clone of `value`, and then repeating on adjacent positions. Only replaces elements that match `replace`.
+    /// 
+    /// # Examples
+    /// ```
+    /// # use aoc::grid::{Grid, Point};
+    /// // Replacing specific elements
+    /// let mut input_grid: Grid<char> = "\
+    ///     XXXX\n\
+    ///     X..X\n\
+    ///     X.XX\n\
+    ///     XXX.".into();
+    /// 
+    /// input_grid.flood_fill(Point { x: 1, y: 1 }, 'O', Some(&'.'));
+    /// 
+    /// assert_eq!(input_grid.to_display_string(' '), "\
+    ///     XXXX\n\
+    ///     XOOX\n\
+    ///     XOXX\n\
+    ///     XXX.\n");
+    /// 
+    /// // Replacing empty grid elements
+    /// let mut input_grid: Grid<char> = Grid::new();
+    /// input_grid.insert(Point { x: 2, y: 2}, 'X');
+    /// input_grid.insert(Point { x: 3, y: 3}, 'X');
+    /// input_grid.flood_fill(Point { x: 0, y: 0 }, 'O', None);
+    /// 
+    /// let output_grid: Grid<char> = "\
+    ///     OOOO\n\
+    ///     OOOO\n\
+    ///     OOXO\n\
+    ///     OOOX".into();
+    /// 
+    /// assert_eq!(input_grid, output_grid);
+    /// ```
+    pub fn flood_fill<P> (&mut self, start: P, value: T, replace: Option<&T>)
+    where
+        P: Into<Point>,
+    {
+        let start = start.into();
+        let mut frontier: BTreeSet<Point> = BTreeSet::new();
+        frontier.insert(start);
+        while let Some(point) = frontier.pop_first() {
+            if self.check_inbounds(point) && replace == self.get(point) {
+                self.map.insert(point, value.clone());
+                frontier.extend(point.neighbors())
+            }
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for Grid<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.width == other.width && self.height == other.height && self.map == other.map
+    }
+}
+
+impl<T: Eq> Eq for Grid<T> {}
+
+impl<T: Hash> Hash for Grid<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.width.hash(state);
+        self.height.hash(state);
+        // Hash occupied cells in sorted order so the hash doesn't depend on HashMap iteration order
+        let mut cells: Vec<(&Point, &T)> = self.map.iter().collect();
+        cells.sort_unstable_by_key(|(point, _)| **point);
+        cells.len().hash(state);
+        for (point, value) in cells {
+            point.hash(state);
+            value.hash(state);
+        }
+    }
+}
+
+impl<T> Default for Grid<T> {
+    fn default() -> Self {
+        Grid::new()
+    }
+}
+
+impl<T, S> From<S> for Grid<T>
+where
+    T: TryFrom<char>,
+    S: Into<String>,
+{
     fn from(input: S) -> Self {
         let grid_string = input.into();
         let mut map = HashMap::new();
@@ -622,10 +1892,230 @@ where
                 }
             }
         }
-        Grid { map, width, height }
+        Grid { map, width, height, bounds: BoundsPolicy::Grow }
+    }
+}
+
+/// Read-only access to a rectangular area of elements, shared by [Grid] and [GridView].
+/// 
+/// Algorithms written against `GridLike` work on views without copying the grid.
+pub trait GridLike<T> {
+    fn width(&self) -> usize;
+    fn height(&self) -> usize;
+    fn get(&self, point: Point) -> Option<&T>;
+}
+
+impl<T> GridLike<T> for Grid<T> {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    fn get(&self, point: Point) -> Option<&T> {
+        self.map.get(&point)
+    }
+}
+
+/// A transposed and/or flipped view into a [Grid], created by [Grid::view].
+/// 
+/// Coordinates are mapped onto the underlying `Grid` on the fly, so views are cheap to create
+/// and compose.
+#[derive(Debug)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    transposed: bool,
+    // Flips are applied in view space, before transposing
+    flip_x: bool,
+    flip_y: bool,
+}
+
+impl<'a, T> Clone for GridView<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for GridView<'a, T> {}
+
+impl<'a, T> GridView<'a, T> {
+    /// Returns a view with x and y swapped.
+    pub fn transposed(self) -> Self {
+        GridView { grid: self.grid, transposed: !self.transposed, flip_x: self.flip_y, flip_y: self.flip_x }
+    }
+
+    /// Returns a view mirrored left to right.
+    pub fn flipped_h(self) -> Self {
+        GridView { flip_x: !self.flip_x, ..self }
+    }
+
+    /// Returns a view mirrored top to bottom.
+    pub fn flipped_v(self) -> Self {
+        GridView { flip_y: !self.flip_y, ..self }
+    }
+
+    /// Returns the width of the view.
+    pub fn width(&self) -> usize {
+        if self.transposed { self.grid.height } else { self.grid.width }
+    }
+
+    /// Returns the height of the view.
+    pub fn height(&self) -> usize {
+        if self.transposed { self.grid.width } else { self.grid.height }
+    }
+
+    /// Returns `Some(&T)` if an element is in the view at that point, otherwise `None`.
+    pub fn get<P>(&self, point: P) -> Option<&'a T>
+    where
+        P: Into<Point>,
+    {
+        let point = point.into();
+        if point.x >= self.width() || point.y >= self.height() {
+            return None;
+        }
+        let x = if self.flip_x { self.width() - 1 - point.x } else { point.x };
+        let y = if self.flip_y { self.height() - 1 - point.y } else { point.y };
+        if self.transposed {
+            self.grid.get(Point { x: y, y: x })
+        } else {
+            self.grid.get(Point { x, y })
+        }
     }
 }
 
+impl<'a, T> GridLike<T> for GridView<'a, T> {
+    fn width(&self) -> usize {
+        GridView::width(self)
+    }
+
+    fn height(&self) -> usize {
+        GridView::height(self)
+    }
+
+    fn get(&self, point: Point) -> Option<&T> {
+        GridView::get(self, point)
+    }
+}
+
+/// A point where two grids differ, found by [Grid::diff].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GridDiff<'a, T> {
+    pub point: Point,
+    pub change: CellDiff<'a, T>,
+}
+
+/// How the elements at a point differ between the left and right grids of a [Grid::diff].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CellDiff<'a, T> {
+    /// Only the left grid has an element at the point.
+    OnlyLeft(&'a T),
+    /// Only the right grid has an element at the point.
+    OnlyRight(&'a T),
+    /// Both grids have an element at the point, but they aren't equal.
+    Changed(&'a T, &'a T),
+}
+
+impl<'a, T: Debug> Display for GridDiff<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.change {
+            CellDiff::OnlyLeft(left) => write!(f, "{}: {:?} -> (empty)", self.point, left),
+            CellDiff::OnlyRight(right) => write!(f, "{}: (empty) -> {:?}", self.point, right),
+            CellDiff::Changed(left, right) => write!(f, "{}: {:?} -> {:?}", self.point, left, right),
+        }
+    }
+}
+
+/// An axis a [Grid] is mirrored across, found by [Grid::mirror_axes].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Reflection {
+    /// Mirrored across a horizontal line, with the given number of rows above it.
+    Horizontal(usize),
+    /// Mirrored across a vertical line, with the given number of columns left of it.
+    Vertical(usize),
+}
+
+/// Describes how a cell of a closed loop's boundary crosses the row it's in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Crossing {
+    /// Boundary continues both up and down, e.g. `|`.
+    Vertical,
+    /// Boundary turns upwards, e.g. `L` or `J`.
+    North,
+    /// Boundary turns downwards, e.g. `F` or `7`.
+    South,
+    /// Boundary continues along the row, e.g. `-`.
+    Horizontal,
+}
+
+/// Counts the points strictly inside the closed loop formed by `boundary`.
+/// 
+/// Each row is ray cast from left to right, and a point is inside when the ray has crossed the
+/// boundary an odd number of times. `crossing` describes the shape of the loop at each boundary point.
+/// Only boundary cells connecting upwards are counted as crossings, so a horizontal run that
+/// enters and leaves in the same direction (`L-J`) doesn't cross, while one that doesn't (`L-7`) does.
+/// 
+/// # Example
+/// 
+/// ```
+/// # use std::collections::HashSet;
+/// # use aoc::grid::{count_interior, Crossing, Grid, GridIterator, Point};
+/// let grid: Grid<char> = "\
+///     F--7\n\
+///     |..|\n\
+///     L--J".into();
+/// let boundary: HashSet<Point> = grid.iter().indexed()
+///     .filter_map(|(p, c)| if *c != '.' { Some(p) } else { None })
+///     .collect();
+/// 
+/// let interior = count_interior(&boundary, |p| match grid.get(p) {
+///     Some('|') => Crossing::Vertical,
+///     Some('L' | 'J') => Crossing::North,
+///     Some('F' | '7') => Crossing::South,
+///     _ => Crossing::Horizontal,
+/// });
+/// assert_eq!(interior, 2);
+/// ```
+pub fn count_interior(boundary: &HashSet<Point>, crossing: impl Fn(Point) -> Crossing) -> usize {
+    let Some(max_x) = boundary.iter().map(|p| p.x).max() else { return 0 };
+    let min_y = boundary.iter().map(|p| p.y).min().unwrap_or(0);
+    let max_y = boundary.iter().map(|p| p.y).max().unwrap_or(0);
+
+    let mut count = 0;
+    for y in min_y..=max_y {
+        let mut inside = false;
+        for x in 0..=max_x {
+            let point = Point { x, y };
+            if boundary.contains(&point) {
+                if matches!(crossing(point), Crossing::Vertical | Crossing::North) {
+                    inside = !inside;
+                }
+            } else if inside {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+/// An error that occurs when parsing a [Grid] from a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridParseError {
+    /// A character that isn't valid in the grid was found at `point`.
+    InvalidChar { c: char, point: Point },
+}
+
+impl Display for GridParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridParseError::InvalidChar { c, point } => write!(f, "Invalid character {c:?} at {point}"),
+        }
+    }
+}
+
+impl std::error::Error for GridParseError {}
+
 pub trait GridIterator<'a, T: 'a>: Iterator<Item = &'a T> {
     fn current_index(&self) -> Point;
     fn indexed(self) -> IndexedGridIter<'a, T> where Self: Sized + 'a {
@@ -716,6 +2206,18 @@ impl<'a, T> GridIterator<'a, T> for GridLinearIter<'a, T> {
     }
 }
 
+pub struct GridLinearIterMut<'a, T> {
+    cells: std::vec::IntoIter<(usize, &'a mut T)>,
+}
+
+impl<'a, T> Iterator for GridLinearIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.cells.next().map(|(_, value)| value)
+    }
+}
+
 pub struct GridNeighbors<'a, T> {
     grid: &'a Grid<T>,
     index: usize,
@@ -749,12 +2251,63 @@ impl<'a, T> GridIterator<'a, T> for GridNeighbors<'a, T> {
 
 impl<T: Display> Display for Grid<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let width = self.iter().map(|v| format!("{}", v).chars().count()).max().unwrap_or(0);
-        for y in 0..self.height {
-            for x in 0..self.width {
-                match self.get(Point { x, y }) {
+        self.display_options().fmt(f)
+    }
+}
+
+/// A configurable [Display] wrapper for a [Grid], created by [Grid::display_options].
+/// 
+/// With no options set, output is identical to the `Grid`'s own `Display` implementation.
+pub struct GridDisplay<'a, T> {
+    grid: &'a Grid<T>,
+    empty: char,
+    separator: &'a str,
+    coordinates: bool,
+}
+
+impl<'a, T> GridDisplay<'a, T> {
+    /// Sets the character printed for empty positions. Defaults to a space.
+    pub fn empty_char(mut self, empty: char) -> Self {
+        self.empty = empty;
+        self
+    }
+
+    /// Sets a separator printed between columns. Defaults to nothing.
+    pub fn separator(mut self, separator: &'a str) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Sets whether to print column indices above the grid and row indices to the left of it.
+    pub fn with_coordinates(mut self, coordinates: bool) -> Self {
+        self.coordinates = coordinates;
+        self
+    }
+}
+
+impl<'a, T: Display> Display for GridDisplay<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let grid = self.grid;
+        let mut width = grid.iter().map(|v| format!("{}", v).chars().count()).max().unwrap_or(0);
+        let row_label_width = grid.height.saturating_sub(1).to_string().len();
+        if self.coordinates {
+            width = width.max(grid.width.saturating_sub(1).to_string().len());
+            write!(f, "{:row_label_width$} ", "")?;
+            for x in 0..grid.width {
+                if x > 0 { write!(f, "{}", self.separator)?; }
+                write!(f, "{:^width$}", x)?;
+            }
+            writeln!(f)?;
+        }
+        for y in 0..grid.height {
+            if self.coordinates {
+                write!(f, "{:>row_label_width$} ", y)?;
+            }
+            for x in 0..grid.width {
+                if x > 0 { write!(f, "{}", self.separator)?; }
+                match grid.get(Point { x, y }) {
                     Some(value) => { write!(f, "{:^width$}", value)? }
-                    None => { write!(f, "{:width$}", " ")? },
+                    None => { write!(f, "{:^width$}", self.empty)? },
                 }
             }
             writeln!(f)?;
@@ -790,9 +2343,759 @@ mod tests {
         assert_eq!(row_iter.next(), None);
 
         // Neighbor iterator
-        let mut n_iter = grid.neighbors_iter(&Point { x: 0, y: 0 }).indexed();
+        let mut n_iter = grid.neighbors_iter(Point { x: 0, y: 0 }).indexed();
         assert_eq!(n_iter.next(), Some((Point { x: 1, y: 0 }, &'b')));
         assert_eq!(n_iter.next(), Some((Point { x: 0, y: 1 }, &'c')));
         assert_eq!(n_iter.next(), None);
     }
+
+    #[test]
+    fn test_from_digits_large() {
+        let size = 1000;
+        let input: String = (0..size)
+            .map(|y| (0..size).map(|x| char::from(b'0' + ((x * 7 + y * 3) % 10) as u8)).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let grid: Grid<u32> = Grid::from_digits(&input).unwrap();
+        assert_eq!((grid.width(), grid.height()), (size, size));
+        assert_eq!(grid.get(Point { x: 999, y: 999 }), Some(&((999 * 7 + 999 * 3) % 10)));
+
+        let expected = Grid::from_2d_vec(
+            input.lines()
+                .map(|line| line.chars().map(|c| c.to_digit(10).unwrap()).collect())
+                .collect()
+        );
+        assert_eq!(grid, expected);
+    }
+
+    #[test]
+    fn test_from_digits_errors() {
+        assert_eq!(
+            Grid::<u8>::from_digits("123\n4.6"),
+            Err(GridParseError::InvalidChar { c: '.', point: Point { x: 1, y: 1 } }),
+        );
+        assert_eq!(
+            Grid::<u8>::from_digits("12é"),
+            Err(GridParseError::InvalidChar { c: 'é', point: Point { x: 2, y: 0 } }),
+        );
+        assert_eq!(Grid::<u8>::from_digits("-1").unwrap_err().to_string(), "Invalid character '-' at (0, 0)");
+
+        // Line endings aren't errors
+        let grid = Grid::<u8>::from_digits("12\r\n34\r\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        let grid = Grid::<u8>::from_digits("").unwrap();
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+    }
+
+    #[test]
+    fn test_grid_hash() {
+        use std::collections::{hash_map::DefaultHasher, HashSet};
+
+        fn hash_of(grid: &Grid<char>) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            grid.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let points: Vec<Point> = (0..5).flat_map(|y| (0..5).map(move |x| Point { x, y })).collect();
+        let mut forward = Grid::new();
+        for (i, point) in points.iter().enumerate() {
+            forward.insert(*point, char::from(b'a' + i as u8));
+        }
+        let mut backward = Grid::new();
+        for (i, point) in points.iter().enumerate().rev() {
+            backward.insert(*point, char::from(b'a' + i as u8));
+        }
+        assert_eq!(forward, backward);
+        assert_eq!(hash_of(&forward), hash_of(&backward));
+
+        let mut changed = Grid::new();
+        for (i, point) in points.iter().enumerate() {
+            changed.insert(*point, char::from(b'a' + i as u8));
+        }
+        changed.insert(Point { x: 2, y: 2 }, 'Z');
+
+        let set: HashSet<Grid<char>> = HashSet::from([forward]);
+        assert!(set.contains(&backward));
+        assert!(!set.contains(&changed));
+    }
+
+    #[test]
+    fn test_display_options() {
+        let mut grid: Grid<u32> = Grid::new();
+        grid.insert(Point { x: 0, y: 0 }, 1);
+        grid.insert(Point { x: 2, y: 0 }, 23);
+        grid.insert(Point { x: 1, y: 2 }, 4);
+
+        // Default output is unchanged
+        assert_eq!(grid.to_string(), "1   23\n      \n  4   \n");
+        assert_eq!(grid.display_options().to_string(), grid.to_string());
+
+        assert_eq!(
+            grid.display_options().empty_char('.').to_string(),
+            "1 . 23\n. . . \n. 4 . \n",
+        );
+        assert_eq!(
+            grid.display_options().empty_char('.').separator("|").to_string(),
+            "1 |. |23\n. |. |. \n. |4 |. \n",
+        );
+        assert_eq!(
+            grid.display_options().separator(" ").with_coordinates(true).to_string(),
+            "  0  1  2 \n0 1     23\n1         \n2    4    \n",
+        );
+    }
+
+    #[test]
+    fn test_linear_iter_mut() {
+        let mut grid: Grid<u32> = Grid::from_digits("123\n456\n789").unwrap();
+        grid.map.remove(&Point { x: 1, y: 1 });
+
+        // Every occupied cell in the column is visited in order, skipping the hole
+        let visited: Vec<u32> = grid.col_iter_mut(1).map(|v| *v).collect();
+        assert_eq!(visited, vec![2, 8]);
+        for value in grid.col_iter_mut(1) {
+            *value *= 10;
+        }
+        assert_eq!(grid.get(Point { x: 1, y: 0 }), Some(&20));
+        assert_eq!(grid.get(Point { x: 1, y: 1 }), None);
+        assert_eq!(grid.get(Point { x: 1, y: 2 }), Some(&80));
+        assert_eq!(grid.get(Point { x: 0, y: 0 }), Some(&1));
+
+        let visited: Vec<u32> = grid.row_iter_mut(2).map(|v| *v).collect();
+        assert_eq!(visited, vec![7, 80, 9]);
+        let visited: Vec<u32> = grid.linear_iter_mut(Point { x: 2, y: 2 }, Direction::North).map(|v| *v).collect();
+        assert_eq!(visited, vec![9, 6, 3]);
+        assert_eq!(grid.row_iter_mut(3).next(), None);
+    }
+
+    #[test]
+    fn test_grid_view() {
+        fn assert_same<T: Debug + PartialEq>(view: &impl GridLike<T>, expected: &Grid<T>) {
+            assert_eq!((view.width(), view.height()), (expected.width(), expected.height()));
+            for y in 0..expected.height() + 1 {
+                for x in 0..expected.width() + 1 {
+                    assert_eq!(view.get(Point { x, y }), expected.get(Point { x, y }), "at {}", Point { x, y });
+                }
+            }
+        }
+
+        // Sparse and asymmetric
+        let mut grid = Grid::new();
+        grid.insert(Point { x: 0, y: 0 }, 'a');
+        grid.insert(Point { x: 3, y: 0 }, 'b');
+        grid.insert(Point { x: 1, y: 1 }, 'c');
+        grid.insert(Point { x: 2, y: 2 }, 'd');
+        assert_same(&grid.view(), &grid);
+
+        let mut transposed = Grid::new();
+        for (point, value) in grid.iter().indexed() {
+            transposed.insert(Point { x: point.y, y: point.x }, *value);
+        }
+        assert_same(&grid.view().transposed(), &transposed);
+        assert_same(&grid.view().transposed().transposed(), &grid);
+
+        let mut flipped_h = Grid::new();
+        for (point, value) in grid.iter().indexed() {
+            flipped_h.insert(Point { x: grid.width() - 1 - point.x, y: point.y }, *value);
+        }
+        assert_same(&grid.view().flipped_h(), &flipped_h);
+
+        let mut flipped_v = Grid::new();
+        for (point, value) in grid.iter().indexed() {
+            flipped_v.insert(Point { x: point.x, y: grid.height() - 1 - point.y }, *value);
+        }
+        assert_same(&grid.view().flipped_v(), &flipped_v);
+
+        // Transpose of a flip
+        let mut flipped_transposed = Grid::new();
+        for (point, value) in flipped_h.iter().indexed() {
+            flipped_transposed.insert(Point { x: point.y, y: point.x }, *value);
+        }
+        assert_same(&grid.view().flipped_h().transposed(), &flipped_transposed);
+
+        // Flip of a transpose
+        let mut transposed_flipped = Grid::new();
+        for (point, value) in transposed.iter().indexed() {
+            transposed_flipped.insert(Point { x: point.x, y: transposed.height() - 1 - point.y }, *value);
+        }
+        assert_same(&grid.view().transposed().flipped_v(), &transposed_flipped);
+    }
+
+    /// Counts the points inside a loop drawn with `|-LJ7F`, where every other character is outside it.
+    fn drawn_interior(input: &str) -> usize {
+        let grid: Grid<char> = input.into();
+        let boundary: HashSet<Point> = grid.iter().indexed()
+            .filter_map(|(p, c)| if *c != '.' { Some(p) } else { None })
+            .collect();
+        count_interior(&boundary, |p| match grid.get(p) {
+            Some('|') => Crossing::Vertical,
+            Some('L' | 'J') => Crossing::North,
+            Some('F' | '7') => Crossing::South,
+            _ => Crossing::Horizontal,
+        })
+    }
+
+    #[test]
+    fn test_count_interior() {
+        let rectangle = "\
+            F---7\n\
+            |...|\n\
+            |...|\n\
+            L---J";
+        assert_eq!(drawn_interior(rectangle), 6);
+
+        // A run that turns back the way it came (F7, L-J) doesn't cross the loop
+        let notched = "\
+            F----7\n\
+            |....|\n\
+            |.F7.|\n\
+            |.||.|\n\
+            L-JL-J";
+        assert_eq!(drawn_interior(notched), 8);
+
+        // A run that carries on the way it was going (L-7) does
+        let stepped = "\
+            F-7...\n\
+            |.L-7.\n\
+            |...|.\n\
+            L---J.";
+        assert_eq!(drawn_interior(stepped), 4);
+
+        assert_eq!(count_interior(&HashSet::new(), |_| Crossing::Vertical), 0);
+    }
+
+    #[test]
+    fn test_ring_and_disk() {
+        let point = Point { x: 10, y: 10 };
+        assert_eq!(point.ring(0).collect::<Vec<_>>(), vec![point]);
+        assert_eq!(point.ring(1).collect::<Vec<_>>(), vec![
+            Point { x: 10, y: 9 }, Point { x: 11, y: 10 }, Point { x: 10, y: 11 }, Point { x: 9, y: 10 },
+        ]);
+
+        let ring: Vec<Point> = point.ring(2).collect();
+        assert_eq!(ring, vec![
+            Point { x: 10, y: 8 }, Point { x: 11, y: 9 },
+            Point { x: 12, y: 10 }, Point { x: 11, y: 11 },
+            Point { x: 10, y: 12 }, Point { x: 9, y: 11 },
+            Point { x: 8, y: 10 }, Point { x: 9, y: 9 },
+        ]);
+        assert!(ring.iter().all(|p| p.manhattan_distance(point) == 2));
+
+        // Points with negative components are dropped near the origin
+        let origin = Point { x: 0, y: 0 };
+        assert_eq!(origin.ring(1).collect::<Vec<_>>(), vec![Point { x: 1, y: 0 }, Point { x: 0, y: 1 }]);
+        assert_eq!(Point { x: 1, y: 0 }.ring(2).count(), 4);
+
+        // Unclipped disks match the closed form
+        for k in 0..8 {
+            let disk: BTreeSet<Point> = point.disk(k).collect();
+            assert_eq!(disk.len(), 2 * k * k + 2 * k + 1);
+            assert_eq!(point.disk(k).count(), disk.len());
+            assert!(disk.iter().all(|p| p.manhattan_distance(point) <= k));
+        }
+    }
+
+    #[test]
+    fn test_signed_bounds() {
+        let grid: Grid<char> = "ab\ncd\nef".into();
+
+        // Negative components
+        assert!(!grid.contains_vec(Vector2D { x: -1, y: 0 }));
+        assert!(!grid.contains_vec(Vector2D { x: 0, y: -1 }));
+        assert_eq!(grid.get_signed(Vector2D { x: -1, y: 0 }), None);
+        assert_eq!(grid.get_signed(Vector2D { x: 0, y: -1 }), None);
+
+        // Exact boundaries
+        assert!(grid.contains_vec(Vector2D { x: 0, y: 0 }));
+        assert!(grid.contains_vec(Vector2D { x: 1, y: 2 }));
+        assert!(!grid.contains_vec(Vector2D { x: 2, y: 2 }));
+        assert!(!grid.contains_vec(Vector2D { x: 1, y: 3 }));
+        assert_eq!(grid.get_signed(Vector2D { x: 0, y: 0 }), Some(&'a'));
+        assert_eq!(grid.get_signed(Vector2D { x: 1, y: 2 }), Some(&'f'));
+        assert_eq!(grid.get_signed(Vector2D { x: 2, y: 2 }), None);
+    }
+
+    #[test]
+    fn test_direction_orderings() {
+        use Direction as D;
+        assert_eq!(D::DIRS, [D::North, D::South, D::East, D::West]);
+        assert_eq!(D::DIRS_CLOCKWISE, [D::North, D::East, D::South, D::West]);
+
+        for (i, dir) in D::DIRS.iter().enumerate() {
+            assert_eq!(dir.index(), i);
+            assert_eq!(D::from_index(i), Some(*dir));
+        }
+        assert_eq!(D::from_index(4), None);
+
+        for (i, dir) in D::DIRS_CLOCKWISE.iter().enumerate() {
+            assert_eq!(dir.right_hand(), D::DIRS_CLOCKWISE[(i + 1) % 4]);
+        }
+    }
+
+    #[test]
+    fn test_direction_round_trip() {
+        for dir in Direction::DIRS {
+            assert_eq!(dir.to_string().parse(), Ok(dir));
+            assert_eq!(dir.to_string()[..1].parse(), Ok(dir));
+            assert_eq!(dir.to_string().to_uppercase().parse(), Ok(dir));
+        }
+        assert_eq!("".parse::<Direction>(), Err(ParseDirectionError(String::new())));
+        assert_eq!("Northwest".parse::<Direction>(), Err(ParseDirectionError(String::from("Northwest"))));
+    }
+
+    #[test]
+    fn test_neighbors8() {
+        let origin = Point { x: 0, y: 0 };
+        assert_eq!(origin.neighbors8().collect::<Vec<_>>(), vec![
+            Point { x: 1, y: 0 }, Point { x: 0, y: 1 }, Point { x: 1, y: 1 },
+        ]);
+
+        let edge = Point { x: 1, y: 0 };
+        assert_eq!(edge.neighbors8().collect::<Vec<_>>(), vec![
+            Point { x: 0, y: 0 }, Point { x: 2, y: 0 },
+            Point { x: 0, y: 1 }, Point { x: 1, y: 1 }, Point { x: 2, y: 1 },
+        ]);
+
+        let interior = Point { x: 5, y: 5 };
+        assert_eq!(interior.neighbors8().collect::<Vec<_>>(), vec![
+            Point { x: 4, y: 4 }, Point { x: 5, y: 4 }, Point { x: 6, y: 4 },
+            Point { x: 4, y: 5 }, Point { x: 6, y: 5 },
+            Point { x: 4, y: 6 }, Point { x: 5, y: 6 }, Point { x: 6, y: 6 },
+        ]);
+    }
+
+    #[test]
+    fn test_neighbors_with_dirs() {
+        use Direction as D;
+        let origin = Point { x: 0, y: 0 };
+        assert_eq!(origin.neighbors_with_dirs().collect::<Vec<_>>(), vec![
+            (D::South, Point { x: 0, y: 1 }), (D::East, Point { x: 1, y: 0 }),
+        ]);
+
+        let edge = Point { x: 1, y: 0 };
+        assert_eq!(edge.neighbors_with_dirs().collect::<Vec<_>>(), vec![
+            (D::South, Point { x: 1, y: 1 }), (D::East, Point { x: 2, y: 0 }), (D::West, Point { x: 0, y: 0 }),
+        ]);
+
+        let interior = Point { x: 5, y: 5 };
+        for (dir, point) in interior.neighbors_with_dirs() {
+            assert_eq!(interior.offset_by(dir.vector()), Some(point));
+        }
+        assert_eq!(interior.neighbors_with_dirs().count(), 4);
+    }
+
+    #[test]
+    fn test_bounds_policy() {
+        let mut strict: Grid<char> = Grid::with_size(3, 2);
+        assert_eq!(strict.bounds_policy(), BoundsPolicy::Strict);
+        assert_eq!(strict.try_insert(Point { x: 3, y: 0 }, 'a'), Err(OutOfBounds(Point { x: 3, y: 0 })));
+        assert_eq!(strict.try_insert(Point { x: 0, y: 2 }, 'a'), Err(OutOfBounds(Point { x: 0, y: 2 })));
+        assert_eq!((strict.width(), strict.height()), (3, 2));
+        assert_eq!(strict.iter().count(), 0);
+
+        let mut grow = Grid::with_size(3, 2).with_bounds_policy(BoundsPolicy::Grow);
+        assert_eq!(grow.insert(Point { x: 3, y: 0 }, 'a'), None);
+        assert_eq!((grow.width(), grow.height()), (4, 2));
+
+        let mut default: Grid<char> = Grid::new();
+        assert_eq!(default.bounds_policy(), BoundsPolicy::Grow);
+        assert_eq!(default.insert(Point { x: 1, y: 1 }, 'a'), None);
+        assert_eq!((default.width(), default.height()), (2, 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "is out of bounds")]
+    fn test_strict_insert_out_of_bounds() {
+        let mut strict: Grid<char> = Grid::with_size(3, 2);
+        strict.insert(Point { x: 3, y: 0 }, 'a');
+    }
+
+    #[test]
+    fn test_with_size_display() {
+        let mut grid: Grid<char> = Grid::with_size(4, 3);
+        grid.insert(Point { x: 1, y: 1 }, '#');
+        assert_eq!(grid.to_string(), "    \n #  \n    \n");
+        assert_eq!(grid.display_options().empty_char('.').to_string(), "\
+            ....\n\
+            .#..\n\
+            ....\n");
+    }
+
+    #[test]
+    fn test_points_where() {
+        let grid: Grid<char> = "\
+            a.b\n\
+            .a.\n\
+            b.a".into();
+        assert_eq!(grid.points_of(&'a').collect::<Vec<_>>(), vec![
+            Point { x: 0, y: 0 }, Point { x: 1, y: 1 }, Point { x: 2, y: 2 },
+        ]);
+        assert_eq!(grid.points_where(|c| *c != '.').count(), 5);
+        assert_eq!(grid.points_of(&'z').next(), None);
+
+        // Lazily scans the grid, so finding the first match doesn't visit every position
+        let mut sparse: Grid<char> = Grid::new();
+        sparse.insert(Point { x: 0, y: 0 }, 'a');
+        sparse.insert(Point { x: 100_000, y: 100_000 }, 'a');
+        assert_eq!(sparse.points_of(&'a').take(1).collect::<Vec<_>>(), vec![Point { x: 0, y: 0 }]);
+    }
+
+    #[test]
+    fn test_to_display_string() {
+        let mut grid: Grid<u32> = Grid::with_size(3, 2);
+        grid.insert(Point { x: 0, y: 0 }, 7);
+        grid.insert(Point { x: 2, y: 1 }, 42);
+        assert_eq!(grid.to_display_string('.'), "7..\n..4\n");
+
+        let empty: Grid<char> = Grid::new();
+        assert_eq!(empty.to_display_string('.'), "");
+
+        let grid: Grid<char> = "#.\n.#".into();
+        assert_eq!(grid.to_display_string(' '), "#.\n.#\n");
+    }
+
+    #[test]
+    fn test_from_ascii() {
+        let input = "\
+            #.#\n\
+            ..#\n\
+            #..\n";
+        let from_str: Grid<char> = input.into();
+        assert_eq!(Grid::from_bytes(input.as_bytes()), from_str);
+        assert_eq!(Grid::from_bytes(input.replace('\n', "\r\n").as_bytes()), from_str);
+
+        let walls = Grid::from_ascii(input.as_bytes(), |b| (b == b'#').then_some(()));
+        assert_eq!((walls.width(), walls.height()), (3, 3));
+        assert_eq!(walls.points_of(&()).count(), 4);
+
+        let empty: Grid<char> = Grid::from_bytes(b"");
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+    }
+
+    #[test]
+    #[ignore = "benchmark; run with --release -- --ignored --nocapture"]
+    fn bench_from_ascii() {
+        // Sparse cells keep the 10k x 10k grid within memory
+        #[derive(Debug, PartialEq, Eq)]
+        struct Wall;
+        impl TryFrom<char> for Wall {
+            type Error = ();
+            fn try_from(c: char) -> Result<Self, ()> { if c == '#' { Ok(Wall) } else { Err(()) } }
+        }
+        impl TryFrom<u8> for Wall {
+            type Error = ();
+            fn try_from(b: u8) -> Result<Self, ()> { if b == b'#' { Ok(Wall) } else { Err(()) } }
+        }
+
+        let size = 10_000;
+        let row: String = (0..size).map(|x| if x % 97 == 0 { '#' } else { '.' }).collect();
+        let input = std::iter::repeat_n(row, size).collect::<Vec<_>>().join("\n");
+
+        let now = std::time::Instant::now();
+        let from_str: Grid<Wall> = input.as_str().into();
+        println!("From<&str>: {:.2?}", now.elapsed());
+
+        let now = std::time::Instant::now();
+        let from_bytes: Grid<Wall> = Grid::from_bytes(input.as_bytes());
+        println!("from_bytes: {:.2?}", now.elapsed());
+
+        assert_eq!(from_str, from_bytes);
+    }
+
+    #[test]
+    fn test_mirror_axes() {
+        // Symmetric along both axes, and across several vertical lines
+        let grid: Grid<char> = "\
+            #..#\n\
+            #..#".into();
+        assert_eq!(grid.mirror_axes(0), vec![Reflection::Vertical(2), Reflection::Horizontal(1)]);
+
+        let stripes: Grid<char> = "\
+            ##..##\n\
+            ##..##".into();
+        assert_eq!(stripes.mirror_axes(0), vec![
+            Reflection::Vertical(1), Reflection::Vertical(3), Reflection::Vertical(5), Reflection::Horizontal(1),
+        ]);
+
+        // A single smudge hides the perfect reflections and reveals new ones
+        let smudged: Grid<char> = "\
+            #..#\n\
+            #.##".into();
+        assert_eq!(smudged.mirror_axes(0), vec![]);
+        assert_eq!(smudged.mirror_axes(1), vec![
+            Reflection::Vertical(2), Reflection::Vertical(3), Reflection::Horizontal(1),
+        ]);
+
+        let empty: Grid<char> = Grid::new();
+        assert_eq!(empty.mirror_axes(0), vec![]);
+    }
+
+    #[test]
+    fn test_shift_row_col() {
+        let mut grid: Grid<char> = "\
+            ab.\n\
+            c..\n\
+            .de".into();
+
+        grid.shift_row(0, 3);
+        grid.shift_col(1, -3);
+        assert_eq!(grid.to_display_string('.'), "ab.\nc..\n.de\n");
+
+        grid.shift_row(1, 2);
+        assert_eq!(grid.to_display_string('.'), "ab.\n..c\n.de\n");
+        grid.shift_row(1, -4);
+        assert_eq!(grid.to_display_string('.'), "ab.\n.c.\n.de\n");
+
+        grid.shift_col(1, 1);
+        assert_eq!(grid.to_display_string('.'), "ad.\n.b.\n.ce\n");
+
+        // Out of range rows and columns are left alone
+        grid.shift_row(3, 1);
+        grid.shift_col(5, -1);
+        assert_eq!(grid.to_display_string('.'), "ad.\n.b.\n.ce\n");
+    }
+
+    #[test]
+    fn test_diff() {
+        let sparse = |input: &str| Grid::from_ascii(input.as_bytes(), |b| (b != b'.').then_some(char::from(b)));
+        let left = sparse("\
+            ab.\n\
+            c.e");
+        let right = sparse("\
+            ab.\n\
+            .dx");
+        assert_eq!(left.diff(&left), vec![]);
+        assert_eq!(left.diff(&right), vec![
+            GridDiff { point: Point { x: 0, y: 1 }, change: CellDiff::OnlyLeft(&'c') },
+            GridDiff { point: Point { x: 1, y: 1 }, change: CellDiff::OnlyRight(&'d') },
+            GridDiff { point: Point { x: 2, y: 1 }, change: CellDiff::Changed(&'e', &'x') },
+        ]);
+
+        let report: Vec<String> = left.diff(&right).iter().map(|diff| diff.to_string()).collect();
+        assert_eq!(report, vec![
+            "(0, 1): 'c' -> (empty)",
+            "(1, 1): (empty) -> 'd'",
+            "(2, 1): 'e' -> 'x'",
+        ]);
+    }
+
+    #[test]
+    fn test_assert_grids_eq() {
+        let grid: Grid<char> = "ab\ncd".into();
+        crate::assert_grids_eq!(grid, Grid::<char>::from("ab\ncd"));
+
+        let result = std::panic::catch_unwind(|| {
+            crate::assert_grids_eq!(grid, Grid::<char>::from("ab\nxd"));
+        });
+        let message = *result.unwrap_err().downcast::<String>().unwrap();
+        assert!(message.contains("(0, 1): 'c' -> 'x'"), "{message}");
+    }
+
+    #[test]
+    fn test_fill_and_clear_rect() {
+        let mut grid: Grid<char> = Grid::new();
+        grid.fill_rect(Point { x: 1, y: 1 }, 3, 2, '#').unwrap();
+        assert_eq!(grid.iter().count(), 6);
+        assert_eq!((grid.width(), grid.height()), (4, 3));
+        assert_eq!(grid.to_display_string('.'), "\
+            ....\n\
+            .###\n\
+            .###\n");
+
+        grid.fill_rect(Point { x: 10, y: 10 }, 0, 5, '#').unwrap();
+        assert_eq!((grid.width(), grid.height()), (4, 3));
+
+        grid.clear_rect(Point { x: 2, y: 1 }, 2, 1);
+        assert_eq!(grid.iter().count(), 4);
+        assert_eq!(grid.to_display_string('.'), "\
+            ....\n\
+            .#..\n\
+            .###\n");
+
+        let mut strict: Grid<char> = Grid::with_size(2, 2);
+        assert_eq!(strict.fill_rect(Point { x: 1, y: 0 }, 2, 1, '#'), Err(OutOfBounds(Point { x: 2, y: 0 })));
+        assert_eq!(strict.iter().count(), 0);
+    }
+
+    #[test]
+    fn test_rect_past_edge() {
+        let mut grid: Grid<char> = "###\n###\n###".into();
+        // Only the part inside the grid is visited, so this doesn't loop over the whole rectangle
+        grid.clear_rect(Point { x: 1, y: 1 }, 1_000_000, 1_000_000);
+        assert_eq!(grid.to_display_string('.'), "###\n#..\n#..\n");
+        grid.clear_rect(Point { x: 2, y: 0 }, usize::MAX, usize::MAX);
+        assert_eq!(grid.to_display_string('.'), "##.\n#..\n#..\n");
+        grid.clear_rect(Point { x: 5, y: 5 }, 2, 2);
+        assert_eq!((grid.width(), grid.height(), grid.iter().count()), (3, 3, 4));
+
+        // The far corner of this one would overflow
+        let corner = Point { x: usize::MAX, y: 1 };
+        assert_eq!(grid.fill_rect(Point { x: 2, y: 0 }, usize::MAX, 2, '#'), Err(OutOfBounds(corner)));
+        assert_eq!((grid.width(), grid.height(), grid.iter().count()), (3, 3, 4));
+    }
+
+    #[test]
+    fn test_update() {
+        let mut grid: Grid<u32> = Grid::from_digits("12\n34").unwrap();
+        grid.update(Point { x: 1, y: 1 }, |n| n.map(|n| n + 1));
+        assert_eq!(grid.get(Point { x: 1, y: 1 }), Some(&5));
+
+        grid.update(Point { x: 0, y: 0 }, |_| None);
+        assert_eq!(grid.get(Point { x: 0, y: 0 }), None);
+        grid.update(Point { x: 0, y: 0 }, |n| {
+            assert_eq!(n, None);
+            Some(9)
+        });
+        assert_eq!(grid.get(Point { x: 0, y: 0 }), Some(&9));
+
+        grid.update(Point { x: 2, y: 0 }, |_| Some(7));
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+
+        let mut strict: Grid<u32> = Grid::with_size(1, 1);
+        assert_eq!(strict.try_update(Point { x: 1, y: 0 }, |_| Some(1)), Err(OutOfBounds(Point { x: 1, y: 0 })));
+        assert_eq!(strict.try_update(Point { x: 1, y: 0 }, |_| None), Ok(()));
+        assert_eq!(strict.iter().count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "is out of bounds")]
+    fn test_strict_update_out_of_bounds() {
+        let mut strict: Grid<u32> = Grid::with_size(1, 1);
+        strict.update(Point { x: 1, y: 0 }, |_| Some(1));
+    }
+
+    #[test]
+    fn test_empty_points() {
+        let full: Grid<char> = "ab\ncd".into();
+        assert_eq!(full.empty_points().next(), None);
+
+        let mut holey: Grid<char> = "ab\ncd".into();
+        holey.clear_rect(Point { x: 0, y: 1 }, 1, 1);
+        assert_eq!(holey.empty_points().collect::<Vec<_>>(), vec![Point { x: 0, y: 1 }]);
+        assert!(holey.is_empty_at(Point { x: 0, y: 1 }));
+
+        let fresh: Grid<char> = Grid::with_size(3, 3);
+        assert_eq!(fresh.empty_points().count(), 9);
+        assert_eq!(fresh.empty_points().last(), Some(Point { x: 2, y: 2 }));
+    }
+
+    #[test]
+    fn test_from_rows() {
+        let dense = Grid::from_dense_rows([['a', 'b'], ['c', 'd']]);
+        assert_eq!(dense, Grid::from("ab\ncd"));
+
+        let holes = Grid::from_rows([[Some(1), None], [None, Some(4)]]);
+        assert_eq!(holes.to_display_string('.'), "1.\n.4\n");
+
+        let ragged = Grid::from_rows(vec![vec![Some('a')], vec![None, None, Some('b')], vec![]]);
+        assert_eq!((ragged.width(), ragged.height()), (3, 3));
+        assert_eq!(ragged.to_display_string('.'), "a..\n..b\n...\n");
+
+        let ragged_str: Grid<char> = "a\n..b\n".into();
+        let ragged_dense = Grid::from_dense_rows("a\n..b\n".lines().map(str::chars));
+        assert_eq!(ragged_dense, ragged_str);
+    }
+
+    #[test]
+    fn test_iter_cells() {
+        let mut grid: Grid<char> = "abc\ndef".into();
+        grid.clear_rect(Point { x: 1, y: 0 }, 1, 2);
+        assert_eq!(grid.iter_cells().count(), grid.width() * grid.height());
+        let holes: Vec<Point> = grid.iter_cells().filter(|(_, cell)| cell.is_none()).map(|(p, _)| p).collect();
+        assert_eq!(holes, vec![Point { x: 1, y: 0 }, Point { x: 1, y: 1 }]);
+
+        assert_eq!(grid.row_cells(1).collect::<Vec<_>>(), vec![
+            (Point { x: 0, y: 1 }, Some(&'d')), (Point { x: 1, y: 1 }, None), (Point { x: 2, y: 1 }, Some(&'f')),
+        ]);
+        assert_eq!(grid.col_cells(1).collect::<Vec<_>>(), vec![
+            (Point { x: 1, y: 0 }, None), (Point { x: 1, y: 1 }, None),
+        ]);
+        assert_eq!(grid.row_cells(2).count(), 0);
+        assert_eq!(grid.col_cells(3).count(), 0);
+    }
+
+    #[test]
+    fn test_resize() {
+        let mut grid: Grid<char> = "\
+            abc\n\
+            def\n\
+            ghi".into();
+        grid.resize(2, 2);
+        assert_eq!(grid.iter().count(), 4);
+        assert_eq!(grid.to_display_string('.'), "ab\nde\n");
+        assert!(!grid.check_inbounds(Point { x: 2, y: 1 }));
+
+        grid.resize(3, 3);
+        assert_eq!(grid.iter().count(), 4);
+        assert_eq!(grid.to_display_string('.'), "ab.\nde.\n...\n");
+
+        grid.truncate_to_content();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+
+        grid.resize(0, 0);
+        assert_eq!(grid.iter().count(), 0);
+        assert_eq!(grid.to_display_string('.'), "");
+        grid.truncate_to_content();
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+    }
+
+    #[test]
+    fn test_direction_towards() {
+        let point = Point { x: 1, y: 1 };
+        for dir in Direction::DIRS {
+            let neighbor = point.step(dir).unwrap();
+            assert_eq!(point.direction_towards(neighbor), Some(dir));
+            assert_eq!(neighbor.direction_towards(point), Some(dir.opposite()));
+        }
+        assert_eq!(point.direction_towards(Point { x: 0, y: 0 }), None);
+        assert_eq!(point.direction_towards(Point { x: 2, y: 0 }), None);
+        assert_eq!(point.direction_towards(point), None);
+        assert_eq!(Point { x: 0, y: 0 }.step(Direction::North), None);
+    }
+
+    #[test]
+    fn test_neighbor_entries() {
+        use Direction as D;
+        let mut grid: Grid<char> = "\
+            abc\n\
+            def\n\
+            ghi".into();
+        let corner = Point { x: 2, y: 2 };
+        assert_eq!(grid.neighbor_entries(corner).collect::<Vec<_>>(), vec![
+            (D::North, Point { x: 2, y: 1 }, &'f'),
+            (D::West, Point { x: 1, y: 2 }, &'h'),
+        ]);
+        let center: String = grid.neighbor_entries(Point { x: 1, y: 1 }).map(|(_, _, c)| c).collect();
+        assert_eq!(center, "bfhd");
+
+        grid.clear_rect(Point { x: 1, y: 0 }, 1, 1);
+        let entries: Vec<_> = grid.neighbor_entries(Point { x: 0, y: 0 }).map(|(d, p, _)| (d, p)).collect();
+        assert_eq!(entries, vec![(D::South, Point { x: 0, y: 1 })]);
+    }
+
+    #[test]
+    fn test_border_points() {
+        // Single row, whole grid is border
+        let grid: Grid<char> = "abcd".into();
+        let border: Vec<Point> = grid.border_points().collect();
+        assert_eq!(border, (0..4).map(|x| Point { x, y: 0 }).collect::<Vec<_>>());
+
+        // Single column, whole grid is border
+        let grid: Grid<char> = "a\nb\nc\nd".into();
+        let border: Vec<Point> = grid.border_points().collect();
+        assert_eq!(border, (0..4).map(|y| Point { x: 0, y }).collect::<Vec<_>>());
+        assert!(border.iter().all(|p| p.is_on_border(&grid)));
+
+        // Corners aren't duplicated
+        let mut grid: Grid<char> = Grid::new();
+        grid.insert(Point { x: 3, y: 2 }, 'a');
+        let border: Vec<Point> = grid.border_points().collect();
+        assert_eq!(border.len(), 10);
+        assert_eq!(border.iter().collect::<BTreeSet<_>>().len(), 10);
+
+        // Empty grid has no border
+        let grid: Grid<char> = Grid::new();
+        assert_eq!(grid.border_points().next(), None);
+    }
 }
\ No newline at end of file
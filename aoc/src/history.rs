@@ -0,0 +1,215 @@
+use std::{collections::BTreeMap, fs, io::Write, path::Path, time::Duration};
+
+use crate::BenchmarkReport;
+
+/// Environment variable naming the history file [crate::Problem::benchmark] compares against.
+pub const HISTORY_VAR: &str = "AOC_BENCH_HISTORY";
+
+/// If set, the history file is only read, not updated.
+pub const NO_SAVE_VAR: &str = "AOC_BENCH_NO_SAVE";
+
+/// Part timings from previous benchmarks, keyed by `crate/part_N`. Stored as JSON nanoseconds.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct History(BTreeMap<String, u64>);
+
+impl History {
+    /// Loads the history at `path`. A missing file is an empty history, and a corrupted one is
+    /// reported on `warnings` and replaced with an empty history.
+    pub fn load(path: &Path, warnings: &mut impl Write) -> std::io::Result<Self> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(History::default()),
+            Err(e) => {
+                writeln!(warnings, "Warning: couldn't read benchmark history {}: {e}", path.display())?;
+                return Ok(History::default());
+            },
+        };
+        match serde_json::from_str(&contents) {
+            Ok(entries) => Ok(History(entries)),
+            Err(e) => {
+                writeln!(warnings, "Warning: benchmark history {} is corrupted and will be rewritten: {e}", path.display())?;
+                Ok(History::default())
+            },
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        fs::write(path, serde_json::to_string_pretty(&self.0)?)?;
+        Ok(())
+    }
+
+    pub fn get(&self, key: &str, part: u8) -> Option<Duration> {
+        self.0.get(&Self::entry(key, part)).map(|&nanos| Duration::from_nanos(nanos))
+    }
+
+    pub fn set(&mut self, key: &str, part: u8, time: Duration) {
+        self.0.insert(Self::entry(key, part), time.as_nanos() as u64);
+    }
+
+    fn entry(key: &str, part: u8) -> String {
+        format!("{key}/part_{part}")
+    }
+}
+
+/// Formats `current`, compared to the `previous` time if there is one, e.g.
+/// `148.00ms (was 410.00ms, -64%)`.
+pub fn format_delta(current: Duration, previous: Option<Duration>) -> String {
+    match previous {
+        Some(previous) if !previous.is_zero() => {
+            let change = (current.as_secs_f64() / previous.as_secs_f64() - 1.0) * 100.0;
+            format!("{current:.2?} (was {previous:.2?}, {change:+.0}%)")
+        },
+        _ => format!("{current:.2?} (first run)"),
+    }
+}
+
+impl BenchmarkReport {
+    /// Prints the report with each part's time compared to the previous run stored in the
+    /// history file at `path`, then records this run's times under `key`, unless
+    /// `AOC_BENCH_NO_SAVE` is set.
+    pub fn print_with_history(&self, key: &str, path: &Path) -> anyhow::Result<()> {
+        let save = std::env::var_os(NO_SAVE_VAR).is_none();
+        self.write_with_history(key, path, save, &mut std::io::stdout(), &mut std::io::stderr())
+    }
+
+    /// Like [BenchmarkReport::print_with_history], writing the report to `out` and any warning
+    /// about the history file to `warnings`.
+    pub fn write_with_history(
+        &self,
+        key: &str,
+        path: &Path,
+        save: bool,
+        out: &mut impl Write,
+        warnings: &mut impl Write,
+    ) -> anyhow::Result<()> {
+        let mut history = History::load(path, warnings)?;
+        write!(out, "{}", self.header())?;
+        for (part, result) in [(1, &self.part1), (2, &self.part2)] {
+            writeln!(out, "{}", self.part_line(part, Some(history.get(key, part))))?;
+            if let Some((_, time)) = result {
+                history.set(key, part, *time);
+            }
+        }
+        if save {
+            history.save(path)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::Parts;
+
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc_history_test_{}_{name}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn report(part1_ms: u64, part2_ms: Option<u64>) -> BenchmarkReport {
+        BenchmarkReport {
+            parse: Duration::from_micros(5),
+            prepare: None,
+            parts: Parts::Both,
+            part1: Some(("1".to_string(), Duration::from_millis(part1_ms))),
+            part2: part2_ms.map(|ms| ("2".to_string(), Duration::from_millis(ms))),
+            part1_allocs: None,
+            part2_allocs: None,
+            part1_peak_bytes: None,
+            part2_peak_bytes: None,
+            part1_timeout: None,
+            part2_timeout: None,
+        }
+    }
+
+    fn write(report: &BenchmarkReport, path: &Path, save: bool) -> (String, String) {
+        let (mut out, mut warnings) = (Vec::new(), Vec::new());
+        report.write_with_history("day_17", path, save, &mut out, &mut warnings).unwrap();
+        (String::from_utf8(out).unwrap(), String::from_utf8(warnings).unwrap())
+    }
+
+    #[test]
+    fn test_format_delta() {
+        let ms = Duration::from_millis;
+        assert_eq!(format_delta(ms(148), Some(ms(410))), "148.00ms (was 410.00ms, -64%)");
+        assert_eq!(format_delta(ms(150), Some(ms(100))), "150.00ms (was 100.00ms, +50%)");
+        assert_eq!(format_delta(ms(100), Some(ms(100))), "100.00ms (was 100.00ms, +0%)");
+        assert_eq!(format_delta(ms(100), None), "100.00ms (first run)");
+        assert_eq!(format_delta(ms(100), Some(Duration::ZERO)), "100.00ms (first run)");
+    }
+
+    #[test]
+    fn test_first_run_then_compare() {
+        let path = temp_dir("runs").join("history.json");
+
+        let (out, warnings) = write(&report(410, Some(20)), &path, true);
+        assert_eq!(out, "Parse in 5.00µs\nPart 1 solution: 1 in 410.00ms (first run)\nPart 2 solution: 2 in 20.00ms (first run)\n");
+        assert_eq!(warnings, "");
+
+        let (out, _) = write(&report(148, None), &path, true);
+        assert!(out.contains("Part 1 solution: 1 in 148.00ms (was 410.00ms, -64%)\n"), "{out}");
+        assert!(out.ends_with("Part 2 solution: not implemented\n"), "{out}");
+
+        // Part 2 keeps its last time when it didn't run
+        let history = History::load(&path, &mut Vec::new()).unwrap();
+        assert_eq!(history.get("day_17", 1), Some(Duration::from_millis(148)));
+        assert_eq!(history.get("day_17", 2), Some(Duration::from_millis(20)));
+        assert_eq!(history.get("day_18", 1), None);
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_stats_with_history() {
+        let path = temp_dir("stats").join("history.json");
+        let report = BenchmarkReport {
+            part1_allocs: Some(crate::allocs::AllocStats { allocations: 3, bytes: 2048 }),
+            part2_peak_bytes: Some(4096),
+            ..report(100, Some(20))
+        };
+
+        // The history only changes the times, the rest of each line matches Display
+        let (out, _) = write(&report, &path, false);
+        let display = report.to_string();
+        assert_eq!(out.lines().count(), display.lines().count());
+        for (line, display_line) in out.lines().zip(display.lines()) {
+            let strip_delta = |line: &str| line.replace(" (first run)", "");
+            assert_eq!(strip_delta(line), display_line);
+        }
+        assert!(out.contains("Part 2 solution: 2 in 20.00ms (first run) (peak 4.00 KiB)\n"), "{out}");
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_no_save() {
+        let path = temp_dir("no_save").join("history.json");
+        fs::write(&path, r#"{"day_17/part_1": 200000000}"#).unwrap();
+
+        let (out, _) = write(&report(100, None), &path, false);
+        assert!(out.contains("100.00ms (was 200.00ms, -50%)"), "{out}");
+        let (out, _) = write(&report(100, None), &path, false);
+        assert!(out.contains("100.00ms (was 200.00ms, -50%)"), "{out}");
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_corrupted_history() {
+        let path = temp_dir("corrupted").join("history.json");
+        fs::write(&path, "{ not json").unwrap();
+
+        let (out, warnings) = write(&report(100, None), &path, true);
+        assert!(out.contains("100.00ms (first run)"), "{out}");
+        assert!(warnings.starts_with("Warning: benchmark history"), "{warnings}");
+        assert!(warnings.contains("corrupted"), "{warnings}");
+
+        // The file was rewritten and is valid again
+        let (out, warnings) = write(&report(50, None), &path, true);
+        assert!(out.contains("50.00ms (was 100.00ms, -50%)"), "{out}");
+        assert_eq!(warnings, "");
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,170 @@
+use std::{borrow::Cow, fs, io::Read, path::{Path, PathBuf}};
+
+use anyhow::{bail, Context};
+
+#[cfg(feature = "fetch")]
+pub(crate) const USER_AGENT: &str = "github.com/Boingboingsplat/aoc-2023 input fetcher";
+
+/// Root of the workspace, where the `inputs/` cache directory and day crates live.
+pub(crate) fn workspace_root() -> &'static Path {
+    Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/.."))
+}
+
+/// Returns the path of the `input.txt` file in a day crate's `src` directory.
+pub fn local_input_path(day: u8) -> PathBuf {
+    workspace_root().join(format!("day_{day:02}/src/input.txt"))
+}
+
+/// Returns the path an input is cached at within `cache_dir`.
+pub fn cache_path(cache_dir: &Path, year: u16, day: u8) -> PathBuf {
+    cache_dir.join(format!("{year}_day_{day:02}.txt"))
+}
+
+/// Fetches the puzzle input for `year` and `day`, using the session cookie in `AOC_SESSION`.
+///
+/// Inputs are cached in the `inputs/` directory at the workspace root, and are never downloaded
+/// again once cached.
+#[cfg(feature = "fetch")]
+pub fn fetch_input(year: u16, day: u8) -> anyhow::Result<String> {
+    let session = std::env::var("AOC_SESSION").ok();
+    fetch_input_with(&workspace_root().join("inputs"), session.as_deref(), year, day)
+}
+
+/// Like [fetch_input], but with an explicit cache directory and session cookie.
+#[cfg(feature = "fetch")]
+pub fn fetch_input_with(cache_dir: &Path, session: Option<&str>, year: u16, day: u8) -> anyhow::Result<String> {
+    let path = cache_path(cache_dir, year, day);
+    if path.exists() {
+        return fs::read_to_string(&path).with_context(|| format!("Couldn't read cached input {}", path.display()));
+    }
+
+    let Some(session) = session else {
+        bail!("No cached input for {year} day {day}, and AOC_SESSION isn't set");
+    };
+    let url = format!("https://adventofcode.com/{year}/day/{day}/input");
+    let input = ureq::get(&url)
+        .set("Cookie", &format!("session={session}"))
+        .set("User-Agent", USER_AGENT)
+        .call()
+        .with_context(|| format!("Couldn't download {url}"))?
+        .into_string()?;
+
+    fs::create_dir_all(cache_dir)?;
+    fs::write(&path, &input).with_context(|| format!("Couldn't cache input to {}", path.display()))?;
+    Ok(input)
+}
+
+/// Resolves the input for a day binary from its first command line argument.
+///
+/// `arg` is a path to read the input from, or `-` to read it from `stdin`. With no argument, the
+/// input is read from `default_path`, failing with a hint to pass a path if it doesn't exist.
+pub fn read_input_arg_or_file(arg: Option<&str>, default_path: &Path, mut stdin: impl Read) -> anyhow::Result<String> {
+    match arg {
+        None if !default_path.exists() => {
+            bail!("No input at {}, pass an input path (or - for stdin) as the first argument", default_path.display())
+        },
+        None => fs::read_to_string(default_path).with_context(|| format!("Couldn't read input {}", default_path.display())),
+        Some("-") => {
+            let mut input = String::new();
+            stdin.read_to_string(&mut input).context("Couldn't read input from stdin")?;
+            Ok(input)
+        },
+        Some(path) => fs::read_to_string(path).with_context(|| format!("Couldn't read input {path}")),
+    }
+}
+
+/// Normalizes input copied or saved on another platform: strips a UTF-8 byte order mark,
+/// converts `\r\n` line endings to `\n`, and trims exactly one trailing newline.
+///
+/// Borrows the input when it's already normalized.
+///
+/// # Example
+///
+/// ```
+/// # use aoc::input::normalize;
+/// assert_eq!(normalize("\u{feff}#.\r\n.#\r\n"), "#.\n.#");
+/// assert_eq!(normalize("a,b\n\n"), "a,b\n");
+/// ```
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let input = input.strip_suffix("\r\n")
+        .or_else(|| input.strip_suffix('\n'))
+        .unwrap_or(input);
+    match input.contains("\r\n") {
+        true => Cow::Owned(input.replace("\r\n", "\n")),
+        false => Cow::Borrowed(input),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_cache_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc_input_test_{}_{name}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[cfg(feature = "fetch")]
+    #[test]
+    fn test_cache_hit() {
+        let dir = temp_cache_dir("hit");
+        fs::write(cache_path(&dir, 2023, 5), "seeds: 1 2 3\n").unwrap();
+        // Cached inputs don't need a session
+        assert_eq!(fetch_input_with(&dir, None, 2023, 5).unwrap(), "seeds: 1 2 3\n");
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_read_input_arg_or_file() {
+        let dir = temp_cache_dir("arg_or_file");
+        let default = dir.join("input.txt");
+        let alternate = dir.join("alternate.txt");
+        fs::write(&alternate, "from file").unwrap();
+
+        let err = read_input_arg_or_file(None, &default, std::io::empty()).unwrap_err();
+        assert!(err.to_string().contains("pass an input path"), "{err}");
+        assert_eq!(read_input_arg_or_file(alternate.to_str(), &default, std::io::empty()).unwrap(), "from file");
+        assert_eq!(read_input_arg_or_file(Some("-"), &default, "from stdin".as_bytes()).unwrap(), "from stdin");
+        let err = read_input_arg_or_file(dir.join("missing.txt").to_str(), &default, std::io::empty()).unwrap_err();
+        assert!(err.to_string().starts_with("Couldn't read input "), "{err}");
+
+        fs::write(&default, "default").unwrap();
+        assert_eq!(read_input_arg_or_file(None, &default, std::io::empty()).unwrap(), "default");
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert!(matches!(normalize("ab\ncd"), Cow::Borrowed("ab\ncd")));
+        assert!(matches!(normalize("ab\ncd\n"), Cow::Borrowed("ab\ncd")));
+        assert_eq!(normalize("ab\r\ncd\r\n"), "ab\ncd");
+        assert_eq!(normalize("\u{feff}ab"), "ab");
+        // Only one trailing newline is trimmed, so blank-line separated blocks keep their shape
+        assert_eq!(normalize("ab\r\n\r\n"), "ab\n");
+        // A lone carriage return isn't a line ending
+        assert_eq!(normalize("a\rb"), "a\rb");
+        assert_eq!(normalize(""), "");
+    }
+
+    #[test]
+    fn test_normalize_crlf_grid() {
+        use crate::grid::{Grid, Point};
+
+        let grid: Grid<char> = Grid::from(normalize("\u{feff}#..\r\n.#.\r\n..#\r\n").into_owned());
+        assert_eq!(grid, Grid::from("#..\n.#.\n..#"));
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid.get(Point { x: 0, y: 0 }), Some(&'#'));
+        assert!(grid.iter().all(|c| *c != '\r'));
+    }
+
+    #[cfg(feature = "fetch")]
+    #[test]
+    fn test_missing_session() {
+        let dir = temp_cache_dir("miss");
+        let error = fetch_input_with(&dir, None, 2023, 6).unwrap_err();
+        assert!(error.to_string().contains("AOC_SESSION"), "{error}");
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
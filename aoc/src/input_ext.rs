@@ -0,0 +1,142 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::parse::LineError;
+
+/// Common ways of splitting up puzzle input.
+pub trait InputExt {
+    /// Parses every line as a `T`. Errors report the 1-based line that failed.
+    ///
+    /// # Example
+    /// ```
+    /// # use aoc::InputExt;
+    /// assert_eq!("1\n-2\n3".lines_as::<i32>().unwrap(), vec![1, -2, 3]);
+    /// assert_eq!("1\nx".lines_as::<i32>().unwrap_err().line, 2);
+    /// ```
+    fn lines_as<T>(&self) -> Result<Vec<T>, LineError>
+    where
+        T: FromStr,
+        T::Err: Display;
+
+    /// Returns the blocks of lines separated by blank lines, without their trailing newlines.
+    /// Handles `\r\n` line endings and any number of blank lines between blocks.
+    ///
+    /// # Example
+    /// ```
+    /// # use aoc::InputExt;
+    /// let blocks: Vec<_> = "a\nb\n\nc\n".blocks().collect();
+    /// assert_eq!(blocks, vec!["a\nb", "c"]);
+    /// ```
+    fn blocks(&self) -> impl Iterator<Item = &str>;
+
+    /// Returns every integer in the text, in order. A `-` directly before digits makes the
+    /// number negative, unless it follows a letter or digit as in `a-1` or `1-2`. Numbers that
+    /// don't fit in `T` are skipped.
+    ///
+    /// # Example
+    /// ```
+    /// # use aoc::InputExt;
+    /// assert_eq!("Time: 7 15, x=-30".extract_ints::<i64>(), vec![7, 15, -30]);
+    /// ```
+    fn extract_ints<T: FromStr>(&self) -> Vec<T>;
+}
+
+impl InputExt for str {
+    fn lines_as<T>(&self) -> Result<Vec<T>, LineError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.lines()
+            .enumerate()
+            .map(|(n, line)| {
+                line.parse().map_err(|e: T::Err| LineError { line: n + 1, column: 1, message: e.to_string() })
+            })
+            .collect()
+    }
+
+    fn blocks(&self) -> impl Iterator<Item = &str> {
+        let mut rest = self;
+        std::iter::from_fn(move || {
+            let (mut start, mut end, mut offset) = (None, 0, 0);
+            for line in rest.split_inclusive('\n') {
+                let content = line.trim_end_matches(['\n', '\r']);
+                if !content.trim().is_empty() {
+                    start.get_or_insert(offset);
+                    end = offset + content.len();
+                } else if start.is_some() {
+                    break;
+                }
+                offset += line.len();
+            }
+            let block = &rest[start?..end];
+            rest = &rest[offset..];
+            Some(block)
+        })
+    }
+
+    fn extract_ints<T: FromStr>(&self) -> Vec<T> {
+        let bytes = self.as_bytes();
+        let mut ints = vec![];
+        let mut i = 0;
+        while i < bytes.len() {
+            if !bytes[i].is_ascii_digit() {
+                i += 1;
+                continue;
+            }
+            let mut start = i;
+            if start > 0 && bytes[start - 1] == b'-' && (start < 2 || !bytes[start - 2].is_ascii_alphanumeric()) {
+                start -= 1;
+            }
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            if let Ok(n) = self[start..i].parse() {
+                ints.push(n);
+            }
+        }
+        ints
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines_as() {
+        assert_eq!("10\n20\r\n30".lines_as::<u32>(), Ok(vec![10, 20, 30]));
+        assert_eq!("".lines_as::<u32>(), Ok(vec![]));
+
+        let err = "10\n20\n3O\n40".lines_as::<u32>().unwrap_err();
+        assert_eq!(err.line, 3);
+        assert_eq!(err.to_string(), "Line 3, column 1: invalid digit found in string");
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "a\nb\n\nc\n\n\n\nd\ne\n";
+        assert_eq!(input.blocks().collect::<Vec<_>>(), vec!["a\nb", "c", "d\ne"]);
+
+        let crlf = "a\r\nb\r\n\r\nc\r\n";
+        assert_eq!(crlf.blocks().collect::<Vec<_>>(), vec!["a\r\nb", "c"]);
+        assert_eq!(crlf.blocks().map(|block| block.lines().count()).collect::<Vec<_>>(), vec![2, 1]);
+
+        // Leading blank lines and lines of only whitespace separate blocks too
+        assert_eq!("\n\na\n  \nb".blocks().collect::<Vec<_>>(), vec!["a", "b"]);
+        assert_eq!("".blocks().count(), 0);
+        assert_eq!("\n\r\n".blocks().count(), 0);
+    }
+
+    #[test]
+    fn test_extract_ints() {
+        assert_eq!("Time:      7  15   30".extract_ints::<usize>(), vec![7, 15, 30]);
+        assert_eq!("p=0,4 v=3,-3".extract_ints::<i32>(), vec![0, 4, 3, -3]);
+        assert_eq!("-1 -2\r\n-3".extract_ints::<i64>(), vec![-1, -2, -3]);
+        // Hyphens between words or numbers aren't signs
+        assert_eq!("seed-to-soil 2023-12-05".extract_ints::<i32>(), vec![2023, 12, 5]);
+        assert_eq!("a-1 --2".extract_ints::<i32>(), vec![1, -2]);
+        // Negative numbers and overflows don't fit unsigned types
+        assert_eq!("1 -2 300".extract_ints::<u8>(), vec![1]);
+        assert_eq!("no numbers".extract_ints::<i32>(), Vec::<i32>::new());
+    }
+}
@@ -1,39 +1,1289 @@
-pub use aoc_macro::EnumFromChar;
+pub use aoc_macro::{aoc_main, sample_tests, EnumFromChar, EnumFromStr, EnumVector};
+#[doc(hidden)]
+pub use aoc_macro::test_cases as __test_cases;
+#[cfg(feature = "criterion")]
+pub use criterion;
 
+#[cfg(feature = "criterion")]
+pub mod bench;
+pub mod allocs;
+pub mod compare;
+pub mod export;
+pub mod geometry;
+pub mod graph;
+pub mod history;
 pub mod grid;
+pub mod input;
+mod input_ext;
+pub mod math;
+pub mod parse;
+pub mod ranges;
+pub mod registry;
+mod run_config;
+#[cfg(feature = "stress")]
+pub mod stress;
+#[cfg(feature = "fetch")]
+pub mod submission;
+pub mod testing;
+
+use std::sync::mpsc::RecvTimeoutError;
+use std::time::{Duration, Instant};
+
+pub use input_ext::InputExt;
+pub use run_config::{Parts, RunConfig};
+#[cfg(feature = "fetch")]
+pub use submission::{submit, SubmitResult};
+
+/// The `Solution2` of a day that only solves part 1, see [Problem::has_part_2].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NoPart2;
+
+/// Parses any answer, so [test_real_input] accepts whatever follows the part 1 answer.
+impl std::str::FromStr for NoPart2 {
+    type Err = std::convert::Infallible;
+
+    fn from_str(_: &str) -> Result<Self, Self::Err> {
+        Ok(NoPart2)
+    }
+}
+
+/// A day's puzzle. Each part may have its own solution type.
+///
+/// The input is parsed once with `parse` and shared by both parts. Days that work on the raw
+/// input can use `type Parsed = String`.
+///
+/// Part 2 is optional: a day that only solves part 1 uses `type Solution2 = NoPart2` and returns
+/// [NoPart2] from `part_2`, which makes `has_part_2` false.
 pub trait Problem {
-    type Solution: std::fmt::Debug;
-    fn part_1(input: &str) -> Self::Solution;
-    fn part_2(input: &str) -> Self::Solution;
+    type Solution1: std::fmt::Debug;
+    type Solution2: std::fmt::Debug + 'static;
+    type Parsed;
+    /// Parses the input, panicking if it's malformed.
+    ///
+    /// Days that override [Problem::try_parse] usually implement this by unwrapping it. Those days
+    /// must override `try_parse`, since its default calls `parse` and the two would recurse forever.
+    fn parse(input: &str) -> Self::Parsed;
+    fn part_1(input: &Self::Parsed) -> Self::Solution1;
+    fn part_2(input: &Self::Parsed) -> Self::Solution2;
+
+    /// Fallible version of `parse`. Days can override this to report malformed input as an error
+    /// rather than panicking; by default it wraps `parse`.
+    ///
+    /// A day that overrides this can implement `parse` by unwrapping it, e.g.
+    /// `Self::try_parse(input).unwrap_or_else(|e| panic!("{e:#}"))` (see `day_02`). A day that
+    /// doesn't override this must not implement `parse` that way, or the two recurse forever.
+    fn try_parse(input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(Self::parse(input))
+    }
+
+    /// Fallible version of `part_1`. Days whose part 1 can fail on input that parses can override
+    /// this to report the error instead of panicking; by default it wraps `part_1`.
+    ///
+    /// [Problem::try_run_with] and the benchmarks solve part 1 through this.
+    fn try_part_1(parsed: &Self::Parsed) -> anyhow::Result<Self::Solution1> {
+        Ok(Self::part_1(parsed))
+    }
+
+    /// Fallible version of `part_2`, like [Problem::try_part_1].
+    fn try_part_2(parsed: &Self::Parsed) -> anyhow::Result<Self::Solution2> {
+        Ok(Self::part_2(parsed))
+    }
+
+    /// Whether benchmarks, [Problem::run], [Problem::solve] and the test macros pass the input
+    /// through [input::normalize] first, see [prepare_input].
+    /// Days that need the raw input, e.g. to see trailing whitespace, can set this to false.
+    const NORMALIZE_INPUT: bool = true;
+
+    /// Returns the solutions to both parts, or only part 1 if [Problem::has_part_2] is false.
+    ///
+    /// By default the input is parsed once and shared by both parts. A [PreparedProblem] can
+    /// override this with [solve_prepared] to share its prepare step too. Registering it with
+    /// [registry::RegistryBuilder::register_prepared] times that same step in the runner.
+    fn solve(input: &str) -> (Self::Solution1, Option<Self::Solution2>) {
+        let parsed = Self::parse(&prepare_input::<Self>(input));
+        (Self::part_1(&parsed), Self::has_part_2().then(|| Self::part_2(&parsed)))
+    }
+
+    /// Whether `part_2` is implemented. Benchmarks skip part 2 when this is false.
+    ///
+    /// By default this is false only for days whose `Solution2` is [NoPart2].
+    fn has_part_2() -> bool {
+        std::any::TypeId::of::<Self::Solution2>() != std::any::TypeId::of::<NoPart2>()
+    }
+
+    /// Prints the report from [Problem::run], running the parts selected by `AOC_PART` (see
+    /// [RunConfig::from_env]). If `AOC_BENCH_OUT` is set, the report is also appended to that
+    /// file, see [export::append_record].
+    ///
+    /// Errors from [Problem::try_parse] are printed with their causes instead of panicking.
     fn benchmark(input: &str) {
-        let now = std::time::Instant::now();
-        let solution = Self::part_1(input);
-        let elapsed = now.elapsed();
-        println!("Part 1 solution: {:?} in {:.2?}", solution, elapsed);
+        match RunConfig::from_env() {
+            Ok(config) => Self::benchmark_with(input, &config),
+            Err(e) => eprintln!("Error: {e:?}"),
+        }
+    }
+
+    /// Like [Problem::benchmark], with an explicit [RunConfig].
+    ///
+    /// If `AOC_BENCH_HISTORY` names a file, part times are compared to the previous run recorded
+    /// there, see [BenchmarkReport::print_with_history].
+    fn benchmark_with(input: &str, config: &RunConfig) {
+        match Self::try_run_with(input, config) {
+            Ok(report) => print_report::<Self>(&report),
+            Err(e) => eprintln!("Error: {e:?}"),
+        }
+    }
+
+    /// Benchmarks the day's `input.txt`, or the downloaded input from [input::fetch_input] if it's absent.
+    #[cfg(feature = "fetch")]
+    fn benchmark_day(year: u16, day: u8) -> anyhow::Result<()> {
+        let input = match std::fs::read_to_string(input::local_input_path(day)) {
+            Ok(input) if !input.is_empty() => input,
+            _ => input::fetch_input(year, day)?,
+        };
+        Self::benchmark(&input);
+        Ok(())
+    }
+
+    /// Solves `part` of the input and submits the answer, see [submit].
+    #[cfg(feature = "fetch")]
+    fn solve_and_submit(input: &str, year: u16, day: u8, part: u8) -> anyhow::Result<SubmitResult>
+    where
+        Self::Solution1: std::fmt::Display,
+        Self::Solution2: std::fmt::Display,
+    {
+        let parsed = Self::try_parse(input)?;
+        let answer = match part {
+            1 => Self::try_part_1(&parsed)?.to_string(),
+            2 if Self::has_part_2() => Self::try_part_2(&parsed)?.to_string(),
+            _ => anyhow::bail!("Can't submit part {part}"),
+        };
+        submit(year, day, part, &answer)
+    }
+
+    /// Like [Problem::benchmark], but writes the report to `out` instead of stdout.
+    fn benchmark_to<W: std::io::Write>(input: &str, out: &mut W) -> std::io::Result<()> {
+        match Self::try_run(input) {
+            Ok(report) => write!(out, "{}", report),
+            Err(e) => writeln!(out, "Error: {e:?}"),
+        }
+    }
+
+    /// Like [Problem::benchmark], but gives up on a part that takes longer than `per_part` and
+    /// reports it as timed out. `AOC_TIMEOUT_SECS` overrides `per_part` (see [timeout_from_env]),
+    /// and the report is printed like [Problem::benchmark_with]'s.
+    ///
+    /// Each part runs on its own thread. A part that times out is left running detached in the
+    /// background, since threads can't be cancelled, until it finishes or the process exits.
+    fn benchmark_with_timeout(input: &str, per_part: Duration)
+    where
+        Self: Sized + 'static,
+        Self::Parsed: Send + Sync + 'static,
+        Self::Solution1: Send + 'static,
+        Self::Solution2: Send + 'static,
+    {
+        let report = RunConfig::from_env()
+            .and_then(|config| Self::try_run_with_timeout(input, &config, timeout_from_env(per_part)));
+        match report {
+            Ok(report) => print_report::<Self>(&report),
+            Err(e) => eprintln!("Error: {e:?}"),
+        }
+    }
+
+    /// Like [Problem::benchmark_with_timeout], but writes the report to `out` instead of stdout,
+    /// without reading the environment.
+    fn benchmark_with_timeout_to<W: std::io::Write>(input: &str, per_part: Duration, out: &mut W) -> std::io::Result<()>
+    where
+        Self: Sized + 'static,
+        Self::Parsed: Send + Sync + 'static,
+        Self::Solution1: Send + 'static,
+        Self::Solution2: Send + 'static,
+    {
+        match Self::try_run_with_timeout(input, &RunConfig::default(), per_part) {
+            Ok(report) => write!(out, "{}", report),
+            Err(e) => writeln!(out, "Error: {e:?}"),
+        }
+    }
+
+    /// Like [Problem::try_run_with], but gives up on a part that takes longer than `per_part`,
+    /// recording the timeout in the report instead of a solution.
+    fn try_run_with_timeout(input: &str, config: &RunConfig, per_part: Duration) -> anyhow::Result<BenchmarkReport>
+    where
+        Self: Sized + 'static,
+        Self::Parsed: Send + Sync + 'static,
+        Self::Solution1: Send + 'static,
+        Self::Solution2: Send + 'static,
+    {
+        let input = prepare_input::<Self>(input);
+        let now = Instant::now();
+        let parsed = std::sync::Arc::new(Self::try_parse(&input)?);
+        let parse = now.elapsed();
+
+        let shared = parsed.clone();
+        let (part1, part1_timeout) = match config.parts.includes(1) {
+            true => run_with_timeout(move || measure_part(|| Self::try_part_1(&shared)), per_part)?,
+            false => ((None, None, None), None),
+        };
+
+        let (part2, part2_timeout) = match config.parts.includes(2) && Self::has_part_2() {
+            true => run_with_timeout(move || measure_part(|| Self::try_part_2(&parsed)), per_part)?,
+            false => ((None, None, None), None),
+        };
+
+        Ok(BenchmarkReport::from_measurements(parse, None, config.parts, part1, part2, [part1_timeout, part2_timeout]))
+    }
 
-        let now = std::time::Instant::now();
-        let solution = Self::part_2(input);
-        let elapsed = now.elapsed();
-        println!("Part 2 solution: {:?} in {:.2?}", solution, elapsed);
+    /// Parses the input and solves both parts, timing each step.
+    ///
+    /// Panics if the input can't be parsed or a part fails, see [Problem::try_run].
+    fn run(input: &str) -> BenchmarkReport {
+        Self::run_with(input, &RunConfig::default())
     }
+
+    /// Like [Problem::run], but only runs the parts selected by `config`.
+    fn run_with(input: &str, config: &RunConfig) -> BenchmarkReport {
+        Self::try_run_with(input, config).unwrap_or_else(|e| panic!("{e:#}"))
+    }
+
+    /// Like [Problem::run], but parses with [Problem::try_parse] and solves with
+    /// [Problem::try_part_1] and [Problem::try_part_2], returning their errors.
+    fn try_run(input: &str) -> anyhow::Result<BenchmarkReport> {
+        Self::try_run_with(input, &RunConfig::default())
+    }
+
+    /// Like [Problem::try_run], but only runs the parts selected by `config`.
+    fn try_run_with(input: &str, config: &RunConfig) -> anyhow::Result<BenchmarkReport> {
+        let input = prepare_input::<Self>(input);
+        let now = Instant::now();
+        let parsed = Self::try_parse(&input)?;
+        let parse = now.elapsed();
+
+        let part1 = match config.parts.includes(1) {
+            true => measure_part(|| Self::try_part_1(&parsed))?,
+            false => (None, None, None),
+        };
+
+        let part2 = match config.parts.includes(2) && Self::has_part_2() {
+            true => measure_part(|| Self::try_part_2(&parsed))?,
+            false => (None, None, None),
+        };
+
+        Ok(BenchmarkReport::from_measurements(parse, None, config.parts, part1, part2, [None, None]))
+    }
+}
+
+/// Timings and solutions from [Problem::run]. Solutions are rendered with their `Debug` format.
+///
+/// Displays in the same format [Problem::benchmark] prints.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BenchmarkReport {
+    pub parse: Duration,
+    /// Time spent in [PreparedProblem::prepare], for reports from [PreparedProblem::try_run_prepared_with].
+    #[serde(default)]
+    pub prepare: Option<Duration>,
+    /// The parts that were requested.
+    #[serde(default)]
+    pub parts: Parts,
+    /// `None` if part 1 was skipped.
+    pub part1: Option<(String, Duration)>,
+    /// `None` if part 2 was skipped or the problem doesn't implement it.
+    pub part2: Option<(String, Duration)>,
+    /// Allocations made by part 1, only counted with the `count-allocs` feature.
+    #[serde(default)]
+    pub part1_allocs: Option<allocs::AllocStats>,
+    /// Allocations made by part 2, only counted with the `count-allocs` feature.
+    #[serde(default)]
+    pub part2_allocs: Option<allocs::AllocStats>,
+    /// Peak memory used by part 1, only measured with the `count-allocs` or `mem-stats` feature,
+    /// see [allocs::measure_peak].
+    #[serde(default)]
+    pub part1_peak_bytes: Option<usize>,
+    /// Peak memory used by part 2, like `part1_peak_bytes`.
+    #[serde(default)]
+    pub part2_peak_bytes: Option<usize>,
+    /// The timeout part 1 ran past, for reports from [Problem::try_run_with_timeout]. `part1` is
+    /// `None` if this is set.
+    #[serde(default)]
+    pub part1_timeout: Option<Duration>,
+    /// The timeout part 2 ran past, like `part1_timeout`.
+    #[serde(default)]
+    pub part2_timeout: Option<Duration>,
 }
 
+impl BenchmarkReport {
+    /// Returns the combined time spent parsing, preparing and solving both parts.
+    pub fn total(&self) -> Duration {
+        [&self.part1, &self.part2].into_iter()
+            .flatten()
+            .fold(self.parse + self.prepare.unwrap_or_default(), |total, (_, time)| total + *time)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("reports always serialize")
+    }
+
+    pub fn from_json(json: &str) -> anyhow::Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Returns the report as `parse_ns,part1_solution,part1_ns,part2_solution,part2_ns`, with
+    /// solutions quoted as needed. A part's columns are empty if it didn't run.
+    pub fn to_csv_row(&self) -> String {
+        let columns = |part: &Option<(String, Duration)>| match part {
+            Some((solution, time)) => (export::csv_field(solution), time.as_nanos().to_string()),
+            None => (String::new(), String::new()),
+        };
+        let ((part1, part1_ns), (part2, part2_ns)) = (columns(&self.part1), columns(&self.part2));
+        format!("{},{},{},{},{}", self.parse.as_nanos(), part1, part1_ns, part2, part2_ns)
+    }
+}
+
+impl BenchmarkReport {
+    /// Builds the report for a run from each part's measurement and the timeout it ran past, if any.
+    fn from_measurements(
+        parse: Duration,
+        prepare: Option<Duration>,
+        parts: Parts,
+        part1: PartMeasurement,
+        part2: PartMeasurement,
+        [part1_timeout, part2_timeout]: [Option<Duration>; 2],
+    ) -> Self {
+        let ((part1, part1_allocs, part1_peak_bytes), (part2, part2_allocs, part2_peak_bytes)) = (part1, part2);
+        BenchmarkReport {
+            parse,
+            prepare,
+            parts,
+            part1,
+            part2,
+            part1_allocs,
+            part2_allocs,
+            part1_peak_bytes,
+            part2_peak_bytes,
+            part1_timeout,
+            part2_timeout,
+        }
+    }
+
+    /// Describes a part without a solution: skipped, timed out or not implemented.
+    fn missing_part(&self, part: u8) -> String {
+        let timeout = if part == 1 { self.part1_timeout } else { self.part2_timeout };
+        match timeout {
+            Some(timeout) => format!("Part {part} timed out after {timeout:.2?}"),
+            None if !self.parts.includes(part) => format!("Part {part} solution: skipped"),
+            None => format!("Part {part} solution: not implemented"),
+        }
+    }
+}
+
+impl BenchmarkReport {
+    /// The lines before the parts': the time spent parsing, and preparing if there was a prepare step.
+    fn header(&self) -> String {
+        let mut header = format!("Parse in {:.2?}\n", self.parse);
+        if let Some(prepare) = self.prepare {
+            header.push_str(&format!("Prepare in {:.2?}\n", prepare));
+        }
+        header
+    }
+
+    /// A part's line, without a newline: its solution and time, or why it has none, followed by
+    /// its allocation and peak memory stats if they were measured.
+    ///
+    /// With `compare`, the time is compared to the previous run's, if there was one, see
+    /// [history::format_delta].
+    fn part_line(&self, part: u8, compare: Option<Option<Duration>>) -> String {
+        let (result, allocs, peak_bytes) = match part {
+            1 => (&self.part1, self.part1_allocs, self.part1_peak_bytes),
+            _ => (&self.part2, self.part2_allocs, self.part2_peak_bytes),
+        };
+        let mut line = match (result, compare) {
+            (Some((solution, time)), Some(previous)) => format!("Part {part} solution: {solution} in {}", history::format_delta(*time, previous)),
+            (Some((solution, time)), None) => format!("Part {part} solution: {solution} in {time:.2?}"),
+            (None, _) => self.missing_part(part),
+        };
+        let stats: Vec<String> = allocs.map(|allocs| allocs.to_string()).into_iter()
+            .chain(peak_bytes.map(|bytes| format!("peak {}", allocs::format_bytes(bytes))))
+            .collect();
+        if !stats.is_empty() {
+            line.push_str(&format!(" ({})", stats.join(", ")));
+        }
+        line
+    }
+}
+
+impl std::fmt::Display for BenchmarkReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.header())?;
+        for part in [1, 2] {
+            writeln!(f, "{}", self.part_line(part, None))?;
+        }
+        Ok(())
+    }
+}
+
+/// A part's solution and time, allocations and peak memory, as stored in a [BenchmarkReport].
+type PartMeasurement = (Option<(String, Duration)>, Option<allocs::AllocStats>, Option<usize>);
+
+/// Solves a part, timing it and measuring its allocations, or returns the part's error. The clock
+/// stops before the solution is formatted, so formatting doesn't count towards the part's time.
+fn measure_part<T: std::fmt::Debug>(solve: impl FnOnce() -> anyhow::Result<T>) -> anyhow::Result<PartMeasurement> {
+    let now = Instant::now();
+    let ((solution, allocs), peak) = allocs::measure_peak(|| allocs::measure(solve));
+    let time = now.elapsed();
+    Ok((Some((format!("{:?}", solution?), time)), allocs, peak))
+}
+
+/// Measures a part on a new thread, returning its measurement and no timeout, or an empty
+/// measurement and `timeout` if it didn't finish in time. The thread is left running if it times out.
+///
+/// A part that panics is returned as an error with the panic's message, not as a timeout.
+fn run_with_timeout(
+    solve: impl FnOnce() -> anyhow::Result<PartMeasurement> + Send + 'static,
+    timeout: Duration,
+) -> anyhow::Result<(PartMeasurement, Option<Duration>)> {
+    let (tx, rx) = std::sync::mpsc::channel();
+    let handle = std::thread::spawn(move || {
+        let _ = tx.send(solve());
+    });
+    match rx.recv_timeout(timeout) {
+        Ok(measurement) => Ok((measurement?, None)),
+        Err(RecvTimeoutError::Timeout) => Ok(((None, None, None), Some(timeout))),
+        Err(RecvTimeoutError::Disconnected) => {
+            // The sender is only dropped unsent if the part panicked
+            let panic = handle.join().expect_err("part finished without sending its result");
+            let message = panic.downcast_ref::<&str>().copied()
+                .or_else(|| panic.downcast_ref::<String>().map(String::as_str))
+                .unwrap_or("unknown panic");
+            Err(anyhow::anyhow!("Part panicked: {message}"))
+        },
+    }
+}
+
+/// Returns the per-part timeout in seconds from the `AOC_TIMEOUT_SECS` environment variable, or
+/// `default` if it isn't set or isn't a number, see [parse_timeout].
+pub fn timeout_from_env(default: Duration) -> Duration {
+    parse_timeout(std::env::var("AOC_TIMEOUT_SECS").ok().as_deref(), default)
+}
+
+/// Parses a timeout in seconds, such as the value of `AOC_TIMEOUT_SECS`, falling back to
+/// `default` if there's none or it isn't a number.
+pub fn parse_timeout(secs: Option<&str>, default: Duration) -> Duration {
+    secs.and_then(|secs| Duration::try_from_secs_f64(secs.trim().parse().ok()?).ok())
+        .unwrap_or(default)
+}
+
+/// Prints the report for [Problem::benchmark_with], comparing part times to the history file
+/// named by `AOC_BENCH_HISTORY` and exporting the report to `AOC_BENCH_OUT` if they're set.
+fn print_report<P: ?Sized>(report: &BenchmarkReport) {
+    match std::env::var_os(history::HISTORY_VAR) {
+        Some(path) => {
+            if let Err(e) = report.print_with_history(crate_name::<P>(), path.as_ref()) {
+                eprintln!("Couldn't update benchmark history: {e:#}");
+            }
+        },
+        None => print!("{}", report),
+    }
+    export::append_from_env(day_name::<P>(), report);
+}
+
+/// Normalizes the input unless the problem opts out with [Problem::NORMALIZE_INPUT]. Every path
+/// from raw input to [Problem::parse] goes through this, so they all see the same input.
+pub fn prepare_input<P: Problem + ?Sized>(input: &str) -> std::borrow::Cow<'_, str> {
+    match P::NORMALIZE_INPUT {
+        true => input::normalize(input),
+        false => std::borrow::Cow::Borrowed(input),
+    }
+}
+
+/// Returns the name of the crate the problem is defined in, e.g. `day_13`.
+fn crate_name<P: ?Sized>() -> &'static str {
+    let name = std::any::type_name::<P>();
+    name.split("::").next().unwrap_or(name)
+}
+
+/// Returns the problem's type name without its module path, e.g. `Day13`.
+fn day_name<P: ?Sized>() -> &'static str {
+    let name = std::any::type_name::<P>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/// Entry point for a day binary, used by [main!]: benchmarks the input file named by the first
+/// argument, stdin if it's `-`, or the file at `default_path` otherwise. The default is read at
+/// runtime, so the binary builds without it.
+///
+/// `--part 1|2|both` (or `AOC_PART`) selects which parts run, see [RunConfig::from_env_and_args].
+pub fn run_main_from_file<P: Problem>(default_path: &str) {
+    benchmark_or_exit(|arg| input::read_input_arg_or_file(arg, default_path.as_ref(), std::io::stdin()), P::benchmark_with);
+}
+
+/// Like [run_main_from_file], for a [PreparedProblem], reporting the time spent preparing the
+/// input separately. Used by `main!(prepared ..)`.
+pub fn run_main_prepared_from_file<P: PreparedProblem>(default_path: &str) {
+    benchmark_or_exit(|arg| input::read_input_arg_or_file(arg, default_path.as_ref(), std::io::stdin()), P::benchmark_prepared_with);
+}
+
+fn benchmark_or_exit(read_input: impl FnOnce(Option<&str>) -> anyhow::Result<String>, benchmark: fn(&str, &RunConfig)) {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let config = RunConfig::from_env_and_args(&mut args);
+    match config.and_then(|config| Ok((read_input(args.first().map(String::as_str))?, config))) {
+        Ok((input, config)) => benchmark(&input, &config),
+        Err(e) => {
+            eprintln!("{e:#}");
+            std::process::exit(1);
+        },
+    }
+}
+
+/// Generates a day binary's `fn main`, see [run_main_from_file].
+///
+/// The input defaults to `src/input.txt` in the invoking crate, or another path relative to
+/// `src/` given as the second argument. Start with `prepared` for a [PreparedProblem], to
+/// benchmark it with [run_main_prepared_from_file].
+///
+/// A type that's missing or isn't a [Problem] is reported where it's named.
+///
+/// ```ignore
+/// use day_17::Day17;
+///
+/// aoc::main!(Day17);
+/// ```
+#[macro_export]
+macro_rules! main {
+    (prepared $t:ty) => {
+        $crate::main!(prepared $t, "input.txt");
+    };
+    (prepared $t:ty, $path:literal) => {
+        fn main() {
+            $crate::run_main_prepared_from_file::<$t>(concat!(env!("CARGO_MANIFEST_DIR"), "/src/", $path));
+        }
+    };
+    ($t:ty) => {
+        $crate::main!($t, "input.txt");
+    };
+    ($t:ty, $path:literal) => {
+        fn main() {
+            $crate::run_main_from_file::<$t>(concat!(env!("CARGO_MANIFEST_DIR"), "/src/", $path));
+        }
+    };
+}
+
+/// A [Problem] whose parts can share work done up front.
+///
+/// `prepare` is computed once from the parsed input and handed to both parts, so expensive setup
+/// shared by part 1 and part 2 isn't repeated. The standalone [Problem] parts remain available.
+pub trait PreparedProblem: Problem {
+    type Intermediate;
+    fn prepare(parsed: &Self::Parsed) -> Self::Intermediate;
+    fn part_1_prepared(intermediate: &Self::Intermediate) -> Self::Solution1;
+    fn part_2_prepared(intermediate: &Self::Intermediate) -> Self::Solution2;
+
+    /// Fallible version of `part_1_prepared`, like [Problem::try_part_1].
+    fn try_part_1_prepared(intermediate: &Self::Intermediate) -> anyhow::Result<Self::Solution1> {
+        Ok(Self::part_1_prepared(intermediate))
+    }
+
+    /// Fallible version of `part_2_prepared`, like [Problem::try_part_1].
+    fn try_part_2_prepared(intermediate: &Self::Intermediate) -> anyhow::Result<Self::Solution2> {
+        Ok(Self::part_2_prepared(intermediate))
+    }
+
+    /// Like [Problem::benchmark], but with the time spent in `prepare` reported separately.
+    fn benchmark_prepared(input: &str) {
+        match RunConfig::from_env() {
+            Ok(config) => Self::benchmark_prepared_with(input, &config),
+            Err(e) => eprintln!("Error: {e:?}"),
+        }
+    }
+
+    /// Like [PreparedProblem::benchmark_prepared], with an explicit [RunConfig].
+    fn benchmark_prepared_with(input: &str, config: &RunConfig) {
+        match Self::try_run_prepared_with(input, config) {
+            Ok(report) => print_report::<Self>(&report),
+            Err(e) => eprintln!("Error: {e:?}"),
+        }
+    }
+
+    /// Like [Problem::try_run], but prepares the parsed input once and solves both parts from it,
+    /// see [PreparedProblem::try_run_prepared_with].
+    fn try_run_prepared(input: &str) -> anyhow::Result<BenchmarkReport> {
+        Self::try_run_prepared_with(input, &RunConfig::default())
+    }
+
+    /// Like [Problem::try_run_with], but prepares the parsed input once and solves both parts
+    /// from it, timing `prepare` on its own.
+    fn try_run_prepared_with(input: &str, config: &RunConfig) -> anyhow::Result<BenchmarkReport> {
+        let input = prepare_input::<Self>(input);
+        let now = Instant::now();
+        let parsed = Self::try_parse(&input)?;
+        let parse = now.elapsed();
+
+        let now = Instant::now();
+        let intermediate = Self::prepare(&parsed);
+        let prepare = now.elapsed();
+
+        let part1 = match config.parts.includes(1) {
+            true => measure_part(|| Self::try_part_1_prepared(&intermediate))?,
+            false => (None, None, None),
+        };
+
+        let part2 = match config.parts.includes(2) && Self::has_part_2() {
+            true => measure_part(|| Self::try_part_2_prepared(&intermediate))?,
+            false => (None, None, None),
+        };
+
+        Ok(BenchmarkReport::from_measurements(parse, Some(prepare), config.parts, part1, part2, [None, None]))
+    }
+}
+
+/// [Problem::solve] for a [PreparedProblem], parsing and preparing the input once for both parts.
+/// Prepared days override `solve` with this.
+pub fn solve_prepared<P: PreparedProblem + ?Sized>(input: &str) -> (P::Solution1, Option<P::Solution2>) {
+    let intermediate = P::prepare(&P::parse(&prepare_input::<P>(input)));
+    (P::part_1_prepared(&intermediate), P::has_part_2().then(|| P::part_2_prepared(&intermediate)))
+}
+
+/// Tests `part_1` against samples and their solutions, with one test per sample.
+///
+/// A single sample gets a test named `test_part_1`. With several, they're named by position
+/// (`test_part_1_case_1`, `test_part_1_case_2`, ...) or by an optional label, so
+/// `test_part_1!(Day01, "small" => SMALL, 8, LARGE, 10)` generates `test_part_1_small` and
+/// `test_part_1_case_2`.
 #[macro_export]
 macro_rules! test_part_1 {
-    ($t:ident, $( $input:expr, $sol:expr ),+) => {
+    ($t:ty, $( $cases:tt )+) => {
+        $crate::__test_cases!($crate, part_1, $t, $( $cases )+);
+    };
+}
+
+/// Like [test_part_1], for `part_2`. Also checks that the problem implements part 2.
+#[macro_export]
+macro_rules! test_part_2 {
+    ($t:ty, $( $cases:tt )+) => {
+        $crate::__test_cases!($crate, part_2, $t, $( $cases )+);
+    };
+}
+
+/// Like [test_part_1], but reads each sample from a file relative to the invoking crate's root.
+/// The file is prepared like a real input, see [testing::prepare_sample].
+#[macro_export]
+macro_rules! test_part_1_file {
+    ($t:ty, $( $path:literal, $sol:expr ),+) => {
         #[test]
-        fn test_part_1() {
-            $( assert_eq!($t::part_1($input), $sol); )+
+        fn test_part_1_file() {
+            $(
+                let input = $crate::testing::prepare_sample::<$t>(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", $path)));
+                assert_eq!(<$t as $crate::Problem>::part_1(&<$t as $crate::Problem>::parse(&input)), $sol, "sample {}", $path);
+            )+
         }
     };
 }
 
+/// Like [test_part_2], but reads each sample from a file relative to the invoking crate's root.
+/// The file is prepared like a real input, see [testing::prepare_sample].
 #[macro_export]
-macro_rules! test_part_2 {
-    ($t:ident, $( $input:expr, $sol:expr ),+) => {
+macro_rules! test_part_2_file {
+    ($t:ty, $( $path:literal, $sol:expr ),+) => {
+        #[test]
+        fn test_part_2_file() {
+            $(
+                let input = $crate::testing::prepare_sample::<$t>(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", $path)));
+                assert_eq!(<$t as $crate::Problem>::part_2(&<$t as $crate::Problem>::parse(&input)), $sol, "sample {}", $path);
+            )+
+        }
+    };
+}
+
+/// Checks both parts against every sample listed in a manifest file relative to the invoking crate's root.
+/// See [testing::check_sample_manifest] for the manifest format.
+#[macro_export]
+macro_rules! test_samples_file {
+    ($t:ty, $manifest:literal) => {
         #[test]
-        fn test_part_2() {
-            $( assert_eq!($t::part_2($input), $sol); )+
+        fn test_samples_file() {
+            $crate::testing::check_sample_manifest::<$t>(
+                ::std::path::Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/", $manifest))
+            );
         }
     };
-}
\ No newline at end of file
+}
+
+/// Generates ignored tests checking both parts against the real puzzle input and its known answers,
+/// run with `cargo test -- --ignored`.
+///
+/// Reads `input.txt` and `answers.txt` from the invoking crate's `src` directory, or from the given
+/// directory relative to the crate root. Expected answers are parsed with `FromStr`. The tests pass
+/// with a message if either file is missing, see [testing::load_real_input], and the part 2 test
+/// does too if the problem doesn't implement part 2.
+#[macro_export]
+macro_rules! test_real_input {
+    ($t:ty) => {
+        $crate::test_real_input!($t, "src");
+    };
+    ($t:ty, $dir:literal) => {
+        #[test]
+        #[ignore = "needs the real puzzle input and answers"]
+        fn test_real_input_part_1() {
+            let dir = ::std::path::Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/", $dir));
+            if let Some((input, [answer, _])) = $crate::testing::load_real_input(dir) {
+                let expected: <$t as $crate::Problem>::Solution1 = $crate::testing::parse_answer(&answer, 1);
+                let parsed = <$t as $crate::Problem>::parse(&$crate::prepare_input::<$t>(&input));
+                assert_eq!(<$t as $crate::Problem>::part_1(&parsed), expected);
+            }
+        }
+
+        #[test]
+        #[ignore = "needs the real puzzle input and answers"]
+        fn test_real_input_part_2() {
+            if !<$t as $crate::Problem>::has_part_2() {
+                println!("Skipping: {} has no part 2", stringify!($t));
+                return;
+            }
+            let dir = ::std::path::Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/", $dir));
+            if let Some((input, [_, answer])) = $crate::testing::load_real_input(dir) {
+                let expected: <$t as $crate::Problem>::Solution2 = $crate::testing::parse_answer(&answer, 2);
+                let parsed = <$t as $crate::Problem>::parse(&$crate::prepare_input::<$t>(&input));
+                assert_eq!(<$t as $crate::Problem>::part_2(&parsed), expected);
+            }
+        }
+    };
+}
+
+/// Asserts that two [Grid](grid::Grid)s are equal, like `assert_eq!`.
+///
+/// On failure, the panic message lists each differing point from [Grid::diff](grid::Grid::diff)
+/// instead of dumping both grids.
+#[macro_export]
+macro_rules! assert_grids_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if left != right {
+                    let mut report = format!(
+                        "assertion `left == right` failed: grids differ\n  left: {}x{}\n right: {}x{}",
+                        left.width(), left.height(), right.width(), right.height(),
+                    );
+                    for diff in left.diff(right) {
+                        report.push_str(&format!("\n  {}", diff));
+                    }
+                    panic!("{}", report);
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use anyhow::Context;
+
+    use super::*;
+
+    struct Mixed;
+    impl Problem for Mixed {
+        type Solution1 = usize;
+        type Solution2 = String;
+        type Parsed = Vec<char>;
+
+        fn parse(input: &str) -> Self::Parsed {
+            input.chars().collect()
+        }
+
+        fn part_1(input: &Self::Parsed) -> Self::Solution1 {
+            input.len()
+        }
+
+        fn part_2(input: &Self::Parsed) -> Self::Solution2 {
+            input.iter().rev().collect()
+        }
+    }
+
+    test_part_1!(Mixed, "abc", 3);
+    test_part_2!(Mixed, "abc", "cba");
+
+    test_part_1_file!(Mixed, "samples/mixed.txt", 3, "samples/mixed_blank_line.txt", 5);
+    test_part_2_file!(Mixed, "samples/mixed.txt", "cba");
+    test_samples_file!(Mixed, "samples/mixed.manifest");
+
+    #[test]
+    #[should_panic(expected = "Wrong part 2 answer for sample mixed.txt")]
+    fn test_samples_file_mismatch() {
+        let dir = std::env::temp_dir().join(format!("aoc_manifest_test_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::copy(concat!(env!("CARGO_MANIFEST_DIR"), "/samples/mixed.txt"), dir.join("mixed.txt")).unwrap();
+        std::fs::write(dir.join("bad.manifest"), "mixed.txt 3 \"abc\"\n").unwrap();
+        let result = std::panic::catch_unwind(|| testing::check_sample_manifest::<Mixed>(&dir.join("bad.manifest")));
+        std::fs::remove_dir_all(&dir).unwrap();
+        if let Err(panic) = result {
+            std::panic::resume_unwind(panic);
+        }
+    }
+
+    // The file macros take any type, not just a name in scope
+    mod qualified {
+        test_part_1_file!(super::Mixed, "samples/mixed.txt", 3);
+        test_part_2_file!(super::Mixed, "samples/mixed.txt", "cba");
+        test_samples_file!(super::Mixed, "samples/mixed.manifest");
+    }
+
+    struct PartOneOnly;
+    impl Problem for PartOneOnly {
+        type Solution1 = usize;
+        type Solution2 = NoPart2;
+        type Parsed = String;
+
+        fn parse(input: &str) -> Self::Parsed {
+            input.to_string()
+        }
+
+        fn part_1(input: &Self::Parsed) -> Self::Solution1 {
+            input.lines().count()
+        }
+
+        fn part_2(_input: &Self::Parsed) -> Self::Solution2 {
+            NoPart2
+        }
+    }
+
+    #[test]
+    fn test_part_2_optional() {
+        let report = PartOneOnly::run("a\nb");
+        let (part1, part1_time) = report.part1.clone().unwrap();
+        assert_eq!(part1, "2");
+        assert_eq!(report.part2, None);
+        assert_eq!(report.total(), report.parse + part1_time);
+
+        let mut out = Vec::new();
+        PartOneOnly::benchmark_to("a\nb", &mut out).unwrap();
+        assert!(String::from_utf8(out).unwrap().ends_with("Part 2 solution: not implemented\n"));
+    }
+
+    struct Digits;
+    impl Problem for Digits {
+        type Solution1 = u32;
+        type Solution2 = u32;
+        type Parsed = Vec<u32>;
+
+        fn parse(input: &str) -> Self::Parsed {
+            Self::try_parse(input).unwrap_or_else(|e| panic!("{e:#}"))
+        }
+
+        fn try_parse(input: &str) -> anyhow::Result<Self::Parsed> {
+            input.lines()
+                .enumerate()
+                .map(|(n, line)| line.parse::<u32>().with_context(|| format!("Bad line {}: {line:?}", n + 1)))
+                .collect()
+        }
+
+        fn part_1(input: &Self::Parsed) -> Self::Solution1 {
+            input.iter().sum()
+        }
+
+        fn part_2(input: &Self::Parsed) -> Self::Solution2 {
+            Self::try_part_2(input).unwrap_or_else(|e| panic!("{e:#}"))
+        }
+
+        fn try_part_2(input: &Self::Parsed) -> anyhow::Result<Self::Solution2> {
+            input.iter().try_fold(1_u32, |product, &n| product.checked_mul(n)).context("Product overflows")
+        }
+    }
+
+    #[test]
+    fn test_try_parts() {
+        let parsed = Digits::try_parse("2\n3").unwrap();
+        assert_eq!(Digits::try_part_1(&parsed).unwrap(), 5);
+        assert_eq!(Digits::try_part_2(&parsed).unwrap(), 6);
+        let err = Digits::try_run("2\nthree").unwrap_err();
+        assert_eq!(format!("{err:#}"), "Bad line 2: \"three\": invalid digit found in string");
+
+        // Problems that don't override the try variants still work through them
+        assert_eq!(Mixed::try_part_1(&Mixed::parse("abc")).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_try_run_part_error() {
+        let overflowing = "100000\n100000";
+        let err = Digits::try_run(overflowing).unwrap_err();
+        assert_eq!(err.to_string(), "Product overflows");
+        let err = Digits::try_run_with_timeout(overflowing, &RunConfig::default(), Duration::from_secs(5)).unwrap_err();
+        assert_eq!(err.to_string(), "Product overflows");
+        // Part 1 alone succeeds
+        let report = Digits::try_run_with(overflowing, &RunConfig { parts: Parts::Part1 }).unwrap();
+        assert_eq!(report.part1.unwrap().0, "200000");
+    }
+
+    #[test]
+    fn test_benchmark_prints_error() {
+        let mut out = Vec::new();
+        Digits::benchmark_to("1\nx", &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.starts_with("Error: Bad line 2: \"x\"\n"), "{out}");
+        assert!(out.contains("invalid digit found in string"), "{out}");
+    }
+
+    #[test]
+    #[should_panic(expected = "Bad line 1")]
+    fn test_run_panics_on_error() {
+        Digits::run("x");
+    }
+
+    mod named_cases {
+        use super::*;
+
+        test_part_1!(Mixed, "abc", 3, "short" => "ab", 2, "", 0);
+        test_part_2!(Mixed, "abc", "cba", "ab", "ba");
+
+        #[test]
+        fn test_case_names() {
+            // Each case is its own function, named by position or label
+            let _ = test_part_1_case_1 as fn();
+            let _ = test_part_1_short as fn();
+            let _ = test_part_1_case_3 as fn();
+            let _ = test_part_2_case_1 as fn();
+            let _ = test_part_2_case_2 as fn();
+        }
+    }
+
+    mod single_case {
+        use super::*;
+
+        test_part_1!(Mixed, "abc", 3,);
+
+        #[test]
+        fn test_single_case_name() {
+            let _ = test_part_1 as fn();
+        }
+    }
+
+    struct Sleepy;
+    impl Problem for Sleepy {
+        type Solution1 = usize;
+        type Solution2 = usize;
+        type Parsed = String;
+
+        fn parse(input: &str) -> Self::Parsed {
+            input.to_string()
+        }
+
+        fn part_1(input: &Self::Parsed) -> Self::Solution1 {
+            input.len()
+        }
+
+        fn part_2(input: &Self::Parsed) -> Self::Solution2 {
+            std::thread::sleep(Duration::from_secs(5));
+            input.len()
+        }
+    }
+
+    #[test]
+    fn test_benchmark_with_timeout() {
+        let now = Instant::now();
+        let mut out = Vec::new();
+        Sleepy::benchmark_with_timeout_to("abc", Duration::from_millis(100), &mut out).unwrap();
+        assert!(now.elapsed() < Duration::from_secs(5));
+
+        let out = String::from_utf8(out).unwrap();
+        let lines: Vec<_> = out.lines().collect();
+        assert_eq!(lines.len(), 3, "{out}");
+        assert!(lines[1].starts_with("Part 1 solution: 3 in "), "{out}");
+        assert_eq!(lines[2], "Part 2 timed out after 100.00ms");
+    }
+
+    struct Panicky;
+    impl Problem for Panicky {
+        type Solution1 = usize;
+        type Solution2 = usize;
+        type Parsed = String;
+
+        fn parse(input: &str) -> Self::Parsed {
+            input.to_string()
+        }
+
+        fn part_1(input: &Self::Parsed) -> Self::Solution1 {
+            input.len()
+        }
+
+        fn part_2(_input: &Self::Parsed) -> Self::Solution2 {
+            panic!("no part 2 today")
+        }
+    }
+
+    #[test]
+    fn test_run_with_timeout_panic() {
+        let err = Panicky::try_run_with_timeout("abc", &RunConfig::default(), Duration::from_secs(5)).unwrap_err();
+        assert_eq!(err.to_string(), "Part panicked: no part 2 today");
+
+        let mut out = Vec::new();
+        Panicky::benchmark_with_timeout_to("abc", Duration::from_secs(5), &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(!out.contains("timed out"), "{out}");
+    }
+
+    #[test]
+    fn test_run_with_timeout_config() {
+        let config = RunConfig { parts: Parts::Part1 };
+        let report = Sleepy::try_run_with_timeout("abc", &config, Duration::from_millis(100)).unwrap();
+        assert_eq!(report.part1.unwrap().0, "3");
+        // Part 2 is skipped rather than timing out
+        assert_eq!((report.part2, report.part2_timeout), (None, None));
+    }
+
+    #[test]
+    fn test_parse_timeout() {
+        let default = Duration::from_secs(10);
+        assert_eq!(parse_timeout(None, default), default);
+        assert_eq!(parse_timeout(Some("1.5"), default), Duration::from_millis(1500));
+        assert_eq!(parse_timeout(Some(" 2 "), default), Duration::from_secs(2));
+        assert_eq!(parse_timeout(Some("soon"), default), default);
+        assert_eq!(parse_timeout(Some("-1"), default), default);
+    }
+
+    #[test]
+    fn test_benchmark_with_timeout_finishes() {
+        let mut out = Vec::new();
+        PartOneOnly::benchmark_with_timeout_to("a\nb", Duration::from_secs(5), &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("Part 1 solution: 2 in "), "{out}");
+        assert!(out.ends_with("Part 2 solution: not implemented\n"), "{out}");
+    }
+
+    mod part_one_only {
+        use super::*;
+
+        test_part_1!(PartOneOnly, "a\nb", 2);
+    }
+
+    mod real_input {
+        use super::*;
+        test_real_input!(Mixed, "samples/real");
+    }
+
+    mod real_input_without_answers {
+        use super::*;
+        test_real_input!(Mixed, "samples/no_answers");
+    }
+
+    mod real_input_part_one_only {
+        use super::*;
+        test_real_input!(PartOneOnly, "samples/real_part_one_only");
+    }
+
+    #[test]
+    fn test_parse_no_part_2() {
+        assert_eq!("".parse(), Ok(NoPart2));
+        assert_eq!("-".parse(), Ok(NoPart2));
+    }
+
+    static COUNTED_PART_1_CALLS: AtomicUsize = AtomicUsize::new(0);
+    static COUNTED_PART_2_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    struct Counted;
+    impl Problem for Counted {
+        type Solution1 = usize;
+        type Solution2 = usize;
+        type Parsed = usize;
+
+        fn parse(input: &str) -> Self::Parsed {
+            input.len()
+        }
+
+        fn part_1(len: &Self::Parsed) -> Self::Solution1 {
+            COUNTED_PART_1_CALLS.fetch_add(1, Ordering::SeqCst);
+            *len
+        }
+
+        fn part_2(len: &Self::Parsed) -> Self::Solution2 {
+            COUNTED_PART_2_CALLS.fetch_add(1, Ordering::SeqCst);
+            len * 2
+        }
+    }
+
+    static PARSE_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    struct ParsesOnce;
+    impl Problem for ParsesOnce {
+        type Solution1 = usize;
+        type Solution2 = usize;
+        type Parsed = usize;
+
+        fn parse(input: &str) -> Self::Parsed {
+            PARSE_CALLS.fetch_add(1, Ordering::SeqCst);
+            input.len()
+        }
+
+        fn part_1(len: &Self::Parsed) -> Self::Solution1 {
+            *len
+        }
+
+        fn part_2(len: &Self::Parsed) -> Self::Solution2 {
+            len * 2
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(ParsesOnce::solve("abc"), (3, Some(6)));
+        assert_eq!(PARSE_CALLS.load(Ordering::SeqCst), 1);
+
+        assert_eq!(PartOneOnly::solve("a\nb"), (2, None));
+    }
+
+    #[test]
+    fn test_solve_normalizes() {
+        // solve sees the same input as the timed run, whatever the line endings
+        let input = "\u{feff}ab\r\ncd\r\n";
+        assert_eq!(Mixed::solve(input), (5, Some("dc\nba".to_string())));
+        assert_eq!(Mixed::run(input).part1.unwrap().0, "5");
+    }
+
+    mod normalized_cases {
+        use super::*;
+
+        test_part_1!(Mixed, "ab\r\ncd\r\n", 5);
+    }
+
+    #[test]
+    fn test_run_selected_part() {
+        let report = Counted::run_with("abc", &RunConfig { parts: Parts::Part2 });
+        assert_eq!(COUNTED_PART_1_CALLS.load(Ordering::SeqCst), 0);
+        assert_eq!(COUNTED_PART_2_CALLS.load(Ordering::SeqCst), 1);
+        assert_eq!(report.part1, None);
+        assert_eq!(report.part2.as_ref().unwrap().0, "6");
+        let display = report.to_string();
+        assert!(display.contains("Part 1 solution: skipped\n"), "{display}");
+        assert!(display.contains("Part 2 solution: 6 in "), "{display}");
+        assert!(report.to_csv_row().contains(",,,6,"));
+
+        let report = Counted::run_with("abc", &RunConfig { parts: Parts::Part1 });
+        assert_eq!(COUNTED_PART_1_CALLS.load(Ordering::SeqCst), 1);
+        assert_eq!(COUNTED_PART_2_CALLS.load(Ordering::SeqCst), 1);
+        assert!(report.to_string().ends_with("Part 2 solution: skipped\n"));
+    }
+
+    #[test]
+    fn test_run_report() {
+        let input = "abcdefghijklmnopqrstuvwxyz".repeat(100);
+        let report = Mixed::run(&input);
+        let (part1, part1_time) = report.part1.clone().unwrap();
+        assert_eq!(part1, "2600");
+        let (part2, part2_time) = report.part2.clone().unwrap();
+        assert_eq!(part2, format!("{:?}", input.chars().rev().collect::<String>()));
+        assert!(report.to_string().starts_with("Parse in "));
+        assert_eq!(report.total(), report.parse + part1_time + part2_time);
+    }
+
+    #[test]
+    #[cfg(feature = "count-allocs")]
+    fn test_run_counts_allocations() {
+        // Part 1 only takes the length, part 2 collects a new String
+        let report = Mixed::run("abcdef");
+        assert_eq!(report.part1_allocs.unwrap().allocations, 0);
+        assert!(report.part2_allocs.unwrap().allocations > 0);
+        assert!(report.to_string().contains("allocations"));
+        assert!(report.part2_peak_bytes.unwrap() > 0);
+        assert!(report.to_string().contains(", peak "));
+    }
+
+    thread_local!(static PREPARE_CALLS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) });
+
+    struct PreparedLength;
+    impl Problem for PreparedLength {
+        type Solution1 = usize;
+        type Solution2 = usize;
+        type Parsed = String;
+
+        fn parse(input: &str) -> Self::Parsed {
+            input.to_string()
+        }
+
+        fn part_1(input: &Self::Parsed) -> Self::Solution1 {
+            Self::part_1_prepared(&Self::prepare(input))
+        }
+
+        fn part_2(input: &Self::Parsed) -> Self::Solution2 {
+            Self::part_2_prepared(&Self::prepare(input))
+        }
+
+        fn solve(input: &str) -> (Self::Solution1, Option<Self::Solution2>) {
+            solve_prepared::<Self>(input)
+        }
+    }
+
+    impl PreparedProblem for PreparedLength {
+        type Intermediate = usize;
+
+        fn prepare(input: &Self::Parsed) -> Self::Intermediate {
+            PREPARE_CALLS.set(PREPARE_CALLS.get() + 1);
+            input.trim().len()
+        }
+
+        fn part_1_prepared(len: &Self::Intermediate) -> Self::Solution1 {
+            *len
+        }
+
+        fn part_2_prepared(len: &Self::Intermediate) -> Self::Solution2 {
+            len * 2
+        }
+    }
+
+    /// [PreparedLength]'s parts, without its `solve` override.
+    struct UnsharedLength;
+    impl Problem for UnsharedLength {
+        type Solution1 = usize;
+        type Solution2 = usize;
+        type Parsed = String;
+
+        fn parse(input: &str) -> Self::Parsed {
+            PreparedLength::parse(input)
+        }
+
+        fn part_1(input: &Self::Parsed) -> Self::Solution1 {
+            PreparedLength::part_1(input)
+        }
+
+        fn part_2(input: &Self::Parsed) -> Self::Solution2 {
+            PreparedLength::part_2(input)
+        }
+    }
+
+    #[test]
+    fn test_solve_override() {
+        // Each test runs on its own thread, so the count is this test's alone
+        assert_eq!(UnsharedLength::solve("abc"), (3, Some(6)));
+        assert_eq!(PREPARE_CALLS.get(), 2);
+
+        PREPARE_CALLS.set(0);
+        assert_eq!(PreparedLength::solve("abc"), (3, Some(6)));
+        assert_eq!(PREPARE_CALLS.get(), 1);
+    }
+
+    #[test]
+    fn test_run_prepared() {
+        let report = PreparedLength::try_run_prepared_with("abc", &RunConfig::default()).unwrap();
+        assert_eq!(report.part1.as_ref().unwrap().0, "3");
+        assert_eq!(report.part2.as_ref().unwrap().0, "6");
+        let prepare = report.prepare.unwrap();
+        assert_eq!(report.total(), report.parse + prepare + report.part1.unwrap().1 + report.part2.unwrap().1);
+
+        let report = PreparedLength::try_run_prepared_with("abc", &RunConfig { parts: Parts::Part2 }).unwrap();
+        let display = report.to_string();
+        let lines: Vec<_> = display.lines().collect();
+        assert!(lines[1].starts_with("Prepare in "), "{display}");
+        assert_eq!(lines[2], "Part 1 solution: skipped");
+        assert!(lines[3].starts_with("Part 2 solution: 6 in "), "{display}");
+        // Without a prepare step the line is left out
+        assert!(!PreparedLength::run("abc").to_string().contains("Prepare"));
+    }
+
+    #[test]
+    fn test_mixed_solutions_benchmark() {
+        let mut out = Vec::new();
+        Mixed::benchmark_to("abc", &mut out).unwrap();
+        let report = String::from_utf8(out).unwrap();
+        let lines: Vec<_> = report.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("Parse in "), "{report}");
+        assert!(lines[1].starts_with("Part 1 solution: 3 in "), "{report}");
+        assert!(lines[2].starts_with("Part 2 solution: \"cba\" in "), "{report}");
+    }
+}
@@ -0,0 +1,189 @@
+use std::ops::{Div, Mul, Rem};
+
+/// Integer types usable with [gcd] and [lcm].
+pub trait Integer: Copy + PartialEq + From<u8> + Rem<Output = Self> + Mul<Output = Self> + Div<Output = Self> {}
+
+impl<T> Integer for T
+where
+    T: Copy + PartialEq + From<u8> + Rem<Output = T> + Mul<Output = T> + Div<Output = T>
+{}
+
+/// Returns the greatest common divisor of two non-negative integers.
+///
+/// # Example
+/// ```
+/// # use aoc::math::gcd;
+/// assert_eq!(gcd(12, 18), 6);
+/// assert_eq!(gcd(7u64, 0), 7);
+/// ```
+pub fn gcd<T: Integer>(mut a: T, mut b: T) -> T {
+    while b != T::from(0) {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Returns the least common multiple of two non-negative integers.
+pub fn lcm_pair<T: Integer>(a: T, b: T) -> T {
+    if a == T::from(0) || b == T::from(0) {
+        return T::from(0);
+    }
+    a / gcd(a, b) * b
+}
+
+/// Returns the least common multiple of all the integers, or 1 if there are none.
+///
+/// # Example
+/// ```
+/// # use aoc::math::lcm;
+/// assert_eq!(lcm([4usize, 6, 10]), 60);
+/// ```
+pub fn lcm<T: Integer>(nums: impl IntoIterator<Item = T>) -> T {
+    nums.into_iter().fold(T::from(1), lcm_pair)
+}
+
+/// Returns `(g, x, y)` such that `a * x + b * y == g == gcd(a, b)`.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    (old_r, old_x, old_y)
+}
+
+/// Solves a system of congruences `x ≡ residue (mod modulus)`, given as `(residue, modulus)`
+/// pairs. The moduli don't need to be coprime.
+///
+/// Returns `(x, m)`, where `m` is the least common multiple of the moduli and `0 <= x < m`, or
+/// `None` if the congruences are inconsistent, a modulus isn't positive, or `m` overflows.
+///
+/// # Example
+/// ```
+/// # use aoc::math::crt;
+/// assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+/// assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+/// ```
+pub fn crt(residues: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let (mut x, mut m): (i128, i128) = (0, 1);
+    for &(residue, modulus) in residues {
+        if modulus <= 0 {
+            return None;
+        }
+        let (residue, modulus) = ((residue as i128).rem_euclid(modulus as i128), modulus as i128);
+        let (g, p, _) = extended_gcd(m, modulus);
+        if (residue - x) % g != 0 {
+            return None;
+        }
+        // x + m * k ≡ residue (mod modulus), so k ≡ (residue - x) / g * p (mod modulus / g)
+        let step = modulus / g;
+        let k = ((residue - x) / g % step * p).rem_euclid(step);
+        x += m * k;
+        m *= step;
+        if m > i64::MAX as i128 {
+            return None;
+        }
+    }
+    Some((x as i64, m as i64))
+}
+
+/// Finds the quadratic `a * x^2 + b * x + c` through all the points, returning `(a, b, c)`.
+///
+/// The curve is fitted to the first three points and checked against the rest. Returns `None` if
+/// there are fewer than three points, the x values aren't distinct, the coefficients aren't
+/// integers, or a point isn't on the curve.
+///
+/// # Example
+/// ```
+/// # use aoc::math::fit_quadratic;
+/// assert_eq!(fit_quadratic(&[(0, 1), (1, 4), (2, 11)]), Some((2, 1, 1)));
+/// assert_eq!(fit_quadratic(&[(0, 0), (1, 1), (2, 3)]), None);
+/// ```
+pub fn fit_quadratic(points: &[(i64, i64)]) -> Option<(i64, i64, i64)> {
+    let &[(x0, y0), (x1, y1), (x2, y2), ..] = points else { return None };
+    let [x0, y0, x1, y1, x2, y2] = [x0, y0, x1, y1, x2, y2].map(i128::from);
+
+    // Lagrange interpolation over the common denominator
+    let denominator = (x0 - x1) * (x0 - x2) * (x1 - x2);
+    if denominator == 0 {
+        return None;
+    }
+    let terms = [(y0 * (x1 - x2), x1, x2), (y1 * (x2 - x0), x0, x2), (y2 * (x0 - x1), x0, x1)];
+    let a: i128 = terms.iter().map(|&(w, _, _)| w).sum();
+    let b: i128 = terms.iter().map(|&(w, p, q)| -w * (p + q)).sum();
+    let c: i128 = terms.iter().map(|&(w, p, q)| w * p * q).sum();
+    if [a, b, c].iter().any(|n| n % denominator != 0) {
+        return None;
+    }
+    let (a, b, c) = (a / denominator, b / denominator, c / denominator);
+
+    let on_curve = points.iter().all(|&(x, y)| {
+        let x = i128::from(x);
+        a * x * x + b * x + c == i128::from(y)
+    });
+    let coefficients = (a.try_into().ok()?, b.try_into().ok()?, c.try_into().ok()?);
+    on_curve.then_some(coefficients)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        for a in 1..50u64 {
+            for b in 1..50u64 {
+                let g = gcd(a, b);
+                assert_eq!(a % g, 0);
+                assert_eq!(b % g, 0);
+                assert_eq!(lcm_pair(a, b) * g, a * b, "a = {a}, b = {b}");
+            }
+        }
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(lcm_pair(0, 5), 0);
+        assert_eq!(lcm(Vec::<usize>::new()), 1);
+        assert_eq!(lcm([2usize, 3, 4, 5, 6]), 60);
+    }
+
+    #[test]
+    fn test_crt_coprime() {
+        assert_eq!(crt(&[(0, 3), (3, 4), (4, 5)]), Some((39, 60)));
+        assert_eq!(crt(&[(-1, 3), (-1, 4)]), Some((11, 12)));
+        assert_eq!(crt(&[]), Some((0, 1)));
+
+        // Every x in 0..m has a distinct set of residues
+        let moduli = [3, 5, 7];
+        for x in 0..105 {
+            let residues: Vec<_> = moduli.iter().map(|&m| (x % m, m)).collect();
+            assert_eq!(crt(&residues), Some((x, 105)));
+        }
+    }
+
+    #[test]
+    fn test_crt_non_coprime() {
+        assert_eq!(crt(&[(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt(&[(3, 6), (3, 9), (3, 12)]), Some((3, 36)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[(1, 0)]), None);
+        assert_eq!(crt(&[(0, i64::MAX), (0, i64::MAX - 1)]), None);
+    }
+
+    #[test]
+    fn test_fit_quadratic() {
+        for (a, b, c) in [(2, 1, 1), (-3, 0, 7), (14590, 14694, 3699), (0, 5, -2)] {
+            let f = |x: i64| a * x * x + b * x + c;
+            let points: Vec<_> = [-4, 0, 3, 10, 11].iter().map(|&x| (x, f(x))).collect();
+            assert_eq!(fit_quadratic(&points[..3]), Some((a, b, c)));
+            assert_eq!(fit_quadratic(&points), Some((a, b, c)));
+        }
+        // y = x^2 / 2
+        assert_eq!(fit_quadratic(&[(0, 0), (2, 2), (4, 8)]), None);
+        assert_eq!(fit_quadratic(&[(0, 1), (1, 4), (2, 11), (3, 0)]), None);
+        assert_eq!(fit_quadratic(&[(0, 1), (0, 4), (2, 11)]), None);
+        assert_eq!(fit_quadratic(&[(0, 1), (1, 4)]), None);
+    }
+}
@@ -0,0 +1,228 @@
+use std::{fmt::Display, str::FromStr};
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{char, digit1, multispace0, space0},
+    combinator::{map_res, opt, recognize},
+    error::Error,
+    multi::separated_list1,
+    sequence::{delimited, pair, preceded, terminated},
+    IResult, Parser,
+};
+
+/// Parses an unsigned integer.
+///
+/// # Example
+/// ```
+/// # use aoc::parse::uint;
+/// assert_eq!(uint::<u64>()("42 apples"), Ok((" apples", 42)));
+/// ```
+pub fn uint<'a, T: FromStr>() -> impl FnMut(&'a str) -> IResult<&'a str, T> {
+    map_res(digit1, str::parse)
+}
+
+/// Parses an integer with an optional leading `-`.
+///
+/// # Example
+/// ```
+/// # use aoc::parse::int;
+/// assert_eq!(int::<i64>()("-7,3"), Ok((",3", -7)));
+/// ```
+pub fn int<'a, T: FromStr>() -> impl FnMut(&'a str) -> IResult<&'a str, T> {
+    map_res(recognize(pair(opt(char('-')), digit1)), str::parse)
+}
+
+/// Parses one or more integers divided by `separator`.
+///
+/// # Example
+/// ```
+/// # use aoc::parse::number_list;
+/// # use nom::character::complete::space1;
+/// assert_eq!(number_list::<u32, _, _>(space1)("83 86  6 | 31"), Ok((" | 31", vec![83, 86, 6])));
+/// ```
+pub fn number_list<'a, T, O, S>(separator: S) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>>
+where
+    T: FromStr,
+    S: Parser<&'a str, O, Error<&'a str>>,
+{
+    separated_list1(separator, int())
+}
+
+/// Wraps `inner` to skip whitespace, including newlines, on either side.
+pub fn ws<'a, O, F>(inner: F) -> impl FnMut(&'a str) -> IResult<&'a str, O>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    delimited(multispace0, inner, multispace0)
+}
+
+/// Wraps `inner` to skip spaces and tabs after it, but not newlines.
+pub fn lexeme<'a, O, F>(inner: F) -> impl FnMut(&'a str) -> IResult<&'a str, O>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    terminated(inner, space0)
+}
+
+/// Parses `label`, then any spaces, then `inner`.
+///
+/// # Example
+/// ```
+/// # use aoc::parse::{labeled, uint};
+/// assert_eq!(labeled("Card", uint::<u32>())("Card  12: 41"), Ok((": 41", 12)));
+/// ```
+pub fn labeled<'a, O, F>(label: &'a str, inner: F) -> impl FnMut(&'a str) -> IResult<&'a str, O>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    preceded(pair(tag(label), space0), inner)
+}
+
+/// An error from [lines_of] or [parse_line], with the 1-based line and column it occurred at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl LineError {
+    /// An error on line `line` at the start of `rest`, which is the end of the line's `text`.
+    /// Columns count characters, not bytes.
+    pub fn at(line: usize, text: &str, rest: &str, message: String) -> Self {
+        let column = text[..text.len() - rest.len()].chars().count() + 1;
+        LineError { line, column, message }
+    }
+}
+
+impl Display for LineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Line {}, column {}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for LineError {}
+
+/// Applies `parser` to each line of `input`, which must consume the whole line.
+///
+/// # Example
+/// ```
+/// # use aoc::parse::{lines_of, uint};
+/// assert_eq!(lines_of("1\n2\n3", uint::<u8>()), Ok(vec![1, 2, 3]));
+///
+/// let err = lines_of("1\n2x", uint::<u8>()).unwrap_err();
+/// assert_eq!(err.to_string(), "Line 2, column 2: unexpected \"x\"");
+/// ```
+pub fn lines_of<'a, T, F>(input: &'a str, mut parser: F) -> Result<Vec<T>, LineError>
+where
+    F: Parser<&'a str, T, Error<&'a str>>,
+{
+    input.lines()
+        .enumerate()
+        .map(|(n, line)| parse_line(line, n + 1, &mut parser))
+        .collect()
+}
+
+/// Applies `parser` to `text`, the 1-based `line` of the input, which must consume the whole line.
+/// Useful when lines are checked further after parsing, otherwise [lines_of] parses them all.
+///
+/// # Example
+/// ```
+/// # use aoc::parse::{parse_line, uint};
+/// assert_eq!(parse_line("12", 1, &mut uint::<u8>()), Ok(12));
+///
+/// let err = parse_line("1é", 4, &mut uint::<u8>()).unwrap_err();
+/// assert_eq!(err.to_string(), "Line 4, column 2: unexpected \"é\"");
+/// ```
+pub fn parse_line<'a, T, F>(text: &'a str, line: usize, parser: &mut F) -> Result<T, LineError>
+where
+    F: Parser<&'a str, T, Error<&'a str>>,
+{
+    match parser.parse(text) {
+        Ok(("", value)) => Ok(value),
+        Ok((rest, _)) => Err(LineError::at(line, text, rest, format!("unexpected {rest:?}"))),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => {
+            Err(LineError::at(line, text, e.input, format!("expected {:?} at {:?}", e.code, e.input)))
+        },
+        Err(nom::Err::Incomplete(_)) => Err(LineError::at(line, text, "", "incomplete input".to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{character::complete::space1, error::ErrorKind};
+
+    use super::*;
+
+    fn error_at(input: &str, code: ErrorKind) -> nom::Err<Error<&str>> {
+        nom::Err::Error(Error { input, code })
+    }
+
+    #[test]
+    fn test_uint() {
+        assert_eq!(uint::<u64>()("123abc"), Ok(("abc", 123)));
+        assert_eq!(uint::<u8>()("0"), Ok(("", 0)));
+        assert_eq!(uint::<u64>()("abc"), Err(error_at("abc", ErrorKind::Digit)));
+        assert_eq!(uint::<u64>()("-1"), Err(error_at("-1", ErrorKind::Digit)));
+        // Out of range for the type
+        assert_eq!(uint::<u8>()("256"), Err(error_at("256", ErrorKind::MapRes)));
+    }
+
+    #[test]
+    fn test_int() {
+        assert_eq!(int::<i64>()("-42 x"), Ok((" x", -42)));
+        assert_eq!(int::<i64>()("42"), Ok(("", 42)));
+        assert_eq!(int::<i64>()("- 4"), Err(error_at(" 4", ErrorKind::Digit)));
+        assert_eq!(int::<u32>()("-4"), Err(error_at("-4", ErrorKind::MapRes)));
+    }
+
+    #[test]
+    fn test_number_list() {
+        assert_eq!(number_list::<u32, _, _>(space1)("1  2 3|"), Ok(("|", vec![1, 2, 3])));
+        assert_eq!(number_list::<i32, _, _>(char(','))("-1,2,-3"), Ok(("", vec![-1, 2, -3])));
+        // A trailing separator is left unconsumed
+        assert_eq!(number_list::<u32, _, _>(char(','))("1,2,"), Ok((",", vec![1, 2])));
+        assert_eq!(number_list::<u32, _, _>(space1)(" 1"), Err(error_at(" 1", ErrorKind::Digit)));
+    }
+
+    #[test]
+    fn test_ws_and_lexeme() {
+        assert_eq!(ws(uint::<u32>())("\n  7 \nrest"), Ok(("rest", 7)));
+        assert_eq!(lexeme(uint::<u32>())("7  \nrest"), Ok(("\nrest", 7)));
+        assert_eq!(lexeme(uint::<u32>())(" 7"), Err(error_at(" 7", ErrorKind::Digit)));
+    }
+
+    #[test]
+    fn test_labeled() {
+        assert_eq!(labeled("seeds:", number_list::<u64, _, _>(space1))("seeds: 79 14"), Ok(("", vec![79, 14])));
+        assert_eq!(labeled("Card", uint::<u32>())("Card1"), Ok(("", 1)));
+        assert_eq!(labeled("Card", uint::<u32>())("Cart 1"), Err(error_at("Cart 1", ErrorKind::Tag)));
+        assert_eq!(labeled("Card", uint::<u32>())("Card x"), Err(error_at("x", ErrorKind::Digit)));
+    }
+
+    #[test]
+    fn test_lines_of() {
+        let parser = || labeled("n", uint::<u32>());
+        assert_eq!(lines_of("n1\nn 2\nn  3", parser()), Ok(vec![1, 2, 3]));
+        assert_eq!(lines_of("", parser()), Ok(vec![]));
+
+        let err = lines_of("n1\nn2\nn x", parser()).unwrap_err();
+        assert_eq!((err.line, err.column), (3, 3));
+        assert_eq!(err.to_string(), "Line 3, column 3: expected Digit at \"x\"");
+
+        let err = lines_of("n1\nn2 ", parser()).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.message, "unexpected \" \"");
+
+        let err = lines_of("m1", parser()).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+    }
+
+    #[test]
+    fn test_parse_line_columns() {
+        // Columns count characters, so a multibyte character is one column
+        let err = parse_line("é1x", 2, &mut labeled("é", uint::<u32>())).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(LineError::at(1, "ab", "", "end".to_string()).column, 3);
+    }
+}
@@ -0,0 +1,134 @@
+use std::ops::Range;
+
+/// Returns the number of values in the range, or 0 if it's empty.
+pub fn len(r: &Range<u64>) -> u64 {
+    r.end.saturating_sub(r.start)
+}
+
+/// Returns the values in both ranges, or `None` if they don't overlap.
+///
+/// # Example
+/// ```
+/// # use aoc::ranges::intersect;
+/// assert_eq!(intersect(&(0..10), &(5..15)), Some(5..10));
+/// assert_eq!(intersect(&(0..5), &(5..10)), None);
+/// ```
+pub fn intersect(a: &Range<u64>, b: &Range<u64>) -> Option<Range<u64>> {
+    let r = a.start.max(b.start)..a.end.min(b.end);
+    (!r.is_empty()).then_some(r)
+}
+
+/// Splits the range into the values below `point` and the rest. Either half may be empty.
+///
+/// # Example
+/// ```
+/// # use aoc::ranges::split_at;
+/// assert_eq!(split_at(&(0..10), 4), (0..4, 4..10));
+/// assert_eq!(split_at(&(0..10), 20), (0..10, 10..10));
+/// ```
+pub fn split_at(r: &Range<u64>, point: u64) -> (Range<u64>, Range<u64>) {
+    let point = point.clamp(r.start, r.end.max(r.start));
+    (r.start..point, point..r.end)
+}
+
+/// Returns the non-empty pieces of `a` that aren't in `b`, in ascending order. There are at
+/// most two, below and above `b`.
+///
+/// # Example
+/// ```
+/// # use aoc::ranges::subtract;
+/// assert_eq!(subtract(&(0..10), &(3..5)), vec![0..3, 5..10]);
+/// assert_eq!(subtract(&(0..10), &(5..15)), vec![0..5]);
+/// ```
+pub fn subtract(a: &Range<u64>, b: &Range<u64>) -> Vec<Range<u64>> {
+    if intersect(a, b).is_none() {
+        return if a.is_empty() { vec![] } else { vec![a.clone()] };
+    }
+    [a.start..b.start, b.end..a.end].into_iter()
+        .filter(|r| !r.is_empty())
+        .collect()
+}
+
+/// Sorts the ranges and merges any that overlap. Ranges that only touch, like `0..5` and
+/// `5..10`, are kept separate. Empty ranges are dropped.
+///
+/// # Example
+/// ```
+/// # use aoc::ranges::merge_overlapping;
+/// assert_eq!(merge_overlapping(vec![8..12, 0..5, 3..6, 12..13]), vec![0..6, 8..12, 12..13]);
+/// ```
+pub fn merge_overlapping(mut ranges: Vec<Range<u64>>) -> Vec<Range<u64>> {
+    ranges.retain(|r| !r.is_empty());
+    ranges.sort_unstable_by_key(|r| (r.start, r.end));
+    let mut merged: Vec<Range<u64>> = Vec::with_capacity(ranges.len());
+    for r in ranges {
+        match merged.last_mut() {
+            Some(last) if r.start < last.end => last.end = last.end.max(r.end),
+            _ => merged.push(r),
+        }
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_len() {
+        assert_eq!(len(&(3..10)), 7);
+        assert_eq!(len(&(5..5)), 0);
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = 10..3;
+        assert_eq!(len(&reversed), 0);
+    }
+
+    #[test]
+    fn test_intersect() {
+        assert_eq!(intersect(&(0..10), &(2..4)), Some(2..4));
+        assert_eq!(intersect(&(2..4), &(0..10)), Some(2..4));
+        assert_eq!(intersect(&(0..10), &(0..10)), Some(0..10));
+        assert_eq!(intersect(&(0..10), &(9..20)), Some(9..10));
+        // Adjacent ranges share no values
+        assert_eq!(intersect(&(0..10), &(10..20)), None);
+        assert_eq!(intersect(&(10..20), &(0..10)), None);
+        assert_eq!(intersect(&(0..10), &(15..20)), None);
+        // Empty ranges intersect with nothing
+        assert_eq!(intersect(&(5..5), &(0..10)), None);
+        assert_eq!(intersect(&(0..10), &(3..3)), None);
+    }
+
+    #[test]
+    fn test_split_at() {
+        assert_eq!(split_at(&(5..10), 7), (5..7, 7..10));
+        assert_eq!(split_at(&(5..10), 5), (5..5, 5..10));
+        assert_eq!(split_at(&(5..10), 10), (5..10, 10..10));
+        assert_eq!(split_at(&(5..10), 0), (5..5, 5..10));
+        assert_eq!(split_at(&(5..5), 7), (5..5, 5..5));
+    }
+
+    #[test]
+    fn test_subtract() {
+        assert_eq!(subtract(&(0..10), &(0..10)), Vec::<Range<u64>>::new());
+        assert_eq!(subtract(&(0..10), &(0..20)), Vec::<Range<u64>>::new());
+        assert_eq!(subtract(&(0..10), &(0..4)), vec![4..10]);
+        assert_eq!(subtract(&(0..10), &(4..10)), vec![0..4]);
+        assert_eq!(subtract(&(0..10), &(4..6)), vec![0..4, 6..10]);
+        // Adjacent or disjoint ranges leave `a` whole
+        assert_eq!(subtract(&(0..10), &(10..20)), vec![0..10]);
+        assert_eq!(subtract(&(10..20), &(0..10)), vec![10..20]);
+        // Subtracting an empty range, or from one
+        assert_eq!(subtract(&(0..10), &(5..5)), vec![0..10]);
+        assert_eq!(subtract(&(5..5), &(0..10)), Vec::<Range<u64>>::new());
+    }
+
+    #[test]
+    fn test_merge_overlapping() {
+        assert_eq!(merge_overlapping(vec![]), Vec::<Range<u64>>::new());
+        assert_eq!(merge_overlapping(vec![0..5, 2..8, 1..3]), vec![0..8]);
+        assert_eq!(merge_overlapping(vec![10..20, 0..5]), vec![0..5, 10..20]);
+        assert_eq!(merge_overlapping(vec![0..5, 5..10]), vec![0..5, 5..10]);
+        assert_eq!(merge_overlapping(vec![0..20, 5..10, 15..30]), vec![0..30]);
+        assert_eq!(merge_overlapping(vec![3..3, 0..2, 7..7]), vec![0..2]);
+    }
+}
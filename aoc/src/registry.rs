@@ -0,0 +1,300 @@
+use std::collections::BTreeMap;
+
+use anyhow::anyhow;
+
+use crate::{compare::{Comparison, Divergence}, BenchmarkReport, PreparedProblem, Problem};
+
+/// Solves both parts of an input, returning the solutions in their `Debug` format.
+pub type Solver = Box<dyn Fn(&str) -> (String, String) + Send + Sync>;
+
+/// Checks a second implementation of a day against the first, such as [crate::compare::compare].
+pub type Comparer = fn(&[&str]) -> Result<Comparison, Divergence>;
+
+/// A registered day: its solver, where its input is, and any alternative implementations.
+struct Entry {
+    solver: Solver,
+    try_run: fn(&str) -> anyhow::Result<BenchmarkReport>,
+    input_path: Option<&'static str>,
+    alternatives: Vec<Comparer>,
+}
+
+/// Solvers for each registered day, for running a day chosen at runtime.
+///
+/// # Example
+/// ```
+/// # use aoc::{Problem, register_day, registry::Registry};
+/// struct Double;
+/// impl Problem for Double {
+///     type Solution1 = usize;
+///     type Solution2 = usize;
+///     type Parsed = usize;
+///     fn parse(input: &str) -> usize { input.len() }
+///     fn part_1(len: &usize) -> usize { len * 2 }
+///     fn part_2(len: &usize) -> usize { len * 4 }
+/// }
+///
+/// let registry = register_day!(Registry::builder(), 1, Double).build();
+/// assert_eq!(registry.run(1, "abc").unwrap(), ("6".to_string(), "12".to_string()));
+/// ```
+pub struct Registry {
+    days: BTreeMap<u8, Entry>,
+}
+
+impl Registry {
+    pub fn builder() -> RegistryBuilder {
+        RegistryBuilder { days: BTreeMap::new() }
+    }
+
+    /// Solves both parts of `input` with the solver registered for `day`, normalizing the input
+    /// first as [Problem::benchmark] does.
+    ///
+    /// Part 2 is reported as `-` if the day doesn't implement it.
+    pub fn run(&self, day: u8, input: &str) -> anyhow::Result<(String, String)> {
+        Ok((self.entry(day)?.solver)(input))
+    }
+
+    /// Solves and times each part of `input` with [Problem::try_run] for `day`, or with
+    /// [PreparedProblem::try_run_prepared] if it was registered with [RegistryBuilder::register_prepared].
+    pub fn try_run(&self, day: u8, input: &str) -> anyhow::Result<BenchmarkReport> {
+        (self.entry(day)?.try_run)(input)
+    }
+
+    fn entry(&self, day: u8) -> anyhow::Result<&Entry> {
+        self.days.get(&day).ok_or_else(|| {
+            let days: Vec<_> = self.days().map(|day| day.to_string()).collect();
+            anyhow!("Day {day} isn't registered (registered days: {})", days.join(", "))
+        })
+    }
+
+    /// Returns the registered days in ascending order.
+    pub fn days(&self) -> impl Iterator<Item = u8> + '_ {
+        self.days.keys().copied()
+    }
+
+    /// Returns the path of `day`'s input, if it was registered with one.
+    pub fn input_path(&self, day: u8) -> Option<&'static str> {
+        self.days.get(&day)?.input_path
+    }
+
+    /// Returns each alternative implementation's comparer with its day, in ascending day order.
+    pub fn alternatives(&self) -> impl Iterator<Item = (u8, Comparer)> + '_ {
+        self.days.iter().flat_map(|(&day, entry)| entry.alternatives.iter().map(move |&compare| (day, compare)))
+    }
+}
+
+pub struct RegistryBuilder {
+    days: BTreeMap<u8, Entry>,
+}
+
+impl RegistryBuilder {
+    /// Registers `P` as the solution to `day`. See also [crate::register_day].
+    ///
+    /// Panics if `day` is already registered.
+    pub fn register<P: Problem>(self, day: u8) -> Self {
+        self.insert::<P>(day, P::try_run)
+    }
+
+    /// Like [RegistryBuilder::register], but [Registry::try_run] prepares the input once for both
+    /// parts and reports the time spent in [PreparedProblem::prepare].
+    pub fn register_prepared<P: PreparedProblem>(self, day: u8) -> Self {
+        self.insert::<P>(day, P::try_run_prepared)
+    }
+
+    fn insert<P: Problem>(mut self, day: u8, try_run: fn(&str) -> anyhow::Result<BenchmarkReport>) -> Self {
+        let solver: Solver = Box::new(|input| {
+            let (part1, part2) = P::solve(input);
+            (format!("{:?}", part1), part2.map_or("-".to_string(), |part2| format!("{:?}", part2)))
+        });
+        let entry = Entry { solver, try_run, input_path: None, alternatives: Vec::new() };
+        if self.days.insert(day, entry).is_some() {
+            panic!("Day {day} is registered twice");
+        }
+        self
+    }
+
+    /// Like [RegistryBuilder::register], also recording where the day's input is.
+    pub fn register_with_input<P: Problem>(self, day: u8, input_path: &'static str) -> Self {
+        self.register::<P>(day).with_input(day, input_path)
+    }
+
+    /// Like [RegistryBuilder::register_prepared], also recording where the day's input is.
+    pub fn register_prepared_with_input<P: PreparedProblem>(self, day: u8, input_path: &'static str) -> Self {
+        self.register_prepared::<P>(day).with_input(day, input_path)
+    }
+
+    fn with_input(mut self, day: u8, input_path: &'static str) -> Self {
+        self.days.get_mut(&day).expect("day was just registered").input_path = Some(input_path);
+        self
+    }
+
+    /// Registers `P2` as an alternative implementation of `day`, checked against `P1` with
+    /// [crate::compare::compare].
+    ///
+    /// Panics if `day` isn't registered yet.
+    pub fn register_alternative<P1, P2>(mut self, day: u8) -> Self
+    where
+        P1: Problem,
+        P2: Problem<Solution1 = P1::Solution1, Solution2 = P1::Solution2>,
+        P1::Solution1: PartialEq,
+        P1::Solution2: PartialEq,
+    {
+        let Some(entry) = self.days.get_mut(&day) else {
+            panic!("Day {day} must be registered before its alternatives");
+        };
+        entry.alternatives.push(crate::compare::compare::<P1, P2>);
+        self
+    }
+
+    pub fn build(self) -> Registry {
+        Registry { days: self.days }
+    }
+}
+
+/// Registers a [Problem] for a day number with a [RegistryBuilder], returning the builder.
+///
+/// `register_day!(builder, 17, Day17)` is shorthand for `builder.register::<Day17>(17)`, and
+/// `register_day!(builder, 17, Day17, input = path)` for `builder.register_with_input::<Day17>(17, path)`.
+/// Start the type with `prepared` to register a [PreparedProblem], e.g. `register_day!(builder, 22, prepared Day22)`.
+#[macro_export]
+macro_rules! register_day {
+    ($builder:expr, $day:expr, prepared $t:ty) => {
+        $builder.register_prepared::<$t>($day)
+    };
+    ($builder:expr, $day:expr, prepared $t:ty, input = $path:expr) => {
+        $builder.register_prepared_with_input::<$t>($day, $path)
+    };
+    ($builder:expr, $day:expr, $t:ty) => {
+        $builder.register::<$t>($day)
+    };
+    ($builder:expr, $day:expr, $t:ty, input = $path:expr) => {
+        $builder.register_with_input::<$t>($day, $path)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NoPart2;
+
+    struct Length;
+    impl Problem for Length {
+        type Solution1 = usize;
+        type Solution2 = usize;
+        type Parsed = String;
+        fn parse(input: &str) -> String { input.to_string() }
+        fn part_1(input: &String) -> usize { input.len() }
+        fn part_2(input: &String) -> usize { input.lines().count() }
+    }
+
+    struct Upper;
+    impl Problem for Upper {
+        type Solution1 = String;
+        type Solution2 = NoPart2;
+        type Parsed = String;
+        fn parse(input: &str) -> String { input.to_uppercase() }
+        fn part_1(input: &String) -> String { input.clone() }
+        fn part_2(_input: &String) -> NoPart2 { NoPart2 }
+    }
+
+    struct PreparedLength;
+    impl Problem for PreparedLength {
+        type Solution1 = usize;
+        type Solution2 = usize;
+        type Parsed = String;
+        fn parse(input: &str) -> String { input.to_string() }
+        fn part_1(input: &String) -> usize { Self::part_1_prepared(&Self::prepare(input)) }
+        fn part_2(input: &String) -> usize { Self::part_2_prepared(&Self::prepare(input)) }
+    }
+
+    impl PreparedProblem for PreparedLength {
+        type Intermediate = usize;
+        fn prepare(input: &String) -> usize { input.len() }
+        fn part_1_prepared(len: &usize) -> usize { *len }
+        fn part_2_prepared(len: &usize) -> usize { len * 2 }
+    }
+
+    fn registry() -> Registry {
+        let builder = register_day!(Registry::builder(), 17, Length);
+        register_day!(builder, 3, Upper).build()
+    }
+
+    #[test]
+    fn test_days_sorted() {
+        assert_eq!(registry().days().collect::<Vec<_>>(), [3, 17]);
+    }
+
+    #[test]
+    fn test_run() {
+        let registry = registry();
+        assert_eq!(registry.run(17, "ab\ncd").unwrap(), ("5".to_string(), "2".to_string()));
+        assert_eq!(registry.run(3, "ab").unwrap(), ("\"AB\"".to_string(), "-".to_string()));
+    }
+
+    #[test]
+    fn test_try_run() {
+        let registry = registry();
+        let report = registry.try_run(17, "ab\r\ncd").unwrap();
+        assert_eq!(report.part1.unwrap().0, "5");
+        assert_eq!(report.part2.unwrap().0, "2");
+        assert!(registry.try_run(3, "ab").unwrap().part2.is_none());
+        assert!(registry.try_run(5, "").is_err());
+    }
+
+    #[test]
+    fn test_try_run_prepared() {
+        let registry = register_day!(Registry::builder(), 22, prepared PreparedLength, input = "day_22/src/input.txt")
+            .register::<Length>(17)
+            .build();
+        let report = registry.try_run(22, "abc").unwrap();
+        assert_eq!((report.part1.unwrap().0, report.part2.unwrap().0), ("3".to_string(), "6".to_string()));
+        assert!(report.prepare.is_some());
+        assert_eq!(registry.input_path(22), Some("day_22/src/input.txt"));
+        assert_eq!(registry.run(22, "abc").unwrap(), ("3".to_string(), "6".to_string()));
+        // Days registered without a prepare step don't report one
+        assert!(registry.try_run(17, "abc").unwrap().prepare.is_none());
+    }
+
+    #[test]
+    fn test_run_normalizes() {
+        assert_eq!(registry().run(17, "ab\r\ncd\r\n").unwrap(), ("5".to_string(), "2".to_string()));
+    }
+
+    #[test]
+    fn test_input_paths_and_alternatives() {
+        let registry = register_day!(Registry::builder(), 17, Length, input = "day_17/src/input.txt")
+            .register_alternative::<Length, Length>(17)
+            .register::<Upper>(3)
+            .build();
+        assert_eq!(registry.input_path(17), Some("day_17/src/input.txt"));
+        assert_eq!(registry.input_path(3), None);
+        assert_eq!(registry.input_path(5), None);
+
+        let alternatives: Vec<_> = registry.alternatives().collect();
+        assert_eq!(alternatives.len(), 1);
+        let (day, compare) = alternatives[0];
+        assert_eq!(day, 17);
+        assert!(compare(&["ab\ncd"]).is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "Day 4 must be registered before its alternatives")]
+    fn test_alternative_unregistered() {
+        registry_builder().register_alternative::<Length, Length>(4);
+    }
+
+    #[test]
+    fn test_run_unregistered() {
+        let err = registry().run(5, "").unwrap_err();
+        assert_eq!(err.to_string(), "Day 5 isn't registered (registered days: 3, 17)");
+    }
+
+    #[test]
+    #[should_panic(expected = "Day 3 is registered twice")]
+    fn test_register_twice() {
+        register_day!(registry_builder(), 3, Length);
+    }
+
+    fn registry_builder() -> RegistryBuilder {
+        register_day!(Registry::builder(), 3, Upper)
+    }
+}
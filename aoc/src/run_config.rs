@@ -0,0 +1,115 @@
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Context};
+
+/// Which parts of a problem to run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum Parts {
+    #[default]
+    Both,
+    Part1,
+    Part2,
+}
+
+impl Parts {
+    /// Whether `part` (1 or 2) should run.
+    pub fn includes(&self, part: u8) -> bool {
+        matches!((self, part), (Parts::Both, 1 | 2) | (Parts::Part1, 1) | (Parts::Part2, 2))
+    }
+}
+
+impl FromStr for Parts {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "1" => Ok(Parts::Part1),
+            "2" => Ok(Parts::Part2),
+            "both" => Ok(Parts::Both),
+            other => Err(anyhow!("Expected 1, 2 or both for the part to run, got {other:?}")),
+        }
+    }
+}
+
+/// Options for [crate::Problem::run_with] and [crate::Problem::benchmark_with].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RunConfig {
+    pub parts: Parts,
+}
+
+impl RunConfig {
+    /// Reads the config from the environment: `AOC_PART=1|2|both` selects the parts to run.
+    pub fn from_env() -> anyhow::Result<Self> {
+        Self::from_part_var(std::env::var("AOC_PART").ok().as_deref())
+    }
+
+    /// Like [RunConfig::from_env], with the value of `AOC_PART` passed in.
+    pub fn from_part_var(part_var: Option<&str>) -> anyhow::Result<Self> {
+        let mut config = RunConfig::default();
+        if let Some(parts) = part_var {
+            config.parts = parts.parse().context("Invalid AOC_PART")?;
+        }
+        Ok(config)
+    }
+
+    /// Like [RunConfig::from_env], but a `--part 1|2|both` argument takes precedence over
+    /// `AOC_PART`. The option is removed from `args`, leaving any other arguments.
+    pub fn from_env_and_args(args: &mut Vec<String>) -> anyhow::Result<Self> {
+        Self::from_part_var_and_args(std::env::var("AOC_PART").ok().as_deref(), args)
+    }
+
+    /// Like [RunConfig::from_env_and_args], with the value of `AOC_PART` passed in.
+    pub fn from_part_var_and_args(part_var: Option<&str>, args: &mut Vec<String>) -> anyhow::Result<Self> {
+        let mut config = RunConfig::from_part_var(part_var)?;
+        if let Some(i) = args.iter().position(|arg| arg == "--part") {
+            let Some(parts) = args.get(i + 1) else { bail!("Missing value for --part") };
+            config.parts = parts.parse()?;
+            args.drain(i..=i + 1);
+        }
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parts() {
+        assert_eq!("1".parse::<Parts>().unwrap(), Parts::Part1);
+        assert_eq!("2\n".parse::<Parts>().unwrap(), Parts::Part2);
+        assert_eq!("both".parse::<Parts>().unwrap(), Parts::Both);
+        assert!("3".parse::<Parts>().is_err());
+
+        assert!(Parts::Both.includes(1) && Parts::Both.includes(2));
+        assert!(Parts::Part1.includes(1) && !Parts::Part1.includes(2));
+        assert!(!Parts::Part2.includes(1) && Parts::Part2.includes(2));
+    }
+
+    #[test]
+    fn test_from_args() {
+        let mut args = vec!["input.txt".to_string(), "--part".to_string(), "2".to_string()];
+        let config = RunConfig::from_part_var_and_args(None, &mut args).unwrap();
+        assert_eq!(config.parts, Parts::Part2);
+        assert_eq!(args, vec!["input.txt"]);
+
+        let mut args = vec!["--part".to_string()];
+        assert!(RunConfig::from_part_var_and_args(None, &mut args).is_err());
+        let mut args = vec!["--part".to_string(), "x".to_string()];
+        assert!(RunConfig::from_part_var_and_args(None, &mut args).is_err());
+    }
+
+    #[test]
+    fn test_from_part_var() {
+        assert_eq!(RunConfig::from_part_var(None).unwrap().parts, Parts::Both);
+        assert_eq!(RunConfig::from_part_var(Some("1")).unwrap().parts, Parts::Part1);
+        assert!(RunConfig::from_part_var(Some("x")).is_err());
+
+        // --part takes precedence over AOC_PART
+        let mut args = vec!["--part".to_string(), "2".to_string()];
+        let config = RunConfig::from_part_var_and_args(Some("1"), &mut args).unwrap();
+        assert_eq!(config.parts, Parts::Part2);
+        let config = RunConfig::from_part_var_and_args(Some("1"), &mut Vec::new()).unwrap();
+        assert_eq!(config.parts, Parts::Part1);
+    }
+}
@@ -0,0 +1,122 @@
+//! Compares a fast solver to a slow reference on random inputs.
+
+use std::fmt::Debug;
+
+pub use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Environment variable that seeds [check], to reproduce a failure.
+pub const SEED_VAR: &str = "AOC_STRESS_SEED";
+
+/// Returns the seed from `AOC_STRESS_SEED`, or a random one if it isn't set.
+///
+/// Panics if the variable isn't a number.
+pub fn seed_from_env() -> u64 {
+    parse_seed(std::env::var(SEED_VAR).ok().as_deref())
+}
+
+fn parse_seed(value: Option<&str>) -> u64 {
+    match value {
+        Some(seed) => seed.trim().parse().unwrap_or_else(|_| panic!("{SEED_VAR} should be a number, got {seed:?}")),
+        None => rand::random(),
+    }
+}
+
+/// Generates `cases` inputs with `gen` and checks that `fast` and `slow` agree on each one.
+///
+/// Each case gets its own RNG, seeded with the seed from [seed_from_env] plus the case number.
+/// If any cases disagree, panics with the smallest failing input (by the length of its `Debug`
+/// output) and its seed. Setting `AOC_STRESS_SEED` to that seed makes it the first case generated.
+///
+/// # Example
+///
+/// ```
+/// # use aoc::stress::{check, Rng};
+/// let sum_of_range = |n: &u64| n * (n + 1) / 2;
+/// let brute_force = |n: &u64| (1..=*n).sum::<u64>();
+/// check(|rng| rng.gen_range(0..1000), sum_of_range, brute_force, 100);
+/// ```
+pub fn check<I, O, A, B>(gen: impl Fn(&mut StdRng) -> I, fast: A, slow: B, cases: usize)
+where
+    I: Debug,
+    O: PartialEq + Debug,
+    A: Fn(&I) -> O,
+    B: Fn(&I) -> O,
+{
+    check_seeded(seed_from_env(), gen, fast, slow, cases);
+}
+
+/// Like [check], starting from an explicit `seed`.
+pub fn check_seeded<I, O, A, B>(seed: u64, gen: impl Fn(&mut StdRng) -> I, fast: A, slow: B, cases: usize)
+where
+    I: Debug,
+    O: PartialEq + Debug,
+    A: Fn(&I) -> O,
+    B: Fn(&I) -> O,
+{
+    let mut failures = 0;
+    let mut smallest: Option<(u64, String, O, O)> = None;
+    for case in 0..cases as u64 {
+        let case_seed = seed.wrapping_add(case);
+        let input = gen(&mut StdRng::seed_from_u64(case_seed));
+        let (fast_output, slow_output) = (fast(&input), slow(&input));
+        if fast_output == slow_output {
+            continue;
+        }
+        failures += 1;
+        let input = format!("{input:?}");
+        if smallest.as_ref().is_none_or(|(_, smallest, _, _)| input.len() < smallest.len()) {
+            smallest = Some((case_seed, input, fast_output, slow_output));
+        }
+    }
+    if let Some((case_seed, input, fast_output, slow_output)) = smallest {
+        panic!(
+            "{failures} of {cases} cases failed, smallest input: {input}\n\
+            fast: {fast_output:?}\n\
+            slow: {slow_output:?}\n\
+            Rerun with {SEED_VAR}={case_seed} to reproduce"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn failure_message(f: impl FnOnce() + std::panic::UnwindSafe) -> String {
+        *std::panic::catch_unwind(f).unwrap_err().downcast::<String>().unwrap()
+    }
+
+    #[test]
+    fn test_check_passes() {
+        check_seeded(1, |rng| rng.gen_range(0..100u32), |n| n * 2, |n| n + n, 200);
+    }
+
+    #[test]
+    fn test_check_reports_smallest_failure() {
+        // Wrong for every vector with a 7 in it
+        let gen = |rng: &mut StdRng| (0..rng.gen_range(1..20)).map(|_| rng.gen_range(0..10)).collect::<Vec<u32>>();
+        let fast = |v: &Vec<u32>| v.iter().filter(|&&n| n != 7).sum::<u32>();
+        let slow = |v: &Vec<u32>| v.iter().sum::<u32>();
+        let message = failure_message(|| check_seeded(42, gen, fast, slow, 100));
+
+        assert!(message.contains(" of 100 cases failed, smallest input: ["), "{message}");
+        let seed: u64 = message.rsplit_once(&format!("{SEED_VAR}=")).unwrap().1
+            .split_whitespace().next().unwrap()
+            .parse().unwrap();
+        assert!((42..142).contains(&seed), "{message}");
+
+        // The reported seed reproduces the same input as the first case
+        let input = gen(&mut StdRng::seed_from_u64(seed));
+        assert!(message.contains(&format!("smallest input: {input:?}\n")), "{message}");
+        assert_ne!(fast(&input), slow(&input));
+        let reproduced = failure_message(|| check_seeded(seed, gen, fast, slow, 1));
+        assert!(reproduced.starts_with("1 of 1 cases failed"), "{reproduced}");
+    }
+
+    #[test]
+    fn test_parse_seed() {
+        assert_eq!(parse_seed(Some("1234")), 1234);
+        assert_eq!(parse_seed(Some(" 5\n")), 5);
+        assert!(failure_message(|| { parse_seed(Some("abc")); }).contains(SEED_VAR));
+    }
+}
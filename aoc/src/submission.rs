@@ -0,0 +1,154 @@
+use std::time::Duration;
+
+use anyhow::{bail, Context};
+
+use crate::input::USER_AGENT;
+
+/// The HTTP requests made to adventofcode.com, so they can be replaced in tests.
+pub trait HttpClient {
+    /// Posts `form` to `url` with the `session` cookie, returning the response body.
+    fn post_form(&self, url: &str, session: &str, form: &[(&str, &str)]) -> anyhow::Result<String>;
+}
+
+/// An [HttpClient] that makes real requests.
+pub struct UreqClient;
+
+impl HttpClient for UreqClient {
+    fn post_form(&self, url: &str, session: &str, form: &[(&str, &str)]) -> anyhow::Result<String> {
+        let response = ureq::post(url)
+            .set("Cookie", &format!("session={session}"))
+            .set("User-Agent", USER_AGENT)
+            .send_form(form)
+            .with_context(|| format!("Couldn't post to {url}"))?;
+        Ok(response.into_string()?)
+    }
+}
+
+/// The outcome of submitting an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubmitResult {
+    Correct,
+    Incorrect,
+    /// An answer was submitted too recently, with the time left to wait.
+    TooRecent(Duration),
+    /// The part was already solved, or isn't unlocked yet.
+    AlreadyDone,
+}
+
+/// Submits `answer` for `part` of `year` and `day`, using the session cookie in `AOC_SESSION`.
+pub fn submit(year: u16, day: u8, part: u8, answer: &str) -> anyhow::Result<SubmitResult> {
+    let session = std::env::var("AOC_SESSION").context("AOC_SESSION isn't set")?;
+    submit_with(&UreqClient, &session, year, day, part, answer)
+}
+
+/// Like [submit], but with an explicit [HttpClient] and session cookie.
+pub fn submit_with(
+    client: &impl HttpClient,
+    session: &str,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> anyhow::Result<SubmitResult> {
+    if !(1..=2).contains(&part) {
+        bail!("Can't submit part {part}, there are only parts 1 and 2");
+    }
+    let url = format!("https://adventofcode.com/{year}/day/{day}/answer");
+    let level = part.to_string();
+    let html = client.post_form(&url, session, &[("level", &level), ("answer", answer)])?;
+    parse_response(&html)
+}
+
+/// Parses the HTML page returned after submitting an answer.
+pub fn parse_response(html: &str) -> anyhow::Result<SubmitResult> {
+    if html.contains("That's the right answer") {
+        Ok(SubmitResult::Correct)
+    } else if html.contains("That's not the right answer") {
+        Ok(SubmitResult::Incorrect)
+    } else if html.contains("You gave an answer too recently") {
+        Ok(SubmitResult::TooRecent(parse_wait(html).unwrap_or_default()))
+    } else if html.contains("You don't seem to be solving the right level") {
+        Ok(SubmitResult::AlreadyDone)
+    } else {
+        bail!("Unrecognized response to answer submission")
+    }
+}
+
+// Parses the wait from e.g. "You have 1m 34s left to wait."
+fn parse_wait(html: &str) -> Option<Duration> {
+    let start = html.find("You have ")? + "You have ".len();
+    let end = start + html[start..].find(" left to wait")?;
+    html[start..end].split_whitespace()
+        .map(|part| {
+            let (unit_start, _) = part.char_indices().last()?;
+            let (n, unit) = part.split_at(unit_start);
+            let n: u64 = n.parse().ok()?;
+            match unit {
+                "h" => Some(n * 3600),
+                "m" => Some(n * 60),
+                "s" => Some(n),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    macro_rules! fixture {
+        ($name:literal) => { include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/samples/submit/", $name)) };
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(parse_response(fixture!("correct.html")).unwrap(), SubmitResult::Correct);
+        assert_eq!(parse_response(fixture!("incorrect.html")).unwrap(), SubmitResult::Incorrect);
+        assert_eq!(parse_response(fixture!("too_recent.html")).unwrap(), SubmitResult::TooRecent(Duration::from_secs(94)));
+        assert_eq!(parse_response(fixture!("already_done.html")).unwrap(), SubmitResult::AlreadyDone);
+        assert!(parse_response("<html>Internal Server Error</html>").is_err());
+    }
+
+    #[test]
+    fn test_parse_wait() {
+        assert_eq!(parse_wait("You have 34s left to wait."), Some(Duration::from_secs(34)));
+        assert_eq!(parse_wait("You have 5m left to wait."), Some(Duration::from_secs(300)));
+        assert_eq!(parse_wait("You have soon left to wait."), None);
+        // A multibyte unit isn't split mid-character
+        assert_eq!(parse_wait("You have 5µ left to wait."), None);
+    }
+
+    type Request = (String, String, Vec<(String, String)>);
+
+    struct MockClient {
+        response: &'static str,
+        requests: RefCell<Vec<Request>>,
+    }
+
+    impl HttpClient for MockClient {
+        fn post_form(&self, url: &str, session: &str, form: &[(&str, &str)]) -> anyhow::Result<String> {
+            let form = form.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+            self.requests.borrow_mut().push((url.to_string(), session.to_string(), form));
+            Ok(self.response.to_string())
+        }
+    }
+
+    #[test]
+    fn test_submit_with() {
+        let client = MockClient { response: fixture!("correct.html"), requests: RefCell::new(vec![]) };
+        assert_eq!(submit_with(&client, "secret", 2023, 5, 2, "46").unwrap(), SubmitResult::Correct);
+        assert_eq!(client.requests.into_inner(), vec![(
+            "https://adventofcode.com/2023/day/5/answer".to_string(),
+            "secret".to_string(),
+            vec![("level".to_string(), "2".to_string()), ("answer".to_string(), "46".to_string())],
+        )]);
+
+        let client = MockClient { response: fixture!("correct.html"), requests: RefCell::new(vec![]) };
+        assert!(submit_with(&client, "secret", 2023, 5, 3, "46").is_err());
+        assert!(client.requests.into_inner().is_empty());
+    }
+}
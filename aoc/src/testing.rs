@@ -0,0 +1,117 @@
+use std::{fmt::Debug, fs, path::Path, str::FromStr};
+
+use crate::{prepare_input, Problem};
+
+/// Removes a single trailing newline from a sample file, so files can end with a newline
+/// without it becoming part of the input.
+///
+/// # Example
+///
+/// ```
+/// # use aoc::testing::trim_sample;
+/// assert_eq!(trim_sample("a\nb\n"), "a\nb");
+/// assert_eq!(trim_sample("a\nb\n\n"), "a\nb\n");
+/// assert_eq!(trim_sample("a\r\n"), "a");
+/// ```
+pub fn trim_sample(input: &str) -> &str {
+    input.strip_suffix('\n')
+        .map(|input| input.strip_suffix('\r').unwrap_or(input))
+        .unwrap_or(input)
+}
+
+/// Prepares a sample file the way [prepare_input] prepares a real input. Problems that opt out of
+/// normalizing still have a single trailing newline trimmed, see [trim_sample].
+pub fn prepare_sample<P: Problem + ?Sized>(sample: &str) -> std::borrow::Cow<'_, str> {
+    match P::NORMALIZE_INPUT {
+        true => prepare_input::<P>(sample),
+        false => std::borrow::Cow::Borrowed(trim_sample(sample)),
+    }
+}
+
+/// Checks every sample listed in a manifest file against its expected answers, panicking on the first mismatch.
+///
+/// Each non-empty line of the manifest that doesn't start with `#` names a sample file, relative to the
+/// manifest, followed by the expected part 1 and part 2 answers. Answers are compared against the `Debug`
+/// format of each solution, so string answers need quotes. Use `-` to skip a part.
+///
+/// ```text
+/// # file        part 1  part 2
+/// sample_1.txt  405     400
+/// sample_2.txt  -       "abc"
+/// ```
+pub fn check_sample_manifest<P: Problem>(manifest_path: &Path) {
+    let manifest = fs::read_to_string(manifest_path)
+        .unwrap_or_else(|e| panic!("Couldn't read sample manifest {}: {e}", manifest_path.display()));
+    let dir = manifest_path.parent().unwrap_or(Path::new("."));
+    let mut checked = 0;
+    for line in manifest.lines().map(str::trim).filter(|line| !line.is_empty() && !line.starts_with('#')) {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let [file, part_1, part_2] = fields[..] else {
+            panic!("Expected a file and two answers in manifest line {line:?}");
+        };
+        let sample_path = dir.join(file);
+        let sample = fs::read_to_string(&sample_path)
+            .unwrap_or_else(|e| panic!("Couldn't read sample {}: {e}", sample_path.display()));
+        let parsed = P::parse(&prepare_sample::<P>(&sample));
+        check_answer(file, 1, part_1, || P::part_1(&parsed));
+        check_answer(file, 2, part_2, || P::part_2(&parsed));
+        checked += 1;
+    }
+    assert!(checked > 0, "Sample manifest {} lists no samples", manifest_path.display());
+}
+
+fn check_answer<S: Debug>(file: &str, part: u8, expected: &str, solve: impl FnOnce() -> S) {
+    if expected != "-" {
+        assert_eq!(format!("{:?}", solve()), expected, "Wrong part {part} answer for sample {file}");
+    }
+}
+
+/// Loads the real puzzle input and expected answers from `input.txt` and `answers.txt` in `dir`.
+///
+/// `answers.txt` has the part 1 answer on its first line and the part 2 answer on its second.
+/// Returns `None`, printing why, if either file is missing or empty, so a test can be skipped.
+pub fn load_real_input(dir: &Path) -> Option<(String, [String; 2])> {
+    let read = |name: &str| fs::read_to_string(dir.join(name)).ok().filter(|s| !s.trim().is_empty());
+    let Some(input) = read("input.txt") else {
+        println!("Skipping: no input.txt in {}", dir.display());
+        return None;
+    };
+    let Some(answers) = read("answers.txt") else {
+        println!("Skipping: no answers.txt in {}", dir.display());
+        return None;
+    };
+    let mut lines = answers.lines().map(|line| line.trim().to_string());
+    let answers = [lines.next().unwrap_or_default(), lines.next().unwrap_or_default()];
+    Some((input, answers))
+}
+
+/// Parses an expected answer with [FromStr], panicking with the part number if it's invalid.
+pub fn parse_answer<S: FromStr>(answer: &str, part: u8) -> S {
+    match answer.parse() {
+        Ok(answer) => answer,
+        Err(_) => panic!("Couldn't parse part {part} answer {answer:?}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_real_input() {
+        let samples = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/samples"));
+        let (input, answers) = load_real_input(&samples.join("real")).unwrap();
+        assert_eq!(input, "abcdef");
+        assert_eq!(answers, ["6".to_string(), "fedcba".to_string()]);
+        assert_eq!(parse_answer::<usize>(&answers[0], 1), 6);
+
+        assert_eq!(load_real_input(&samples.join("no_answers")), None);
+        assert_eq!(load_real_input(&samples.join("missing")), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Couldn't parse part 2 answer \"x\"")]
+    fn test_parse_answer_invalid() {
+        parse_answer::<u32>("x", 2);
+    }
+}
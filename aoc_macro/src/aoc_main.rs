@@ -0,0 +1,47 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse::{Parse, ParseStream}, spanned::Spanned, Error, Ident, LitStr, Result, Token, Type};
+
+/// `Day17, input = "input.txt", bench`
+pub(crate) struct AocMain {
+    problem: Type,
+    input: Option<LitStr>,
+    bench: bool,
+}
+
+impl Parse for AocMain {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let problem = input.parse()?;
+        let mut main = AocMain { problem, input: None, bench: false };
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            let option: Ident = input.parse()?;
+            if option == "input" && main.input.is_none() {
+                input.parse::<Token![=]>()?;
+                main.input = Some(input.parse()?);
+            } else if option == "bench" && !main.bench {
+                main.bench = true;
+            } else if option == "input" || option == "bench" {
+                return Err(Error::new(option.span(), format!("`{option}` is given more than once")));
+            } else {
+                return Err(Error::new(option.span(), format!("unknown option `{option}`, expected `input = \"...\"` or `bench`")));
+            }
+        }
+        Ok(main)
+    }
+}
+
+pub(crate) fn expand(main: AocMain) -> Result<TokenStream> {
+    let AocMain { problem, input, bench } = main;
+    // Relative to the crate's src/, like the second argument of aoc::main!
+    let input = input.unwrap_or_else(|| LitStr::new("input.txt", problem.span()));
+    // The type keeps its span, so if it's missing or isn't a Problem rustc reports it there
+    Ok(if bench {
+        quote!(::aoc::criterion_day!(#problem, #input);)
+    } else {
+        quote!(::aoc::main!(#problem, #input);)
+    })
+}
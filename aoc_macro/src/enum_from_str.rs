@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Error, Fields, LitStr, Result};
+
+pub(crate) fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let case_insensitive = enum_options(&input)?;
+    let Data::Enum(data) = input.data else {
+        return Err(Error::new(input.ident.span(), "#[derive(EnumFromStr)] expects an enum"));
+    };
+    let name = input.ident;
+    let error_name = format_ident!("Parse{}Error", name);
+    let error_doc = format!("The string isn't one of the tokens of a [{name}].");
+
+    // The tokens claimed so far, keyed lowercased if matching ignores case, to report duplicates
+    let mut claimed: HashMap<String, LitStr> = HashMap::new();
+    let mut tokens = vec![];
+    let mut from_str_arms = vec![];
+    let mut display_arms = vec![];
+    for variant in data.variants.iter() {
+        let ident = &variant.ident;
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new_spanned(&variant.fields, format!("#[derive(EnumFromStr)] variant `{ident}` must be a unit variant")));
+        }
+        let variant_tokens = variant.attrs.iter()
+            .filter(|attr| attr.path().is_ident("token"))
+            .map(|attr| match &attr.meta {
+                syn::Meta::NameValue(syn::MetaNameValue { value: syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(token), .. }), .. }) => Ok(token.clone()),
+                _ => Err(Error::new_spanned(attr, "#[derive(EnumFromStr)] expects attribute #[token = \"...\"]")),
+            })
+            .collect::<Result<Vec<LitStr>>>()?;
+        let Some(first) = variant_tokens.first() else {
+            return Err(Error::new(ident.span(), format!("#[derive(EnumFromStr)] variant `{ident}` needs a #[token = \"...\"] attribute")));
+        };
+        for token in variant_tokens.iter() {
+            let key = if case_insensitive { token.value().to_ascii_lowercase() } else { token.value() };
+            if let Some(first_use) = claimed.insert(key, token.clone()) {
+                let mut err = Error::new(token.span(), format!("#[derive(EnumFromStr)] token {:?} is used by more than one variant", token.value()));
+                err.combine(Error::new(first_use.span(), format!("{:?} is first used here", first_use.value())));
+                return Err(err);
+            }
+        }
+        from_str_arms.push(if case_insensitive {
+            quote!(if #(s.eq_ignore_ascii_case(#variant_tokens))||* { return Ok(Self::#ident); })
+        } else {
+            quote!(#(#variant_tokens)|* => return Ok(Self::#ident),)
+        });
+        display_arms.push(quote!(Self::#ident => #first,));
+        tokens.extend(variant_tokens);
+    }
+    let from_str = if case_insensitive {
+        quote!(#(#from_str_arms)*)
+    } else {
+        quote!(match s { #(#from_str_arms)* _ => (), })
+    };
+    let token_count = tokens.len();
+    let expected = tokens.iter().map(|token| format!("{:?}", token.value())).collect::<Vec<_>>().join(", ");
+
+    Ok(quote!(
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Every token from the `#[token = "..."]` attributes, in declaration order.
+            pub const TOKENS: [&'static str; #token_count] = [#(#tokens),*];
+        }
+
+        #[doc = #error_doc]
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub enum #error_name {
+            /// No variant has this token.
+            UnknownToken(String),
+        }
+
+        impl ::std::fmt::Display for #error_name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                match self {
+                    Self::UnknownToken(s) => write!(f, "Cannot create {} from {:?}, expected one of {}", stringify!(#name), s, #expected),
+                }
+            }
+        }
+
+        impl ::std::error::Error for #error_name {}
+
+        impl #impl_generics ::std::str::FromStr for #name #ty_generics #where_clause {
+            type Err = #error_name;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                #from_str
+                Err(#error_name::UnknownToken(s.to_string()))
+            }
+        }
+
+        impl #impl_generics ::std::fmt::Display for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(match self {
+                    #(#display_arms)*
+                })
+            }
+        }
+    ))
+}
+
+/// Parses the enum's `#[enum_from_str(...)]` options, returning whether matching ignores case.
+fn enum_options(input: &DeriveInput) -> Result<bool> {
+    let mut case_insensitive = false;
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("enum_from_str")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("case_insensitive") {
+                case_insensitive = true;
+                Ok(())
+            } else {
+                Err(meta.error("#[derive(EnumFromStr)] expects #[enum_from_str(case_insensitive)]"))
+            }
+        })?;
+    }
+    Ok(case_insensitive)
+}
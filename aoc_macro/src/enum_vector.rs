@@ -0,0 +1,72 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{punctuated::Punctuated, Attribute, Data, DeriveInput, Error, Expr, Fields, Ident, Result, Token};
+
+pub(crate) fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let Data::Enum(data) = input.data else {
+        return Err(Error::new(input.ident.span(), "#[derive(EnumVector)] expects an enum"));
+    };
+    let name = input.ident;
+    let variant_names: Vec<&Ident> = data.variants.iter().map(|variant| &variant.ident).collect();
+
+    let mut vector_arms = vec![];
+    let mut opposite_arms = vec![];
+    let mut missing_opposite = None;
+    for variant in data.variants.iter() {
+        let ident = &variant.ident;
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new_spanned(&variant.fields, format!("#[derive(EnumVector)] variant `{ident}` must be a unit variant")));
+        }
+        let vector_attr = find_attr(&variant.attrs, "vector")
+            .ok_or_else(|| Error::new(ident.span(), format!("#[derive(EnumVector)] variant `{ident}` needs a #[vector(x, y)] attribute")))?;
+        let components = vector_attr.parse_args_with(Punctuated::<Expr, Token![,]>::parse_terminated)?;
+        let [x, y] = components.iter().collect::<Vec<_>>()[..] else {
+            return Err(Error::new_spanned(vector_attr, "#[derive(EnumVector)] expects two components, #[vector(x, y)]"));
+        };
+        vector_arms.push(quote!(Self::#ident => ::aoc::grid::Vector2D { x: #x, y: #y },));
+
+        match find_attr(&variant.attrs, "opposite") {
+            Some(attr) => {
+                let opposite: Ident = attr.parse_args()?;
+                if !variant_names.contains(&&opposite) {
+                    return Err(Error::new(opposite.span(), format!("#[derive(EnumVector)] `{name}` has no variant `{opposite}`")));
+                }
+                opposite_arms.push(quote!(Self::#ident => Self::#opposite,));
+            },
+            None => missing_opposite = missing_opposite.or(Some(ident)),
+        }
+    }
+    // opposite() is only generated if every variant has one
+    let opposite = match (opposite_arms.is_empty(), missing_opposite) {
+        (true, _) => None,
+        (false, None) => Some(quote!(
+            /// Returns the variant named by this variant's `#[opposite(...)]` attribute.
+            pub fn opposite(&self) -> Self {
+                match self {
+                    #(#opposite_arms)*
+                }
+            }
+        )),
+        (false, Some(ident)) => {
+            return Err(Error::new(ident.span(), format!("#[derive(EnumVector)] variant `{ident}` needs an #[opposite(...)] attribute, since other variants have one")));
+        },
+    };
+
+    Ok(quote!(
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Returns the offset from this variant's `#[vector(x, y)]` attribute.
+            pub fn vector(&self) -> ::aoc::grid::Vector2D {
+                match self {
+                    #(#vector_arms)*
+                }
+            }
+
+            #opposite
+        }
+    ))
+}
+
+fn find_attr<'a>(attrs: &'a [Attribute], name: &str) -> Option<&'a Attribute> {
+    attrs.iter().find(|attr| attr.path().is_ident(name))
+}
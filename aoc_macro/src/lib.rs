@@ -1,7 +1,41 @@
 use proc_macro::TokenStream;
 use syn::{parse_macro_input, DeriveInput};
 
-#[proc_macro_derive(EnumFromChar, attributes(char, init))]
+mod aoc_main;
+mod enum_from_str;
+mod enum_vector;
+mod sample_tests;
+mod test_cases;
+
+/// Derives `TryFrom<char>`, `FromStr` for single character strings, `From<Enum> for char` and
+/// `Display` from `#[char = ...]` attributes.
+///
+/// Every variant needs a character, unless it's marked `#[skip_char]`. Skipped variants can't
+/// be parsed and convert to a space. Characters are `'#'` literals, or paths to `char` consts
+/// in `#[chars(WALL)]`, since attribute values after `=` must be literals.
+///
+/// One `Variant(char)` can be marked `#[fallback]` to hold any character no other variant has.
+/// Conversions then can't fail, so `From<char>` is derived instead of `TryFrom<char>`.
+///
+/// A variant holding one integer can take a range of ASCII characters with
+/// `#[char_range('0'..='9')]`. Digit ranges store the digit's value, other ranges the offset from
+/// their first character, and it's displayed as that character again. These variants aren't in
+/// `VARIANTS`, but their characters are in `CHARS`.
+///
+/// Variants can also have `#[byte = b'#']` attributes, which derive `TryFrom<u8>`. A variant with
+/// only bytes parses from the matching characters too, and if no variant has only characters,
+/// `const fn from_byte(b: u8) -> Option<Self>` is derived as well.
+///
+/// With `#[enum_from_char(roundtrip_test)]` on the enum, a test is generated checking every
+/// character parses to a variant that displays as that character, or as the variant's first
+/// character if it has several.
+///
+/// Failed conversions return a generated `ParseEnumError` (named after the enum, e.g.
+/// `ParseCellError` for `Cell`), either `InvalidChar(char)` or `WrongLength(usize)` for strings
+/// that aren't one character long. Before this the error was a `String`, so `.unwrap()` and `?`
+/// into `anyhow` still work, but code matching on the message should match on the error's
+/// variant, or use its `to_string()`.
+#[proc_macro_derive(EnumFromChar, attributes(char, chars, char_range, byte, init, skip_char, fallback, enum_from_char))]
 pub fn derive_enum_from_char(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     expand::enum_from_char(input)
@@ -9,90 +43,554 @@ pub fn derive_enum_from_char(input: TokenStream) -> TokenStream {
         .into()
 }
 
+/// Derives `FromStr` and `Display` for an enum of unit variants from `#[token = "..."]`
+/// attributes, for words like `"red"` in the input. A variant can have several tokens, and
+/// displays as its first.
+///
+/// `#[enum_from_str(case_insensitive)]` on the enum ignores ASCII case when parsing. Every token
+/// is also listed in `Enum::TOKENS`, and failures return a generated `Parse<Enum>Error`.
+///
+/// # Example
+///
+/// ```
+/// use aoc::EnumFromStr;
+///
+/// #[derive(Debug, PartialEq, EnumFromStr)]
+/// enum Color {
+///     #[token = "red"]
+///     Red,
+///     #[token = "green"]
+///     #[token = "g"]
+///     Green,
+/// }
+///
+/// assert_eq!("g".parse(), Ok(Color::Green));
+/// assert_eq!(Color::Green.to_string(), "green");
+/// assert!("blue".parse::<Color>().is_err());
+/// ```
+#[proc_macro_derive(EnumFromStr, attributes(token, enum_from_str))]
+pub fn derive_enum_from_str(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    enum_from_str::expand(input)
+        .unwrap_or_else(|err| err.into_compile_error())
+        .into()
+}
+
+/// Derives `vector()` for a direction-like enum from `#[vector(x, y)]` attributes on its unit
+/// variants, returning an `aoc::grid::Vector2D`. If variants also have `#[opposite(Variant)]`
+/// attributes, `opposite()` is derived as well.
+#[proc_macro_derive(EnumVector, attributes(vector, opposite))]
+pub fn derive_enum_vector(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    enum_vector::expand(input)
+        .unwrap_or_else(|err| err.into_compile_error())
+        .into()
+}
+
+/// Generates one test per sample for `aoc::test_part_1!` and `aoc::test_part_2!`.
+#[proc_macro]
+pub fn test_cases(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as test_cases::TestCases);
+    test_cases::expand(input)
+        .unwrap_or_else(|err| err.into_compile_error())
+        .into()
+}
+
+/// Generates a day binary's `fn main` with `aoc::main!`, checked at compile time: a type that's
+/// missing or isn't a `Problem` is reported where it's named.
+///
+/// `aoc_main!(Day01)` reads `src/input.txt` at runtime, or the path relative to `src/` given as
+/// `input = "..."`. With `bench`, the criterion harness for `benches/bench.rs` is generated
+/// instead, with `aoc::criterion_day!`.
+#[proc_macro]
+pub fn aoc_main(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as aoc_main::AocMain);
+    aoc_main::expand(input)
+        .unwrap_or_else(|err| err.into_compile_error())
+        .into()
+}
+
+/// Generates tests from the samples in a directory relative to the crate root, like
+/// `sample_tests!(Day16, dir = "samples")`.
+///
+/// Each `<name>.txt` sample is paired with `<name>.answers`, which has the part 1 answer on its
+/// first line and the part 2 answer on its second. A missing or `-` answer skips that part,
+/// otherwise `test_<name>_part_1` and `test_<name>_part_2` are generated. Answers are parsed with
+/// `FromStr`. Any other file in the directory is a compile error, and adding a sample needs a
+/// rebuild of the crate to be picked up.
+#[proc_macro]
+pub fn sample_tests(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as sample_tests::SampleTests);
+    sample_tests::expand(input)
+        .unwrap_or_else(|err| err.into_compile_error())
+        .into()
+}
+
 mod expand {
-    use proc_macro2::TokenStream;
-    use syn::{spanned::Spanned, DataEnum, DeriveInput, Error, Result};
-    use quote::{quote, ToTokens};
+    use std::collections::HashMap;
+
+    use proc_macro2::{Span, TokenStream};
+    use syn::{punctuated::Punctuated, spanned::Spanned, DataEnum, DeriveInput, Error, Expr, Ident, Result, Token, Variant};
+    use quote::{format_ident, quote, ToTokens};
     
     pub(crate) fn enum_from_char(input: DeriveInput) -> Result<TokenStream> {
+        let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+        let roundtrip_test = enum_options(&input)?;
         match input.data {
             syn::Data::Enum(data) => {
                 let name = input.ident;
-                let (into_char_arms, char_display_arms) = expand_match_arms(data)?;
+                let error_name = format_ident!("Parse{}Error", name);
+                let error_doc = format!("The character or string couldn't be converted to a [{name}].");
+                let Arms {
+                    from_char, mut to_char, variants, chars, displayed_chars, from_byte, char_only, byte_only: _, any_skipped, fallback,
+                } = expand_match_arms(&name, data)?;
+                // Without skipped variants the match is already exhaustive
+                if any_skipped {
+                    to_char.push(quote!(_ => ' ',));
+                }
+                let (variant_count, char_count) = (variants.len(), chars.len());
+                // With a fallback every character converts, and TryFrom comes from From
+                let (from_char_impl, from_single_char) = match &fallback {
+                    Some(fallback) => (
+                        quote!(
+                            impl #impl_generics From<char> for #name #ty_generics #where_clause {
+                                fn from(c: char) -> Self {
+                                    match c {
+                                        #(#from_char)*
+                                        c => Self::#fallback(c),
+                                    }
+                                }
+                            }
+                        ),
+                        quote!(Ok(Self::from(c))),
+                    ),
+                    None => (
+                        quote!(
+                            impl #impl_generics TryFrom<char> for #name #ty_generics #where_clause {
+                                type Error = #error_name;
+                                fn try_from(c: char) -> Result<Self, Self::Error> {
+                                    Ok(match c {
+                                        #(#from_char)*
+                                        _ => return Err(#error_name::InvalidChar(c)),
+                                    })
+                                }
+                            }
+                        ),
+                        quote!(Self::try_from(c)),
+                    ),
+                };
                 
+                let byte_impls = (!from_byte.is_empty()).then(|| {
+                    let unmatched = match &fallback {
+                        Some(fallback) => quote!(b => Self::#fallback(b as char)),
+                        None => quote!(_ => return None),
+                    };
+                    let try_from_byte = quote!(
+                        impl #impl_generics TryFrom<u8> for #name #ty_generics #where_clause {
+                            type Error = #error_name;
+                            fn try_from(b: u8) -> Result<Self, Self::Error> {
+                                Self::match_byte(b).ok_or(#error_name::InvalidByte(b))
+                            }
+                        }
+                    );
+                    // Only const if every variant can be made from a byte, and no init uses type parameters
+                    let constness = (char_only.is_none() && input.generics.type_params().next().is_none()).then(|| quote!(const));
+                    let from_byte_fn = constness.is_some().then(|| quote!(
+                        /// Converts an ASCII byte to a variant, in const contexts too.
+                        pub const fn from_byte(b: u8) -> Option<Self> {
+                            Self::match_byte(b)
+                        }
+                    ));
+                    quote!(
+                        impl #impl_generics #name #ty_generics #where_clause {
+                            #from_byte_fn
+
+                            #constness fn match_byte(b: u8) -> Option<Self> {
+                                Some(match b {
+                                    #(#from_byte)*
+                                    #unmatched,
+                                })
+                            }
+                        }
+
+                        #try_from_byte
+                    )
+                });
+
+                // #[init] expressions using type parameters, like T::default(), can't be const
+                let variants_const = input.generics.type_params().next().is_none().then(|| quote!(
+                    /// Every variant in declaration order, except variants with fields and no `#[init]`.
+                    pub const VARIANTS: [Self; #variant_count] = [#(#variants),*];
+                ));
+
+                let roundtrip_test = match roundtrip_test {
+                    Some(_) if input.generics.type_params().next().is_some() => {
+                        return Err(Error::new(name.span(), "#[enum_from_char(roundtrip_test)] isn't supported on generic enums"));
+                    },
+                    Some(_) => {
+                        let module = format_ident!("__enum_from_char_roundtrip_{}", name);
+                        Some(quote!(
+                            #[cfg(test)]
+                            #[allow(non_snake_case)]
+                            mod #module {
+                                // #[chars(...)] can name consts from the enum's module
+                                use super::*;
+
+                                /// Checks each character parses, and displays as itself or its variant's first character.
+                                #[test]
+                                pub fn roundtrip() {
+                                    for (c, displayed) in [#(#displayed_chars),*] {
+                                        let variant = #name::try_from(c)
+                                            .unwrap_or_else(|e| panic!("{:?} should parse to a {}: {:?}", c, stringify!(#name), e));
+                                        assert_eq!(variant.to_string(), displayed.to_string(), "{:?} doesn't display as {:?}", c, displayed);
+                                    }
+                                }
+                            }
+                        ))
+                    },
+                    None => None,
+                };
+
                 Ok(quote!(
-                    impl TryFrom<char> for #name {
-                        type Error = String;
-                        fn try_from(c: char) -> Result<Self, Self::Error> {
-                            match c {
-                                #into_char_arms
-                                _ => Err(format!("Cannot create enum from character '{}'", c)),
+                    #roundtrip_test
+
+                    impl #impl_generics #name #ty_generics #where_clause {
+                        #variants_const
+
+                        /// Every character a variant can be parsed from, in declaration order.
+                        pub const CHARS: [char; #char_count] = [#(#chars),*];
+
+                        /// Iterates over every variant in declaration order, except variants with
+                        /// fields and no `#[init]`.
+                        pub fn variants() -> impl Iterator<Item = Self> {
+                            [#(#variants),*].into_iter()
+                        }
+                    }
+
+                    #[doc = #error_doc]
+                    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+                    pub enum #error_name {
+                        /// No variant has this character.
+                        InvalidChar(char),
+                        /// Parsed from a string with this many characters instead of one.
+                        WrongLength(usize),
+                        /// No variant has this byte.
+                        InvalidByte(u8),
+                    }
+
+                    impl ::std::fmt::Display for #error_name {
+                        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                            match self {
+                                Self::InvalidChar(c) => write!(f, "Cannot create {} from character {:?}", stringify!(#name), c),
+                                Self::WrongLength(len) => write!(f, "Cannot create {} from a string of {} characters, expected 1", stringify!(#name), len),
+                                Self::InvalidByte(b) => write!(f, "Cannot create {} from byte {:?}", stringify!(#name), ::std::ascii::escape_default(*b).to_string()),
+                            }
+                        }
+                    }
+
+                    impl ::std::error::Error for #error_name {}
+
+                    #byte_impls
+
+                    #from_char_impl
+
+                    impl #impl_generics ::std::str::FromStr for #name #ty_generics #where_clause {
+                        type Err = #error_name;
+                        fn from_str(s: &str) -> Result<Self, Self::Err> {
+                            let mut chars = s.chars();
+                            match (chars.next(), chars.next()) {
+                                (Some(c), None) => #from_single_char,
+                                _ => Err(#error_name::WrongLength(s.chars().count())),
                             }
                         }
                     }
 
-                    impl ::std::fmt::Display for #name {
+                    impl #impl_generics From<&#name #ty_generics> for char #where_clause {
+                        fn from(value: &#name #ty_generics) -> char {
+                            match value {
+                                #(#to_char)*
+                            }
+                        }
+                    }
+
+                    impl #impl_generics From<#name #ty_generics> for char #where_clause {
+                        fn from(value: #name #ty_generics) -> char {
+                            char::from(&value)
+                        }
+                    }
+
+                    impl #impl_generics ::std::fmt::Display for #name #ty_generics #where_clause {
                         fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-                            let c = match self {
-                                #char_display_arms
-                                _ => ' ',
-                            };
-                            write!(f, "{}", c)
+                            write!(f, "{}", char::from(self))
                         }
                     }
                 ))
             },
-            _ => Err(Error::new(input.span(), "#[derive(EnumFromChar) expects an enum")),
+            syn::Data::Struct(data) => Err(Error::new(data.struct_token.span, "#[derive(EnumFromChar)] expects an enum")),
+            syn::Data::Union(data) => Err(Error::new(data.union_token.span, "#[derive(EnumFromChar)] expects an enum")),
         }
     }
 
-    fn expand_match_arms(data: DataEnum) -> Result<(TokenStream, TokenStream)> {
-        let mut into_char_arms = vec![];
-        let mut char_display_arms = vec![];
+    /// Parses the enum's `#[enum_from_char(...)]` options, returning the span of `roundtrip_test`
+    /// if it's set.
+    fn enum_options(input: &DeriveInput) -> Result<Option<Span>> {
+        let mut roundtrip_test = None;
+        for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("enum_from_char")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("roundtrip_test") {
+                    roundtrip_test = Some(meta.path.span());
+                    Ok(())
+                } else {
+                    Err(meta.error("#[derive(EnumFromChar)] expects #[enum_from_char(roundtrip_test)]"))
+                }
+            })?;
+        }
+        Ok(roundtrip_test)
+    }
+
+    /// Pieces of the generated impls, collected from the variants in declaration order.
+    #[derive(Default)]
+    struct Arms {
+        /// `'c' => Self::Variant` arms for `TryFrom<char>`
+        from_char: Vec<TokenStream>,
+        /// `Enum::Variant => 'c'` arms for `From<&Enum> for char`
+        to_char: Vec<TokenStream>,
+        /// Constructors for every variant without fields or with an #[init]
+        variants: Vec<TokenStream>,
+        /// Every accepted character
+        chars: Vec<TokenStream>,
+        /// `(c, displayed)` for every accepted character and the character its variant displays as
+        displayed_chars: Vec<TokenStream>,
+        /// `b'c' => Self::Variant` arms for `TryFrom<u8>`
+        from_byte: Vec<TokenStream>,
+        /// A variant with characters but no bytes, which rules out `from_byte`
+        char_only: Option<Ident>,
+        /// A variant with bytes but no characters
+        byte_only: Option<Ident>,
+        any_skipped: bool,
+        /// The `#[fallback]` variant holding unmatched characters
+        fallback: Option<Ident>,
+    }
+
+    /// Checks a `#[fallback]` variant is the only one and holds a single `char`.
+    fn fallback_variant(arms: &Arms, attr: &syn::Attribute, variant: &Variant) -> Result<Ident> {
+        if let Some(first) = &arms.fallback {
+            let mut err = Error::new_spanned(attr, "#[derive(EnumFromChar)] expects at most one #[fallback] variant");
+            err.combine(Error::new(first.span(), format!("`{first}` is already the fallback")));
+            return Err(err);
+        }
+        if let Some(char_attr) = variant.attrs.iter().find(|attr| ["char", "chars", "char_range", "skip_char", "init"].iter().any(|name| attr.path().is_ident(name))) {
+            return Err(Error::new_spanned(char_attr, "#[derive(EnumFromChar)] #[fallback] variants can't also have #[char], #[chars], #[char_range], #[skip_char] or #[init]"));
+        }
+        let is_char = match &variant.fields {
+            syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                matches!(&fields.unnamed[0].ty, syn::Type::Path(ty) if ty.qself.is_none() && ty.path.is_ident("char"))
+            },
+            _ => false,
+        };
+        if !is_char {
+            let span = match &variant.fields {
+                syn::Fields::Unit => variant.ident.span(),
+                fields => fields.span(),
+            };
+            return Err(Error::new(span, format!("#[derive(EnumFromChar)] #[fallback] variant `{}` must hold a single char, like `{}(char)`", variant.ident, variant.ident)));
+        }
+        Ok(variant.ident.clone())
+    }
+
+    /// Errors if one variant has only bytes and another only characters.
+    fn check_bytes_not_mixed(arms: &Arms) -> Result<()> {
+        if let (Some(char_only), Some(byte_only)) = (&arms.char_only, &arms.byte_only) {
+            let mut err = Error::new(byte_only.span(), format!("#[derive(EnumFromChar)] variant `{byte_only}` only has a #[byte], but `{char_only}` only has a #[char]. Give every variant a byte, or none"));
+            err.combine(Error::new(char_only.span(), format!("`{char_only}` has no #[byte]")));
+            return Err(err);
+        }
+        Ok(())
+    }
+
+    /// Checks a `#[char_range('0'..='9')]` is an inclusive ASCII range on a variant with one field,
+    /// returning its first and last characters.
+    fn char_range_variant(attr: &syn::Attribute, variant: &Variant) -> Result<(syn::LitChar, syn::LitChar)> {
+        if let Some(other) = variant.attrs.iter().find(|attr| ["char", "chars", "byte", "skip_char", "init"].iter().any(|name| attr.path().is_ident(name))) {
+            return Err(Error::new_spanned(other, "#[derive(EnumFromChar)] #[char_range] variants can't also have #[char], #[chars], #[byte], #[skip_char] or #[init]"));
+        }
+        if !matches!(&variant.fields, syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1) {
+            let span = match &variant.fields {
+                syn::Fields::Unit => variant.ident.span(),
+                fields => fields.span(),
+            };
+            return Err(Error::new(span, format!("#[derive(EnumFromChar)] #[char_range] variant `{}` must hold a single integer, like `{}(u8)`", variant.ident, variant.ident)));
+        }
+        let (start, end) = attr.parse_args_with(|input: syn::parse::ParseStream| {
+            let start: syn::LitChar = input.parse()?;
+            input.parse::<Token![..=]>()?;
+            Ok((start, input.parse::<syn::LitChar>()?))
+        }).map_err(|_| Error::new_spanned(attr, "#[derive(EnumFromChar)] expects an inclusive range of char literals, like #[char_range('0'..='9')]"))?;
+        let range = quote!(#start..=#end);
+        if !start.value().is_ascii() || !end.value().is_ascii() {
+            return Err(Error::new_spanned(range, "#[derive(EnumFromChar)] #[char_range] must only contain ASCII characters"));
+        }
+        if start.value() > end.value() {
+            return Err(Error::new_spanned(range, "#[derive(EnumFromChar)] #[char_range] is empty, its start is after its end"));
+        }
+        Ok((start, end))
+    }
+
+    fn expand_match_arms(name: &Ident, data: DataEnum) -> Result<Arms> {
+        let mut arms = Arms::default();
+        // Spans of the literal characters claimed so far, to report duplicates
+        let mut claimed: HashMap<char, Span> = HashMap::new();
+        let mut claimed_bytes: HashMap<u8, Span> = HashMap::new();
         for variant in data.variants {
-            // Only parse variants with a "char" attribute
-            if let Some(char_attr) = variant.attrs.iter().find(|attr| attr.path().is_ident("char")) {
-                let variant_span = variant.span();
-                let variant_ident = variant.ident.into_token_stream();
-                let char_expr = match &char_attr.meta {
-                    syn::Meta::NameValue(mnv) => &mnv.value,
-                    _ => return Err(Error::new(variant_span, "#[derive(EnumFromChar) expects attribute #[char = ...]"))
-                };
-                let init_attr = variant.attrs.iter().find(|attr| attr.path().is_ident("init"));
-                match variant.fields {
-                    syn::Fields::Named(_) => {
-                        let init_attr = init_attr
-                            .ok_or(Error::new(variant_span, "#[derive(EnumFromChar) expects variants with named fields to have an attribute #[init { ... }]"))?;
-                        let init_expr = match &init_attr.meta {
-                            syn::Meta::List(list) => &list.tokens,
-                            _ => return Err(Error::new(variant_span, "#[derive(EnumFromChar) expects attribute #[init { ... }]"))
-                        };
-                        into_char_arms.push(quote!(#char_expr => Ok(Self::#variant_ident{#init_expr}),));
-                        char_display_arms.push(quote!(Self::#variant_ident{..} => #char_expr,));
-                    },
-                    syn::Fields::Unnamed(_) => {
-                        let init_attr = init_attr
-                            .ok_or(Error::new(variant_span, "#[derive(EnumFromChar) expects variants with unnamed fields to have an attribute #[init(...)]"))?;
-                        let init_expr = match &init_attr.meta {
-                            syn::Meta::List(list) => &list.tokens,
-                            _ => return Err(Error::new(variant_span, "#[derive(EnumFromChar) expects attribute #[init(...)]"))
-                        };
-                        into_char_arms.push(quote!(#char_expr => Ok(Self::#variant_ident(#init_expr)),));
-                        char_display_arms.push(quote!(Self::#variant_ident(..) => #char_expr,));
-                    },
-                    syn::Fields::Unit => {
-                        if init_attr.is_some() {
-                            return Err(Error::new(variant_span, "#[derive(EnumFromChar) expects unit variants to have no init attribute"));
-                        }
-                        into_char_arms.push(quote!(#char_expr => Ok(Self::#variant_ident),));
-                        char_display_arms.push(quote!(Self::#variant_ident => #char_expr,));
-                    },
+            if let Some(attr) = variant.attrs.iter().find(|attr| attr.path().is_ident("fallback")) {
+                arms.fallback = Some(fallback_variant(&arms, attr, &variant)?);
+                let ident = &variant.ident;
+                arms.to_char.push(quote!(#name::#ident(c) => *c,));
+                continue;
+            }
+            if let Some(attr) = variant.attrs.iter().find(|attr| attr.path().is_ident("char_range")) {
+                let (start, end) = char_range_variant(attr, &variant)?;
+                // Digits hold their value, other ranges the offset from their start
+                let base = if start.value().is_ascii_digit() && end.value().is_ascii_digit() { b'0' } else { start.value() as u8 };
+                let range: Vec<syn::LitChar> = (start.value()..=end.value()).map(|c| syn::LitChar::new(c, start.span())).collect();
+                for c in range.iter() {
+                    if let Some(first) = claimed.insert(c.value(), attr.span()) {
+                        let mut err = Error::new_spanned(attr, format!("#[derive(EnumFromChar)] character {:?} is used by more than one variant", c.value()));
+                        err.combine(Error::new(first, format!("{:?} is first used here", c.value())));
+                        return Err(err);
+                    }
                 }
+                let ident = &variant.ident;
+                arms.from_char.push(quote!(c @ #start..=#end => Self::#ident((c as u8 - #base) as _),));
+                arms.to_char.push(quote!(#name::#ident(value) => (#base + *value as u8) as char,));
+                arms.chars.extend(range.iter().map(|c| c.to_token_stream()));
+                arms.displayed_chars.extend(range.iter().map(|c| quote!((#c, #c))));
+                arms.char_only.get_or_insert(ident.clone());
+                check_bytes_not_mixed(&arms)?;
+                continue;
+            }
+            let bytes = variant_bytes(&variant)?;
+            let mut chars = variant_chars(&variant)?;
+            match (chars.is_empty(), bytes.is_empty()) {
+                // Byte-only variants parse from the same characters
+                (true, false) => {
+                    chars = bytes.iter().map(|b| {
+                        let c = syn::LitChar::new(b.value() as char, b.span());
+                        syn::parse_quote!(#c)
+                    }).collect();
+                    arms.byte_only.get_or_insert(variant.ident.clone());
+                },
+                (false, true) => { arms.char_only.get_or_insert(variant.ident.clone()); },
+                _ => (),
+            }
+            check_bytes_not_mixed(&arms)?;
+            let skip_attr = variant.attrs.iter().find(|attr| attr.path().is_ident("skip_char"));
+            match (chars.is_empty(), skip_attr) {
+                (true, None) => return Err(Error::new(
+                    variant.ident.span(),
+                    format!("#[derive(EnumFromChar)] variant `{}` needs a #[char = ...] attribute, or #[skip_char] to leave it out", variant.ident),
+                )),
+                (false, Some(attr)) => return Err(Error::new_spanned(
+                    attr,
+                    format!("#[derive(EnumFromChar)] variant `{}` can't have both #[skip_char] and a character", variant.ident),
+                )),
+                (true, Some(_)) => arms.any_skipped = true,
+                (false, None) => (),
             }
+            for b in bytes.iter() {
+                if let Some(first) = claimed_bytes.insert(b.value(), b.span()) {
+                    let mut err = Error::new(b.span(), format!("#[derive(EnumFromChar)] byte {:?} is used by more than one variant", b.value() as char));
+                    err.combine(Error::new(first, format!("{:?} is first used here", b.value() as char)));
+                    return Err(err);
+                }
+            }
+            for c in chars.iter() {
+                if let Expr::Lit(syn::ExprLit { lit: syn::Lit::Char(lit), .. }) = c {
+                    if let Some(first) = claimed.insert(lit.value(), lit.span()) {
+                        let mut err = Error::new(lit.span(), format!("#[derive(EnumFromChar)] character {:?} is used by more than one variant", lit.value()));
+                        err.combine(Error::new(first, format!("{:?} is first used here", lit.value())));
+                        return Err(err);
+                    }
+                }
+            }
+            let variant_ident = variant.ident.into_token_stream();
+            let init_expr = match variant.attrs.iter().find(|attr| attr.path().is_ident("init")) {
+                Some(init_attr) => match (&init_attr.meta, &variant.fields) {
+                    (_, syn::Fields::Unit) => return Err(Error::new_spanned(init_attr, "#[derive(EnumFromChar)] expects unit variants to have no init attribute")),
+                    (syn::Meta::List(list), _) => Some(&list.tokens),
+                    (_, syn::Fields::Named(_)) => return Err(Error::new_spanned(init_attr, "#[derive(EnumFromChar)] expects attribute #[init { ... }]")),
+                    (_, syn::Fields::Unnamed(_)) => return Err(Error::new_spanned(init_attr, "#[derive(EnumFromChar)] expects attribute #[init(...)]")),
+                },
+                None => None,
+            };
+            // How to construct the variant, if it has no fields or an #[init] for them
+            let (constructor, pattern) = match (&variant.fields, init_expr) {
+                (syn::Fields::Unit, _) => (Some(quote!(Self::#variant_ident)), quote!(#name::#variant_ident)),
+                (syn::Fields::Named(_), init_expr) => (init_expr.map(|init_expr| quote!(Self::#variant_ident{#init_expr})), quote!(#name::#variant_ident{..})),
+                (syn::Fields::Unnamed(_), init_expr) => (init_expr.map(|init_expr| quote!(Self::#variant_ident(#init_expr))), quote!(#name::#variant_ident(..))),
+            };
+            if let Some(constructor) = &constructor {
+                arms.variants.push(constructor.clone());
+            }
+
+            // Skipped variants have no characters. The first character is used for display
+            if let Some(char_expr) = chars.first() {
+                let constructor = constructor.ok_or_else(|| match &variant.fields {
+                    fields @ syn::Fields::Named(_) => Error::new_spanned(fields, "#[derive(EnumFromChar)] expects variants with named fields to have an attribute #[init { ... }]"),
+                    fields => Error::new_spanned(fields, "#[derive(EnumFromChar)] expects variants with unnamed fields to have an attribute #[init(...)]"),
+                })?;
+                let char_pattern = quote!(#(#chars)|*);
+                arms.from_char.push(quote!(#char_pattern => #constructor,));
+                if !bytes.is_empty() {
+                    arms.from_byte.push(quote!(#(#bytes)|* => #constructor,));
+                }
+                arms.to_char.push(quote!(#pattern => #char_expr,));
+                arms.chars.extend(chars.iter().map(|c| c.to_token_stream()));
+                arms.displayed_chars.extend(chars.iter().map(|c| quote!((#c, #char_expr))));
+            }
+        }
+        Ok(arms)
+    }
+
+    /// Returns the bytes from a variant's `#[byte = ...]` attributes, in order.
+    fn variant_bytes(variant: &Variant) -> Result<Vec<syn::LitByte>> {
+        variant.attrs.iter()
+            .filter(|attr| attr.path().is_ident("byte"))
+            .map(|attr| match &attr.meta {
+                syn::Meta::NameValue(syn::MetaNameValue { value: Expr::Lit(syn::ExprLit { lit: syn::Lit::Byte(b), .. }), .. }) => Ok(b.clone()),
+                _ => Err(Error::new_spanned(attr, "#[derive(EnumFromChar)] expects attribute #[byte = b'...']")),
+            })
+            .collect()
+    }
+
+    /// Returns the characters from a variant's `#[char = ...]` and `#[chars(...)]` attributes, in order.
+    fn variant_chars(variant: &Variant) -> Result<Vec<Expr>> {
+        let mut chars = vec![];
+        for attr in variant.attrs.iter() {
+            if attr.path().is_ident("char") {
+                match &attr.meta {
+                    syn::Meta::NameValue(mnv) => chars.push(char_expr(&mnv.value)?),
+                    _ => return Err(Error::new_spanned(attr, "#[derive(EnumFromChar)] expects attribute #[char = ...]")),
+                }
+            } else if attr.path().is_ident("chars") {
+                let list = attr.parse_args_with(Punctuated::<Expr, Token![,]>::parse_terminated)
+                    .map_err(|_| Error::new_spanned(attr, "#[derive(EnumFromChar)] expects attribute #[chars(..., ...)]"))?;
+                if list.is_empty() {
+                    return Err(Error::new_spanned(attr, "#[derive(EnumFromChar)] expects at least one character in #[chars(...)]"));
+                }
+                for expr in list.iter() {
+                    chars.push(char_expr(expr)?);
+                }
+            }
+        }
+        Ok(chars)
+    }
+
+    /// Checks a character is a `'c'` literal or a path to a `char` const, which can both be matched on.
+    fn char_expr(expr: &Expr) -> Result<Expr> {
+        match expr {
+            Expr::Lit(syn::ExprLit { lit: syn::Lit::Char(_), .. }) | Expr::Path(_) => Ok(expr.clone()),
+            _ => Err(Error::new_spanned(expr, "#[derive(EnumFromChar)] expects a char literal like '#', or the path to a char const")),
         }
-        Ok((
-            quote!(#(#into_char_arms)*),
-            quote!(#(#char_display_arms)*),
-        ))
     }
 }
@@ -0,0 +1,105 @@
+use std::{fs, path::{Path, PathBuf}};
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{parse::{Parse, ParseStream}, Error, Ident, LitStr, Result, Token, Type};
+
+/// `Day16, dir = "samples"`
+pub(crate) struct SampleTests {
+    problem: Type,
+    dir: LitStr,
+}
+
+impl Parse for SampleTests {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let problem = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let option: Ident = input.parse()?;
+        if option != "dir" {
+            return Err(Error::new(option.span(), format!("unknown option `{option}`, expected `dir = \"...\"`")));
+        }
+        input.parse::<Token![=]>()?;
+        let dir = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(SampleTests { problem, dir })
+    }
+}
+
+/// A `<name>.txt` sample and the answers from its `<name>.answers` file
+struct Sample {
+    name: String,
+    input: PathBuf,
+    answers_path: PathBuf,
+    answers: [Option<String>; 2],
+}
+
+pub(crate) fn expand(sample_tests: SampleTests) -> Result<TokenStream> {
+    let SampleTests { problem, dir } = sample_tests;
+    let span = dir.span();
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
+        .map_err(|_| Error::new(span, "sample_tests! needs CARGO_MANIFEST_DIR to find the samples"))?;
+    let samples = read_samples(&Path::new(&manifest_dir).join(dir.value()), span)?;
+
+    let mut tests = vec![];
+    for Sample { name, input, answers_path, answers } in samples {
+        let input = input.to_string_lossy().into_owned();
+        let answers_path = answers_path.to_string_lossy().into_owned();
+        for (part, answer) in (1..=2u8).zip(answers) {
+            let Some(answer) = answer else { continue };
+            let test_name = format_ident!("test_{}_part_{}", name, part);
+            let solve = format_ident!("part_{}", part);
+            let solution = format_ident!("Solution{}", part);
+            tests.push(quote!(
+                #[test]
+                fn #test_name() {
+                    // Rebuilds the tests when the answers change
+                    const _: &str = include_str!(#answers_path);
+                    let input = ::aoc::testing::prepare_sample::<#problem>(include_str!(#input));
+                    let expected: <#problem as ::aoc::Problem>::#solution = ::aoc::testing::parse_answer(#answer, #part);
+                    assert_eq!(<#problem as ::aoc::Problem>::#solve(&<#problem as ::aoc::Problem>::parse(&input)), expected, "sample {}", #input);
+                }
+            ));
+        }
+    }
+    Ok(quote!(#(#tests)*))
+}
+
+/// Pairs every `.txt` file in `dir` with its `.answers` file, sorted by name.
+///
+/// Any other file, or a sample without answers, is an error naming its path.
+fn read_samples(dir: &Path, span: Span) -> Result<Vec<Sample>> {
+    let error = |message: String| Error::new(span, message);
+    let mut paths = fs::read_dir(dir)
+        .and_then(|entries| entries.map(|entry| entry.map(|entry| entry.path())).collect::<std::io::Result<Vec<_>>>())
+        .map_err(|e| error(format!("couldn't read samples directory {}: {e}", dir.display())))?;
+    paths.sort();
+
+    let mut samples = vec![];
+    for path in paths.iter() {
+        let extension = path.extension().and_then(|ext| ext.to_str());
+        let name = path.file_stem().and_then(|stem| stem.to_str()).unwrap_or_default();
+        match extension {
+            Some("txt") => (),
+            Some("answers") if paths.contains(&path.with_extension("txt")) => continue,
+            Some("answers") => return Err(error(format!("{} has no sample, expected a .txt file next to it", path.display()))),
+            _ => return Err(error(format!("unexpected file {}, samples directories only hold .txt samples and .answers files", path.display()))),
+        }
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Err(error(format!("sample names may only contain ASCII letters, digits and underscores: {}", path.display())));
+        }
+        let answers_path = path.with_extension("answers");
+        let answers = fs::read_to_string(&answers_path)
+            .map_err(|e| error(format!("couldn't read sample answers {}: {e}", answers_path.display())))?;
+        // The first line answers part 1 and the second part 2. A missing or `-` answer skips the part
+        let mut lines = answers.lines().map(str::trim);
+        let answers = [(); 2].map(|_| lines.next().filter(|line| !line.is_empty() && *line != "-").map(String::from));
+        if answers.iter().all(Option::is_none) {
+            return Err(error(format!("expected a part 1 or part 2 answer on the first two lines of {}", answers_path.display())));
+        }
+        samples.push(Sample { name: name.to_string(), input: path.clone(), answers_path, answers });
+    }
+    if samples.is_empty() {
+        return Err(error(format!("no .txt samples in {}", dir.display())));
+    }
+    Ok(samples)
+}
@@ -0,0 +1,88 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{ext::IdentExt, parse::{Parse, ParseStream}, Error, Expr, Ident, LitStr, Result, Token, Type};
+
+/// `$crate, part_1, Day01, "label" => SAMPLE_1, 8, SAMPLE_2, 10`, where `$crate` is the `aoc`
+/// crate as seen by the calling `test_part_N!`
+pub(crate) struct TestCases {
+    krate: Ident,
+    part: Ident,
+    problem: Type,
+    cases: Vec<Case>,
+}
+
+struct Case {
+    label: Option<LitStr>,
+    input: Expr,
+    solution: Expr,
+}
+
+impl Parse for TestCases {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let krate = input.call(Ident::parse_any)?;
+        input.parse::<Token![,]>()?;
+        let part = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let problem = input.parse()?;
+        let mut cases = vec![];
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            let label = if input.peek(LitStr) && input.peek2(Token![=>]) {
+                let label = input.parse()?;
+                input.parse::<Token![=>]>()?;
+                Some(label)
+            } else {
+                None
+            };
+            let case_input = input.parse()?;
+            input.parse::<Token![,]>()?;
+            let solution = input.parse()?;
+            cases.push(Case { label, input: case_input, solution });
+        }
+        if cases.is_empty() {
+            return Err(Error::new(Span::call_site(), "expected at least one sample and solution"));
+        }
+        Ok(TestCases { krate, part, problem, cases })
+    }
+}
+
+pub(crate) fn expand(test_cases: TestCases) -> Result<TokenStream> {
+    let TestCases { krate, part, problem, cases } = test_cases;
+    let part_2_check = (part == "part_2").then(|| quote!(
+        assert!(#problem::has_part_2(), "{} doesn't implement part 2", stringify!(#problem));
+    ));
+
+    // A single unlabeled sample keeps the plain `test_part_N` name
+    let single = cases.len() == 1 && cases[0].label.is_none();
+    let mut names: Vec<Ident> = vec![];
+    let mut tests = vec![];
+    for (i, Case { label, input, solution }) in cases.into_iter().enumerate() {
+        let name = match &label {
+            _ if single => format_ident!("test_{}", part),
+            Some(label) => {
+                let suffix = label.value();
+                if suffix.is_empty() || !suffix.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                    return Err(Error::new(label.span(), "test labels may only contain ASCII letters, digits and underscores"));
+                }
+                format_ident!("test_{}_{}", part, suffix)
+            },
+            None => format_ident!("test_{}_case_{}", part, i + 1),
+        };
+        if names.contains(&name) {
+            let span = label.map_or(Span::call_site(), |label| label.span());
+            return Err(Error::new(span, format!("duplicate test name `{name}`")));
+        }
+        tests.push(quote!(
+            #[test]
+            fn #name() {
+                #part_2_check
+                assert_eq!(#problem::#part(&#problem::parse(&#krate::prepare_input::<#problem>(#input))), #solution, "in {}", stringify!(#name));
+            }
+        ));
+        names.push(name);
+    }
+    Ok(quote!(#(#tests)*))
+}
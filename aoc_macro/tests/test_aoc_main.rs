@@ -0,0 +1,43 @@
+use aoc::Problem;
+
+struct Numbers;
+
+impl Problem for Numbers {
+    type Parsed = Vec<u32>;
+    type Solution1 = u32;
+    type Solution2 = u32;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input.split(' ').map(|n| n.parse().unwrap()).collect()
+    }
+
+    fn part_1(parsed: &Self::Parsed) -> Self::Solution1 {
+        parsed.iter().sum()
+    }
+
+    fn part_2(parsed: &Self::Parsed) -> Self::Solution2 {
+        parsed.iter().product()
+    }
+}
+
+// The generated functions are local to each test, so they're only checked to exist with the
+// right signatures rather than run, which would read the test binary's arguments as an input
+
+#[test]
+fn test_aoc_main_expands_to_main() {
+    aoc::aoc_main!(Numbers, input = "../tests/samples/sample1.txt");
+    let _: fn() = main;
+}
+
+#[test]
+fn test_aoc_main_defaults_to_input_txt() {
+    aoc::aoc_main!(Numbers,);
+    let _: fn() = main;
+}
+
+#[test]
+fn test_aoc_main_bench() {
+    aoc::aoc_main!(Numbers, bench, input = "../tests/samples/sample1.txt");
+    let _: fn() = main;
+    let _: fn(&mut aoc::criterion::Criterion) = criterion_day;
+}
@@ -18,5 +18,278 @@ fn test_enum_from_char() {
     assert_eq!('F'.try_into(), Ok(FooBar::Foo));
     assert_eq!('B'.try_into(), Ok(FooBar::Bar(10)));
     assert_eq!('Z'.try_into(), Ok(FooBar::Baz{foo: 8, bar: 10}));
-    assert_eq!(format!("{} {} {}", FooBar::Foo, FooBar::Bar(0), FooBar::Baz { foo: 1, bar: 2 }), "F B Z")
-}
\ No newline at end of file
+    assert_eq!(format!("{} {} {}", FooBar::Foo, FooBar::Bar(0), FooBar::Baz { foo: 1, bar: 2 }), "F B Z");
+    assert_eq!(char::from(&FooBar::Bar(10)), 'B');
+    assert_eq!(char::from(&FooBar::Baz { foo: 0, bar: 0 }), 'Z');
+    assert_eq!(char::from(FooBar::Foo), 'F');
+
+    assert_eq!(FooBar::VARIANTS.len(), 3);
+    assert_eq!(FooBar::VARIANTS, [FooBar::Foo, FooBar::Bar(10), FooBar::Baz { foo: 8, bar: 10 }]);
+    assert_eq!(FooBar::CHARS, ['F', 'B', 'Z']);
+    assert_eq!(FooBar::variants().map(char::from).collect::<Vec<_>>(), FooBar::CHARS);
+    assert_eq!(FooBar::variants().last(), Some(FooBar::Baz { foo: 8, bar: 10 }));
+
+    assert_eq!("F".parse(), Ok(FooBar::Foo));
+    assert_eq!("B".parse(), Ok(FooBar::Bar(10)));
+    assert_eq!("".parse::<FooBar>(), Err(ParseFooBarError::WrongLength(0)));
+    assert_eq!("FB".parse::<FooBar>(), Err(ParseFooBarError::WrongLength(2)));
+    assert_eq!("Q".parse::<FooBar>(), Err(ParseFooBarError::InvalidChar('Q')));
+    assert_eq!(
+        "FB".parse::<FooBar>().unwrap_err().to_string(),
+        "Cannot create FooBar from a string of 2 characters, expected 1",
+    );
+}
+
+#[test]
+fn test_enum_from_char_error() {
+
+    #[derive(Debug, PartialEq, Eq, EnumFromChar)]
+    enum Cell {
+        #[char = '.']
+        Empty,
+    }
+
+    let err = Cell::try_from('?').unwrap_err();
+    assert_eq!(err, ParseCellError::InvalidChar('?'));
+    assert_eq!(err.to_string(), "Cannot create Cell from character '?'");
+    // Usable as a boxed error, e.g. with ?
+    let err: Box<dyn std::error::Error> = Box::new(err);
+    assert_eq!(err.to_string(), "Cannot create Cell from character '?'");
+}
+
+#[test]
+fn test_enum_into_char() {
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, EnumFromChar)]
+    enum Tile {
+        #[char = '.']
+        Empty,
+        #[char = '#']
+        Wall,
+        #[skip_char]
+        Unmapped,
+    }
+
+    let row = [Tile::Wall, Tile::Empty, Tile::Wall];
+    assert_eq!(row.iter().map(char::from).collect::<String>(), "#.#");
+    assert_eq!(row.into_iter().map(char::from).collect::<String>(), "#.#");
+    // Skipped unit variants are still listed
+    assert_eq!(Tile::VARIANTS, [Tile::Empty, Tile::Wall, Tile::Unmapped]);
+    assert_eq!(Tile::CHARS, ['.', '#']);
+    // Skipped variants convert to a space, as they display
+    assert_eq!(char::from(Tile::Unmapped), ' ');
+    assert_eq!(Tile::Unmapped.to_string(), " ");
+}
+#[test]
+fn test_enum_from_multiple_chars() {
+
+    #[derive(Debug, PartialEq, Eq, EnumFromChar)]
+    enum Step {
+        #[char = '.']
+        #[char = 'S']
+        Garden,
+        #[chars('^', 'U', '3')]
+        Up,
+        #[char = '#']
+        #[chars('X', 'x')]
+        #[init(true)]
+        Rock(bool),
+    }
+
+    assert_eq!('.'.try_into(), Ok(Step::Garden));
+    assert_eq!('S'.try_into(), Ok(Step::Garden));
+    assert_eq!('^'.try_into(), Ok(Step::Up));
+    assert_eq!('U'.try_into(), Ok(Step::Up));
+    assert_eq!('3'.try_into(), Ok(Step::Up));
+    assert_eq!('x'.try_into(), Ok(Step::Rock(true)));
+    assert_eq!(Step::try_from('D'), Err(ParseStepError::InvalidChar('D')));
+    // Display uses the first character
+    assert_eq!(format!("{}{}{}", Step::Garden, Step::Up, Step::Rock(false)), ".^#");
+    // Every accepted character is listed, and each variant once
+    assert_eq!(Step::CHARS, ['.', 'S', '^', 'U', '3', '#', 'X', 'x']);
+    assert_eq!(Step::VARIANTS, [Step::Garden, Step::Up, Step::Rock(true)]);
+}
+
+#[test]
+fn test_enum_from_char_fallback() {
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, EnumFromChar)]
+    enum Schematic {
+        #[char = '.']
+        Empty,
+        #[chars('0', '1', '2', '3', '4', '5', '6', '7', '8', '9')]
+        Digit,
+        #[fallback]
+        Symbol(char),
+    }
+
+    assert_eq!(Schematic::from('.'), Schematic::Empty);
+    assert_eq!(Schematic::from('7'), Schematic::Digit);
+    assert_eq!(Schematic::from('*'), Schematic::Symbol('*'));
+    // Still usable where TryFrom<char> is expected, and can't fail
+    fn parse_all<T: TryFrom<char>>(s: &str) -> Option<Vec<T>> {
+        s.chars().map(|c| T::try_from(c).ok()).collect()
+    }
+    assert_eq!(parse_all("#."), Some(vec![Schematic::Symbol('#'), Schematic::Empty]));
+    assert_eq!("$".parse(), Ok(Schematic::Symbol('$')));
+    assert_eq!("".parse::<Schematic>(), Err(ParseSchematicError::WrongLength(0)));
+
+    // The captured character is displayed
+    let line: String = "4.*#".chars().map(Schematic::from).map(char::from).collect();
+    assert_eq!(line, "0.*#");
+    assert_eq!(Schematic::Symbol('+').to_string(), "+");
+    assert_eq!(Schematic::VARIANTS, [Schematic::Empty, Schematic::Digit]);
+}
+
+#[test]
+fn test_generic_enum_from_char() {
+
+    #[derive(Debug, PartialEq, Eq, EnumFromChar)]
+    enum Wrapper<T: Default> {
+        #[char = 'x']
+        #[init(T::default())]
+        X(T),
+        #[char = 'e']
+        Empty,
+    }
+
+    assert_eq!(Wrapper::<u8>::try_from('x'), Ok(Wrapper::X(0)));
+    assert_eq!("x".parse(), Ok(Wrapper::X(String::new())));
+    assert_eq!(Wrapper::<u8>::try_from('?'), Err(ParseWrapperError::InvalidChar('?')));
+    assert_eq!(Wrapper::X(5u8).to_string(), "x");
+    assert_eq!(char::from(Wrapper::<u8>::Empty), 'e');
+    assert_eq!(Wrapper::<u8>::variants().collect::<Vec<_>>(), vec![Wrapper::X(0), Wrapper::Empty]);
+    assert_eq!(Wrapper::<u8>::CHARS, ['x', 'e']);
+
+    #[derive(Debug, PartialEq, Eq, EnumFromChar)]
+    enum Tagged<T>
+    where
+        T: Clone + From<u8>,
+    {
+        #[char = 't']
+        #[init(T::from(1))]
+        Tag(T),
+    }
+
+    assert_eq!(Tagged::<u32>::try_from('t'), Ok(Tagged::Tag(1)));
+}
+
+#[test]
+fn test_enum_from_char_range() {
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, EnumFromChar)]
+    enum Cell {
+        #[char = '.']
+        Empty,
+        #[char_range('0'..='9')]
+        Height(u8),
+        #[char_range('a'..='f')]
+        Gate(usize),
+    }
+
+    assert_eq!(Cell::try_from('0'), Ok(Cell::Height(0)));
+    assert_eq!(Cell::try_from('9'), Ok(Cell::Height(9)));
+    assert_eq!(Cell::try_from('c'), Ok(Cell::Gate(2)));
+    assert_eq!(Cell::try_from('g'), Err(ParseCellError::InvalidChar('g')));
+    assert_eq!(Cell::Height(7).to_string(), "7");
+    assert_eq!(char::from(Cell::Gate(5)), 'f');
+    let row: String = "3.a9".chars().map(|c| Cell::try_from(c).unwrap()).map(char::from).collect();
+    assert_eq!(row, "3.a9");
+    assert_eq!(Cell::VARIANTS, [Cell::Empty]);
+    assert_eq!(Cell::CHARS.len(), 17);
+
+    // Ranges that don't start at '0' still hold the digit's value
+    #[derive(Debug, PartialEq, Eq, EnumFromChar)]
+    enum Weight {
+        #[char_range('1'..='9')]
+        Digit(u32),
+    }
+
+    assert_eq!("5".parse(), Ok(Weight::Digit(5)));
+    assert_eq!(Weight::Digit(1).to_string(), "1");
+}
+
+#[test]
+fn test_enum_from_byte() {
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, EnumFromChar)]
+    enum Rock {
+        #[byte = b'O']
+        Round,
+        #[byte = b'#']
+        Cube,
+        #[byte = b'.']
+        #[byte = b'_']
+        Empty,
+    }
+
+    let row: Result<Vec<Rock>, _> = b"O#._".iter().map(|&b| Rock::try_from(b)).collect();
+    assert_eq!(row, Ok(vec![Rock::Round, Rock::Cube, Rock::Empty, Rock::Empty]));
+    assert_eq!(Rock::try_from(b'x'), Err(ParseRockError::InvalidByte(b'x')));
+    // Byte-only variants convert from the same characters
+    assert_eq!(Rock::try_from('#'), Ok(Rock::Cube));
+    assert_eq!(Rock::Empty.to_string(), ".");
+
+    const ROUND: Option<Rock> = Rock::from_byte(b'O');
+    assert_eq!(ROUND, Some(Rock::Round));
+    assert_eq!(Rock::from_byte(b'?'), None);
+
+    // Variants can have both, with the bytes and chars differing
+    #[derive(Debug, PartialEq, Eq, EnumFromChar)]
+    enum Light {
+        #[char = '█']
+        #[byte = b'#']
+        On,
+        #[char = ' ']
+        #[byte = b'.']
+        Off,
+        #[fallback]
+        Other(char),
+    }
+
+    assert_eq!(Light::try_from(b'#'), Ok(Light::On));
+    assert_eq!(Light::from('█'), Light::On);
+    assert_eq!(Light::try_from(b'@'), Ok(Light::Other('@')));
+    assert_eq!(Light::from_byte(b'.'), Some(Light::Off));
+}
+
+#[derive(Debug, PartialEq, Eq, EnumFromChar)]
+#[enum_from_char(roundtrip_test)]
+enum Pipe {
+    #[char = '|']
+    Vertical,
+    #[chars('-', '_')]
+    Horizontal,
+    #[skip_char]
+    Unknown,
+}
+
+#[derive(Debug, PartialEq, Eq, EnumFromChar)]
+#[enum_from_char(roundtrip_test)]
+enum Symbol {
+    #[char = '.']
+    Empty,
+    #[fallback]
+    Other(char),
+}
+
+const ROCK: char = 'O';
+
+#[derive(Debug, PartialEq, Eq, EnumFromChar)]
+#[enum_from_char(roundtrip_test)]
+enum Platform {
+    #[chars(ROCK)]
+    Rock,
+    #[char = '.']
+    Empty,
+}
+
+#[test]
+fn test_generated_roundtrip_tests() {
+    // Each enum gets its own module, and the generated tests pass
+    __enum_from_char_roundtrip_Pipe::roundtrip();
+    __enum_from_char_roundtrip_Symbol::roundtrip();
+    __enum_from_char_roundtrip_Platform::roundtrip();
+    assert_eq!(Pipe::Unknown.to_string(), " ");
+    assert_eq!(Symbol::from('#'), Symbol::Other('#'));
+}
@@ -0,0 +1,60 @@
+use aoc_macro::EnumFromStr;
+
+#[test]
+fn test_enum_from_str() {
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, EnumFromStr)]
+    enum Color {
+        #[token = "red"]
+        Red,
+        #[token = "green"]
+        Green,
+        #[token = "blue"]
+        #[token = "b"]
+        Blue,
+    }
+
+    assert_eq!("red".parse(), Ok(Color::Red));
+    assert_eq!("green".parse(), Ok(Color::Green));
+    assert_eq!("b".parse(), Ok(Color::Blue));
+    assert_eq!("Red".parse::<Color>(), Err(ParseColorError::UnknownToken("Red".to_string())));
+    assert_eq!("".parse::<Color>(), Err(ParseColorError::UnknownToken(String::new())));
+    assert_eq!(Color::Blue.to_string(), "blue");
+    assert_eq!(Color::TOKENS, ["red", "green", "blue", "b"]);
+}
+
+#[test]
+fn test_enum_from_str_error() {
+
+    #[derive(Debug, PartialEq, EnumFromStr)]
+    enum Workflow {
+        #[token = "A"]
+        Accept,
+        #[token = "R"]
+        Reject,
+    }
+
+    let err = "in".parse::<Workflow>().unwrap_err();
+    assert_eq!(err.to_string(), r#"Cannot create Workflow from "in", expected one of "A", "R""#);
+    let err: Box<dyn std::error::Error> = Box::new(err);
+    assert!(err.to_string().starts_with("Cannot create Workflow"));
+}
+
+#[test]
+fn test_enum_from_str_case_insensitive() {
+
+    #[derive(Debug, PartialEq, EnumFromStr)]
+    #[enum_from_str(case_insensitive)]
+    enum Hand {
+        #[token = "Rock"]
+        Rock,
+        #[token = "Paper"]
+        Paper,
+    }
+
+    assert_eq!("rock".parse(), Ok(Hand::Rock));
+    assert_eq!("PAPER".parse(), Ok(Hand::Paper));
+    assert!("scissors".parse::<Hand>().is_err());
+    // Still displayed as written
+    assert_eq!(Hand::Paper.to_string(), "Paper");
+}
@@ -0,0 +1,47 @@
+use aoc::grid::Vector2D;
+use aoc_macro::EnumVector;
+
+#[test]
+fn test_enum_vector() {
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, EnumVector)]
+    enum Dir {
+        #[vector(0, -1)]
+        #[opposite(Down)]
+        Up,
+        #[vector(0, 1)]
+        #[opposite(Up)]
+        Down,
+        #[vector(-1, 0)]
+        #[opposite(Right)]
+        Left,
+        #[vector(1, 0)]
+        #[opposite(Left)]
+        Right,
+    }
+
+    assert_eq!(Dir::Up.vector(), Vector2D { x: 0, y: -1 });
+    assert_eq!(Dir::Down.vector(), Vector2D { x: 0, y: 1 });
+    assert_eq!(Dir::Left.vector(), Vector2D { x: -1, y: 0 });
+    assert_eq!(Dir::Right.vector(), Vector2D { x: 1, y: 0 });
+
+    for dir in [Dir::Up, Dir::Down, Dir::Left, Dir::Right] {
+        assert_eq!(dir.opposite().opposite(), dir);
+        assert_eq!(dir.vector() + dir.opposite().vector(), Vector2D { x: 0, y: 0 });
+    }
+}
+
+#[test]
+fn test_enum_vector_without_opposites() {
+
+    #[derive(EnumVector)]
+    enum Diagonal {
+        #[vector(1, 1)]
+        DownRight,
+        #[vector(-1, -1)]
+        UpLeft,
+    }
+
+    assert_eq!(Diagonal::DownRight.vector(), Vector2D { x: 1, y: 1 });
+    assert_eq!(Diagonal::UpLeft.vector(), Vector2D { x: -1, y: -1 });
+}
@@ -0,0 +1,30 @@
+use aoc::Problem;
+
+struct Numbers;
+
+impl Problem for Numbers {
+    type Parsed = Vec<u32>;
+    type Solution1 = u32;
+    type Solution2 = u32;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input.split(' ').map(|n| n.parse().unwrap()).collect()
+    }
+
+    fn part_1(parsed: &Self::Parsed) -> Self::Solution1 {
+        parsed.iter().sum()
+    }
+
+    fn part_2(parsed: &Self::Parsed) -> Self::Solution2 {
+        parsed.iter().product()
+    }
+}
+
+aoc::sample_tests!(Numbers, dir = "tests/samples");
+
+#[test]
+fn test_skipped_parts_have_no_tests() {
+    // Only these are generated: sample2.answers has no part 2 answer, and zero.answers skips part 1 with `-`
+    let tests: [fn(); 4] = [test_sample1_part_1, test_sample1_part_2, test_sample2_part_1, test_zero_part_2];
+    tests.iter().for_each(|test| test());
+}
@@ -0,0 +1,23 @@
+struct NotAProblem;
+
+mod missing {
+    aoc::aoc_main!(Day99, input = "input.txt");
+}
+
+mod not_a_problem {
+    aoc::aoc_main!(super::NotAProblem);
+}
+
+mod not_a_problem_bench {
+    aoc::aoc_main!(super::NotAProblem, bench);
+}
+
+mod unknown_option {
+    aoc::aoc_main!(super::NotAProblem, inputs = "input.txt");
+}
+
+mod repeated_option {
+    aoc::aoc_main!(super::NotAProblem, bench, bench);
+}
+
+fn main() {}
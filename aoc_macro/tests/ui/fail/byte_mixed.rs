@@ -0,0 +1,26 @@
+use aoc_macro::EnumFromChar;
+
+#[derive(EnumFromChar)]
+enum Mixed {
+    #[char = '.']
+    Empty,
+    #[byte = b'#']
+    Wall,
+}
+
+#[derive(EnumFromChar)]
+enum DuplicateByte {
+    #[byte = b'#']
+    Wall,
+    #[char = '.']
+    #[byte = b'#']
+    Empty,
+}
+
+#[derive(EnumFromChar)]
+enum NotAByte {
+    #[byte = '#']
+    Wall,
+}
+
+fn main() {}
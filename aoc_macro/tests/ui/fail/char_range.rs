@@ -0,0 +1,35 @@
+use aoc_macro::EnumFromChar;
+
+#[derive(EnumFromChar)]
+enum NotAscii {
+    #[char_range('α'..='ω')]
+    Greek(u8),
+}
+
+#[derive(EnumFromChar)]
+enum Exclusive {
+    #[char_range('0'..'9')]
+    Digit(u8),
+}
+
+#[derive(EnumFromChar)]
+enum Backwards {
+    #[char_range('9'..='0')]
+    Digit(u8),
+}
+
+#[derive(EnumFromChar)]
+enum Unit {
+    #[char_range('0'..='9')]
+    Digit,
+}
+
+#[derive(EnumFromChar)]
+enum Overlapping {
+    #[char = '5']
+    Five,
+    #[char_range('0'..='9')]
+    Digit(u8),
+}
+
+fn main() {}
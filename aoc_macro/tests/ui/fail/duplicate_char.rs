@@ -0,0 +1,12 @@
+use aoc_macro::EnumFromChar;
+
+#[derive(EnumFromChar)]
+enum Tile {
+    #[chars('.', 'S')]
+    Garden,
+    #[char = '#']
+    #[char = 'S']
+    Rock,
+}
+
+fn main() {}
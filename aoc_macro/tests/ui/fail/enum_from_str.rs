@@ -0,0 +1,40 @@
+use aoc_macro::EnumFromStr;
+
+#[derive(EnumFromStr)]
+enum Duplicate {
+    #[token = "red"]
+    Red,
+    #[token = "rouge"]
+    #[token = "red"]
+    Crimson,
+}
+
+#[derive(EnumFromStr)]
+#[enum_from_str(case_insensitive)]
+enum DuplicateIgnoringCase {
+    #[token = "red"]
+    Red,
+    #[token = "RED"]
+    Crimson,
+}
+
+#[derive(EnumFromStr)]
+enum Missing {
+    #[token = "red"]
+    Red,
+    Green,
+}
+
+#[derive(EnumFromStr)]
+enum NotUnit {
+    #[token = "red"]
+    Red(u32),
+}
+
+#[derive(EnumFromStr)]
+enum NotAString {
+    #[token = 'r']
+    Red,
+}
+
+fn main() {}
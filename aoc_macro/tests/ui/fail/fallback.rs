@@ -0,0 +1,38 @@
+use aoc_macro::EnumFromChar;
+
+#[derive(EnumFromChar)]
+enum TwoFallbacks {
+    #[char = '.']
+    Empty,
+    #[fallback]
+    Symbol(char),
+    #[fallback]
+    Other(char),
+}
+
+#[derive(EnumFromChar)]
+enum NotAChar {
+    #[char = '.']
+    Empty,
+    #[fallback]
+    Symbol(u8),
+}
+
+#[derive(EnumFromChar)]
+enum UnitFallback {
+    #[char = '.']
+    Empty,
+    #[fallback]
+    Symbol,
+}
+
+#[derive(EnumFromChar)]
+enum FallbackWithChar {
+    #[char = '.']
+    Empty,
+    #[fallback]
+    #[char = '#']
+    Symbol(char),
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use aoc_macro::EnumFromChar;
+
+#[derive(EnumFromChar)]
+enum Tile {
+    #[char = 'S']
+    Start(usize),
+}
+
+#[derive(EnumFromChar)]
+enum Named {
+    #[char = 'S']
+    Start { steps: usize },
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use aoc_macro::EnumFromChar;
+
+#[derive(EnumFromChar)]
+enum Tile {
+    #[char = '.']
+    #[init(0)]
+    Empty,
+}
+
+fn main() {}
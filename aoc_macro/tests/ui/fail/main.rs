@@ -0,0 +1,11 @@
+struct NotAProblem;
+
+mod missing {
+    aoc::main!(Day99, "input.txt");
+}
+
+mod not_a_problem {
+    aoc::main!(super::NotAProblem);
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use aoc_macro::EnumFromChar;
+
+#[derive(EnumFromChar)]
+enum Tile {
+    #[char = '.']
+    Garden,
+    Marker,
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use aoc_macro::EnumFromChar;
+
+#[derive(EnumFromChar)]
+enum Text {
+    #[char = "#"]
+    Wall,
+}
+
+#[derive(EnumFromChar)]
+enum Number {
+    #[chars('.', 1)]
+    Empty,
+}
+
+#[derive(EnumFromChar)]
+enum Call {
+    #[chars(char::from(b'#'))]
+    Wall,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use aoc_macro::EnumFromChar;
+
+#[derive(EnumFromChar)]
+struct Tile {
+    c: char,
+}
+
+fn main() {}
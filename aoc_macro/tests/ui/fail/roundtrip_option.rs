@@ -0,0 +1,10 @@
+use aoc_macro::EnumFromChar;
+
+#[derive(EnumFromChar)]
+#[enum_from_char(round_trip)]
+enum Tile {
+    #[char = '.']
+    Garden,
+}
+
+fn main() {}
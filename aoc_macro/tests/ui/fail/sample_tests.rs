@@ -0,0 +1,7 @@
+struct Day01;
+
+aoc::sample_tests!(Day01, dir = "no_such_samples");
+
+aoc::sample_tests!(Day01, samples = "samples");
+
+fn main() {}
@@ -0,0 +1,10 @@
+use aoc_macro::EnumFromChar;
+
+#[derive(EnumFromChar)]
+enum Tile {
+    #[char = '.']
+    #[skip_char]
+    Garden,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use aoc_macro::EnumVector;
+
+#[derive(EnumVector)]
+enum Dir {
+    #[vector(0, -1)]
+    #[opposite(Down)]
+    Up,
+    #[opposite(Up)]
+    Down,
+}
+
+fn main() {}
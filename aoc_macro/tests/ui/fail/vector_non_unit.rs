@@ -0,0 +1,11 @@
+use aoc_macro::EnumVector;
+
+#[derive(EnumVector)]
+enum Dir {
+    #[vector(0, -1)]
+    Up,
+    #[vector(0, 1)]
+    Down(u8),
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use aoc_macro::EnumVector;
+
+#[derive(EnumVector)]
+enum Dir {
+    #[vector(0, -1)]
+    #[opposite(Down)]
+    Up,
+    #[vector(0, 1)]
+    Down,
+}
+
+#[derive(EnumVector)]
+enum Turn {
+    #[vector(0, -1)]
+    #[opposite(Right)]
+    Left,
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+use aoc::Problem;
+
+struct Count;
+
+impl Problem for Count {
+    type Parsed = usize;
+    type Solution1 = usize;
+    type Solution2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input.len()
+    }
+
+    fn part_1(parsed: &Self::Parsed) -> Self::Solution1 {
+        *parsed
+    }
+
+    fn part_2(parsed: &Self::Parsed) -> Self::Solution2 {
+        *parsed
+    }
+}
+
+// There's no input next to the generated crate, so the benchmarks are skipped when this runs
+aoc::aoc_main!(Count, bench);
@@ -0,0 +1,22 @@
+use aoc_macro::EnumFromChar;
+
+const WALL: char = '#';
+
+mod symbols {
+    pub const START: char = 'S';
+}
+
+#[derive(Debug, PartialEq, Eq, EnumFromChar)]
+enum Tile {
+    #[chars(WALL)]
+    Wall,
+    #[chars('.', symbols::START)]
+    Open,
+}
+
+fn main() {
+    assert_eq!(Tile::try_from('#'), Ok(Tile::Wall));
+    assert_eq!(Tile::try_from('S'), Ok(Tile::Open));
+    assert_eq!(Tile::Open.to_string(), ".");
+    assert_eq!(Tile::CHARS, ['#', '.', 'S']);
+}
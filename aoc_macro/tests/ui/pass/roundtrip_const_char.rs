@@ -0,0 +1,17 @@
+use aoc_macro::EnumFromChar;
+
+const WALL: char = '#';
+
+#[derive(Debug, PartialEq, Eq, EnumFromChar)]
+#[enum_from_char(roundtrip_test)]
+enum Tile {
+    #[chars(WALL)]
+    Wall,
+    #[char = '.']
+    Open,
+}
+
+fn main() {
+    assert_eq!(Tile::try_from(WALL), Ok(Tile::Wall));
+    assert_eq!(Tile::Open.to_string(), ".");
+}
@@ -0,0 +1,14 @@
+use aoc_macro::EnumFromChar;
+
+#[derive(Debug, PartialEq, EnumFromChar)]
+enum Tile {
+    #[char = '.']
+    Garden,
+    #[skip_char]
+    Marker,
+}
+
+fn main() {
+    assert_eq!(Tile::try_from('.'), Ok(Tile::Garden));
+    assert_eq!(Tile::Marker.to_string(), " ");
+}
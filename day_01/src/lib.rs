@@ -0,0 +1,298 @@
+use aoc::*;
+
+/// An error returned for a line with no digits, holding the line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalibrationError(pub String);
+
+impl std::fmt::Display for CalibrationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "No digits in line {:?}", self.0)
+    }
+}
+
+impl std::error::Error for CalibrationError {}
+
+pub struct Day01;
+impl Problem for Day01 {
+    type Solution1 = u32;
+    type Solution2 = u32;
+    type Parsed = String;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input.to_string()
+    }
+
+    fn part_1(input: &Self::Parsed) -> Self::Solution1 {
+        sum_calibration_values(input, calibration_value).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    fn part_2(input: &Self::Parsed) -> Self::Solution2 {
+        solve_with_words(input, &DigitWords::standard()).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    fn try_part_1(input: &Self::Parsed) -> anyhow::Result<Self::Solution1> {
+        Ok(sum_calibration_values(input, calibration_value)?)
+    }
+
+    fn try_part_2(input: &Self::Parsed) -> anyhow::Result<Self::Solution2> {
+        Ok(solve_with_words(input, &DigitWords::standard())?)
+    }
+}
+
+/// Solves part 2 with another table of spelled out digits, e.g. one that includes "zero".
+///
+/// # Example
+///
+/// ```
+/// # use day_01::{solve_with_words, DigitWords};
+/// let words = DigitWords::new([("zero", 0), ("one", 1)]);
+/// assert_eq!(solve_with_words("zero5one\n2two", &words), Ok(1 + 22));
+/// ```
+pub fn solve_with_words(input: &str, words: &DigitWords) -> Result<u32, CalibrationError> {
+    sum_calibration_values(input, |line| spelled_calibration_value(line, words))
+}
+
+/// Sums the calibration value of every line, skipping blank lines.
+fn sum_calibration_values(input: &str, value: impl Fn(&str) -> Result<u32, CalibrationError>) -> Result<u32, CalibrationError> {
+    input.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(value)
+        .sum()
+}
+
+/// Combines the first and last ASCII digits of a line into a two digit number.
+fn calibration_value(line: &str) -> Result<u32, CalibrationError> {
+    let (first, last) = first_last(line, None).ok_or_else(|| CalibrationError(line.to_string()))?;
+    Ok(first * 10 + last)
+}
+
+/// Like [calibration_value], but digits can also be spelled out with any of `words`.
+fn spelled_calibration_value(line: &str, words: &DigitWords) -> Result<u32, CalibrationError> {
+    let (first, last) = first_last(line, Some(words)).ok_or_else(|| CalibrationError(line.to_string()))?;
+    Ok(first * 10 + last)
+}
+
+const DIGIT_WORDS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+/// The words part 2 reads as digits, alongside ASCII digits, with the value of each.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DigitWords {
+    words: Vec<(String, u32)>,
+}
+
+impl DigitWords {
+    /// Creates a table from `(word, value)` pairs. Empty words are ignored.
+    pub fn new<S: Into<String>>(words: impl IntoIterator<Item = (S, u32)>) -> Self {
+        let words = words.into_iter()
+            .map(|(word, value)| (word.into(), value))
+            .filter(|(word, _)| !word.is_empty())
+            .collect();
+        DigitWords { words }
+    }
+
+    /// The puzzle's words, "one" to "nine".
+    pub fn standard() -> Self {
+        Self::new(DIGIT_WORDS.into_iter().zip(1..))
+    }
+
+    /// Returns the digit at the start of `bytes`, either as an ASCII digit or a word. If several
+    /// words match, like "six" and "sixteen", the longest wins.
+    fn digit_at(&self, bytes: &[u8]) -> Option<u32> {
+        match bytes.first()? {
+            b @ b'0'..=b'9' => Some((b - b'0') as u32),
+            _ => self.words.iter()
+                .filter(|(word, _)| bytes.starts_with(word.as_bytes()))
+                .max_by_key(|(word, _)| word.len())
+                .map(|&(_, value)| value),
+        }
+    }
+}
+
+impl Default for DigitWords {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+/// Returns the first and last digits in the line, without allocating. Its bytes are scanned
+/// forward until the first digit, then backward from the end until the last.
+///
+/// With `words`, spelled out digits count too. Every position is checked, so overlapping words
+/// like "twone" give both digits.
+fn first_last(line: &str, words: Option<&DigitWords>) -> Option<(u32, u32)> {
+    let bytes = line.as_bytes();
+    let mut digits = (0..bytes.len()).filter_map(|i| match words {
+        Some(words) => words.digit_at(&bytes[i..]),
+        None => bytes[i].is_ascii_digit().then(|| (bytes[i] - b'0') as u32),
+    });
+    let first = digits.next()?;
+    Some((first, digits.next_back().unwrap_or(first)))
+}
+
+/// The digits part 2 found in a line, for finding mis-parsed lines, see [explain].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineReport {
+    pub line: String,
+    /// The byte offset and value of every digit or word, including overlapping words.
+    pub digits: Vec<(usize, u32)>,
+    /// The calibration value, or `None` if the line has no digits.
+    pub value: Option<u32>,
+}
+
+impl std::fmt::Display for LineReport {
+    /// Formats the report as one row, e.g. `24 | xtwone3four | 1:2 3:1 6:3 7:4`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.value {
+            Some(value) => write!(f, "{value:>3} | {} |", self.line)?,
+            None => write!(f, "  - | {} |", self.line)?,
+        }
+        for (offset, digit) in self.digits.iter() {
+            write!(f, " {offset}:{digit}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Reports every digit part 2 finds on each line of the input, with the calibration value.
+///
+/// # Example
+///
+/// ```
+/// # use day_01::explain;
+/// for report in explain("two1nine\nabc") {
+///     println!("{report}");
+/// }
+/// ```
+pub fn explain(input: &str) -> Vec<LineReport> {
+    let words = DigitWords::standard();
+    input.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let bytes = line.as_bytes();
+            let digits: Vec<(usize, u32)> = (0..bytes.len())
+                .filter_map(|i| Some((i, words.digit_at(&bytes[i..])?)))
+                .collect();
+            let value = digits.first().zip(digits.last()).map(|((_, first), (_, last))| first * 10 + last);
+            LineReport { line: line.to_string(), digits, value }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_PART_1: &str = "\
+        1abc2\n\
+        pqr3stu8vwx\n\
+        a1b2c3d4e5f\n\
+        treb7uchet";
+
+    const SAMPLE_PART_2: &str = "\
+        two1nine\n\
+        eightwothree\n\
+        abcone2threexyz\n\
+        xtwone3four\n\
+        4nineeightseven2\n\
+        zoneight234\n\
+        7pqrstsixteen";
+
+    test_part_1!(Day01, SAMPLE_PART_1, 142);
+    test_part_2!(Day01, SAMPLE_PART_2, 281);
+
+    #[test]
+    fn test_first_last() {
+        let words = DigitWords::standard();
+        assert_eq!(first_last("twone", Some(&words)), Some((2, 1)));
+        assert_eq!(first_last("eightwo", Some(&words)), Some((8, 2)));
+        assert_eq!(first_last("7pqrstsixteen", Some(&words)), Some((7, 6)));
+        assert_eq!(first_last("é3ü", Some(&words)), Some((3, 3)));
+        assert_eq!(first_last("abc", Some(&words)), None);
+        assert_eq!(first_last("abc", None), None);
+        // A single digit is both the first and the last
+        assert_eq!(first_last("treb7uchet", None), Some((7, 7)));
+        assert_eq!(first_last("xsevenx", Some(&words)), Some((7, 7)));
+        // The last digit can overlap the word before it
+        assert_eq!(first_last("3oneight", Some(&words)), Some((3, 8)));
+        assert_eq!(first_last("3oneight", None), Some((3, 3)));
+        assert_eq!(first_last("pqr3stu8vwx", None), Some((3, 8)));
+    }
+
+    #[test]
+    fn test_blank_lines() {
+        let input = format!("\n{}\n\n", SAMPLE_PART_1.replace('\n', "\n  \n"));
+        assert_eq!(Day01::try_part_1(&input).unwrap(), 142);
+        assert_eq!(Day01::try_part_2(&format!("{SAMPLE_PART_2}\n")).unwrap(), 281);
+        assert_eq!(Day01::solve(&input).0, 142);
+    }
+
+    #[test]
+    fn test_line_without_digits() {
+        let input = format!("{SAMPLE_PART_1}\nabc\n");
+        let err = Day01::try_part_1(&input).unwrap_err();
+        assert_eq!(err.downcast_ref(), Some(&CalibrationError("abc".to_string())));
+        assert_eq!(err.to_string(), "No digits in line \"abc\"");
+        // Spelled out digits count in part 2
+        assert_eq!(Day01::try_part_2(&"xoney".to_string()).unwrap(), 11);
+        assert!(Day01::try_part_2(&"onetwo\nzero".to_string()).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "No digits in line \"treb\"")]
+    fn test_part_1_panics_on_line_without_digits() {
+        Day01::part_1(&"1abc2\ntreb".to_string());
+    }
+
+    #[test]
+    fn test_try_run_line_without_digits() {
+        let err = Day01::try_run_with("1abc2\nabc", &RunConfig::default()).unwrap_err();
+        assert_eq!(err.to_string(), "No digits in line \"abc\"");
+        // Part 2 alone fails too, "abc" has no spelled out digits either
+        assert!(Day01::try_run_with("1abc2\nabc", &RunConfig { parts: Parts::Part2 }).is_err());
+        assert!(Day01::try_run_with("1abc2\nxoney", &RunConfig { parts: Parts::Part2 }).is_ok());
+    }
+
+    #[test]
+    fn test_solve_with_words() {
+        assert_eq!(solve_with_words(SAMPLE_PART_2, &DigitWords::standard()), Ok(281));
+        assert_eq!(DigitWords::default(), DigitWords::standard());
+
+        let words = DigitWords::new(DIGIT_WORDS.into_iter().zip(1..).chain([("zero", 0)]));
+        assert_eq!(solve_with_words("zero5\nxzerone", &words), Ok(5 + 1));
+        assert_eq!(solve_with_words("zero", &DigitWords::standard()), Err(CalibrationError("zero".to_string())));
+    }
+
+    #[test]
+    fn test_words_sharing_prefixes() {
+        let words = DigitWords::new([("six", 6), ("sixteen", 16), ("teen", 10), ("een", 3)]);
+        // The longest word wins at each position, and later overlapping words still count
+        assert_eq!(first_last("sixteen", Some(&words)), Some((16, 3)));
+        assert_eq!(first_last("xsix", Some(&words)), Some((6, 6)));
+        assert_eq!(first_last("sixtee", Some(&words)), Some((6, 6)));
+        assert_eq!(DigitWords::new([("", 1)]), DigitWords::new(Vec::<(String, u32)>::new()));
+    }
+
+    #[test]
+    fn test_explain() {
+        let reports = explain("xtwone3four\n\nabc");
+        assert_eq!(reports[0], LineReport {
+            line: "xtwone3four".to_string(),
+            digits: vec![(1, 2), (3, 1), (6, 3), (7, 4)],
+            value: Some(24),
+        });
+        assert_eq!(reports[0].to_string(), " 24 | xtwone3four | 1:2 3:1 6:3 7:4");
+        assert_eq!(reports[1].value, None);
+        assert_eq!(reports[1].to_string(), "  - | abc |");
+        // The values add up to the part 2 answer
+        let total: u32 = explain(SAMPLE_PART_2).iter().filter_map(|report| report.value).sum();
+        assert_eq!(total, 281);
+    }
+
+    #[test]
+    fn test_first_last_long_line() {
+        // Quadratic scanning would take minutes on a line this long
+        let line = format!("two{}nine", "xé".repeat(500_000));
+        let words = DigitWords::standard();
+        assert_eq!(first_last(&line, Some(&words)), Some((2, 9)));
+    }
+}
\ No newline at end of file
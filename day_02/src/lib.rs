@@ -0,0 +1,319 @@
+mod parse;
+
+pub use parse::{games, parse_games, GameParseError};
+
+use std::{collections::BTreeMap, fmt::Display};
+
+use aoc::*;
+#[cfg(any(test, feature = "random"))]
+use aoc::stress::Rng;
+
+/// Counts of cubes by color, for any color names. Missing colors count as 0.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ColorCounts(BTreeMap<String, u32>);
+
+impl ColorCounts {
+    pub fn get(&self, color: &str) -> u32 {
+        self.0.get(color).copied().unwrap_or(0)
+    }
+
+    /// Sets the count of `color`, returning its previous count if it had one.
+    pub fn insert(&mut self, color: impl Into<String>, count: u32) -> Option<u32> {
+        self.0.insert(color.into(), count)
+    }
+
+    /// Returns the colors and their counts, in alphabetical order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.0.iter().map(|(color, &count)| (color.as_str(), count))
+    }
+
+    /// Returns the larger count of each color in either set.
+    pub fn max(&self, other: &ColorCounts) -> ColorCounts {
+        let mut res = self.clone();
+        for (color, count) in other.iter() {
+            let max = res.0.entry(color.to_string()).or_default();
+            *max = (*max).max(count);
+        }
+        res
+    }
+}
+
+impl Display for ColorCounts {
+    /// Formats the counts like a round in the input, e.g. `3 blue, 4 red`, in alphabetical order.
+    /// Colors counted as 0 are kept, so the text parses back to the same counts.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let counts: Vec<String> = self.iter()
+            .map(|(color, count)| format!("{count} {color}"))
+            .collect();
+        write!(f, "{}", counts.join(", "))
+    }
+}
+
+impl<S: Into<String>> FromIterator<(S, u32)> for ColorCounts {
+    fn from_iter<I: IntoIterator<Item = (S, u32)>>(iter: I) -> Self {
+        ColorCounts(iter.into_iter().map(|(color, count)| (color.into(), count)).collect())
+    }
+}
+
+/// The red, green and blue counts of a [ColorCounts].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CubeSet {
+    red: u32,
+    green: u32,
+    blue: u32
+}
+
+impl CubeSet {
+    pub const fn new(red: u32, green: u32, blue: u32) -> Self {
+        CubeSet { red, green, blue }
+    }
+
+    pub fn red(&self) -> u32 {
+        self.red
+    }
+
+    pub fn green(&self) -> u32 {
+        self.green
+    }
+
+    pub fn blue(&self) -> u32 {
+        self.blue
+    }
+
+    /// The count of `color`, which is 0 for colors other than red, green and blue.
+    pub fn get(&self, color: &str) -> u32 {
+        match color {
+            "red" => self.red,
+            "green" => self.green,
+            "blue" => self.blue,
+            _ => 0,
+        }
+    }
+}
+
+impl Display for CubeSet {
+    /// Formats the set like a round in the input, e.g. `4 red, 3 blue`, leaving out colors with
+    /// no cubes.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let counts: Vec<String> = [("red", self.red), ("green", self.green), ("blue", self.blue)].into_iter()
+            .filter(|&(_, count)| count > 0)
+            .map(|(color, count)| format!("{count} {color}"))
+            .collect();
+        write!(f, "{}", counts.join(", "))
+    }
+}
+
+impl From<&ColorCounts> for CubeSet {
+    fn from(counts: &ColorCounts) -> Self {
+        CubeSet::new(counts.get("red"), counts.get("green"), counts.get("blue"))
+    }
+}
+
+/// The bag's contents in part 1.
+pub const BAG: CubeSet = CubeSet::new(12, 13, 14);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    id: u32,
+    rounds: Vec<ColorCounts>,
+}
+
+impl Game {
+    /// Whether every round could have been drawn from a bag holding `limit`. A bag of red, green
+    /// and blue cubes can't produce any other color.
+    pub fn is_possible(&self, limit: &CubeSet) -> bool {
+        self.min_cubes_required().iter().all(|(color, count)| count <= limit.get(color))
+    }
+
+    /// Generates a game with up to six rounds, each drawing between one and twenty cubes of some
+    /// colors. Mostly red, green and blue, but sometimes yellow.
+    ///
+    /// Needs the `random` feature outside of this crate's tests.
+    #[cfg(any(test, feature = "random"))]
+    pub fn random(rng: &mut impl Rng) -> Game {
+        const COLORS: [&str; 4] = ["red", "green", "blue", "yellow"];
+        let rounds = (0..rng.gen_range(1..=6))
+            .map(|_| {
+                let colors = if rng.gen_bool(0.1) { &COLORS[..] } else { &COLORS[..3] };
+                let mut round = ColorCounts::default();
+                for &color in colors {
+                    if rng.gen_bool(0.5) {
+                        round.insert(color, rng.gen_range(1..=20));
+                    }
+                }
+                if round.iter().next().is_none() {
+                    round.insert("red", rng.gen_range(1..=20));
+                }
+                round
+            })
+            .collect();
+        Game { id: rng.gen_range(1..=100), rounds }
+    }
+
+    /// The product of the fewest red, green and blue cubes the game could be played with.
+    pub fn power(&self) -> u32 {
+        let min_cubes = CubeSet::from(&self.min_cubes_required());
+        min_cubes.red() * min_cubes.green() * min_cubes.blue()
+    }
+
+    /// Returns the most cubes of each color drawn in any round.
+    pub fn min_cubes_required(&self) -> ColorCounts {
+        self.rounds.iter().fold(ColorCounts::default(), |acc, round| acc.max(round))
+    }
+}
+
+impl Display for Game {
+    /// Formats the game like a line of the input, e.g. `Game 1: 3 blue, 4 red; 2 green`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Game {}: ", self.id)?;
+        for (i, round) in self.rounds.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{round}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the ids of the games that could have been played with a bag holding `limit`.
+pub fn possible_games<'a>(games: &'a [Game], limit: &'a CubeSet) -> impl Iterator<Item = u32> + 'a {
+    games.iter()
+        .filter(|game| game.is_possible(limit))
+        .map(|game| game.id)
+}
+
+/// Part 1 straight from the input with [games], without collecting the games first. Stops at the
+/// first game that doesn't parse.
+pub fn sum_possible_ids(input: &str, limit: &CubeSet) -> Result<u32, GameParseError> {
+    games(input).try_fold(0, |sum, game| {
+        let game = game?;
+        Ok(if game.is_possible(limit) { sum + game.id } else { sum })
+    })
+}
+
+/// Part 2 straight from the input, like [sum_possible_ids].
+pub fn sum_powers(input: &str) -> Result<u32, GameParseError> {
+    games(input).map(|game| game.map(|game| game.power())).sum()
+}
+
+pub struct Day02;
+impl Problem for Day02 {
+    type Solution1 = u32;
+    type Solution2 = u32;
+    type Parsed = Vec<Game>;
+
+    fn parse(input: &str) -> Self::Parsed {
+        Self::try_parse(input).unwrap_or_else(|e| panic!("{e:#}"))
+    }
+
+    fn try_parse(input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(parse::parse_games(input)?)
+    }
+
+    fn part_1(games: &Self::Parsed) -> Self::Solution1 {
+        possible_games(games, &BAG).sum()
+    }
+
+    fn part_2(games: &Self::Parsed) -> Self::Solution2 {
+        games.iter().map(Game::power).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*; 
+
+    const SAMPLE: &str = "\
+        Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\n\
+        Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue\n\
+        Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red\n\
+        Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red\n\
+        Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    test_part_1!(Day02, SAMPLE, 8);
+    test_part_2!(Day02, SAMPLE, 2286);
+
+    #[test]
+    fn test_possible_games() {
+        let games = Day02::parse(SAMPLE);
+        assert_eq!(possible_games(&games, &BAG).collect::<Vec<_>>(), vec![1, 2, 5]);
+        // Game 1 needs 6 blue cubes
+        assert_eq!(possible_games(&games, &CubeSet::new(12, 13, 5)).sum::<u32>(), 7);
+        assert_eq!(possible_games(&games, &CubeSet::new(0, 0, 0)).count(), 0);
+        assert!(games[2].is_possible(&CubeSet::new(20, 13, 6)));
+        assert!(!games[2].is_possible(&CubeSet::new(19, 13, 6)));
+    }
+
+    #[test]
+    fn test_other_colors() {
+        let games = Day02::parse("Game 1: 3 yellow, 2 red; 5 yellow\nGame 2: 1 blue, 2 green, 3 red");
+        let min_cubes = games[0].min_cubes_required();
+        assert_eq!(min_cubes, ColorCounts::from_iter([("red", 2), ("yellow", 5)]));
+        assert_eq!(min_cubes.iter().collect::<Vec<_>>(), vec![("red", 2), ("yellow", 5)]);
+        assert_eq!(CubeSet::from(&min_cubes), CubeSet::new(2, 0, 0));
+        // The part 1 bag has no yellow cubes
+        assert!(!games[0].is_possible(&BAG));
+        assert_eq!(Day02::part_1(&games), 2);
+        assert_eq!(Day02::part_2(&games), 6);
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let games = Day02::parse(SAMPLE);
+        // Colors are listed alphabetically, so the text can differ from the input
+        assert_eq!(games[0].to_string(), "Game 1: 3 blue, 4 red; 6 blue, 2 green, 1 red; 2 green");
+        let formatted: Vec<String> = games.iter().map(Game::to_string).collect();
+        assert_eq!(parse_games(&formatted.join("\n")).unwrap(), games);
+
+        // Colors counted as 0 are kept
+        let games = Day02::parse("Game 1: 0 red, 2 blue");
+        assert_eq!(games[0].to_string(), "Game 1: 2 blue, 0 red");
+        assert_eq!(parse_games(&games[0].to_string()).unwrap(), games);
+
+        assert_eq!(CubeSet::new(4, 0, 3).to_string(), "4 red, 3 blue");
+        assert_eq!(ColorCounts::from_iter([("red", 0), ("yellow", 2)]).to_string(), "0 red, 2 yellow");
+    }
+
+    #[test]
+    fn test_parse_random_games() {
+        let gen = |rng: &mut aoc::stress::StdRng| Game::random(rng);
+        let parse_formatted = |game: &Game| parse_games(&game.to_string()).ok();
+        aoc::stress::check(gen, parse_formatted, |game| Some(vec![game.clone()]), 500);
+    }
+
+    #[test]
+    fn test_games_iterator() {
+        let ids: Vec<u32> = games(SAMPLE).map(|game| game.unwrap().id).collect();
+        assert_eq!(ids, vec![1, 2, 3, 4, 5]);
+
+        let broken = SAMPLE.replace("Game 3: 8 green", "Game 3: 8 gr3en");
+        let results: Vec<_> = games(&broken).collect();
+        assert_eq!(results.len(), 5);
+        assert!(results[..2].iter().all(Result::is_ok));
+        assert!(matches!(results[2], Err(GameParseError::Syntax(aoc::parse::LineError { line: 3, .. }))));
+        // Lines after the error are still parsed
+        assert_eq!(results[4].as_ref().unwrap().id, 5);
+        assert_eq!(games(&broken).position(|game| game.is_err()), Some(2));
+
+        let games = Day02::try_parse(SAMPLE).unwrap();
+        assert_eq!(Day02::try_part_1(&games).unwrap(), 8);
+        assert_eq!(Day02::try_part_2(&games).unwrap(), 2286);
+        // Both parts from the iterator match the collected games' answers
+        assert_eq!(sum_possible_ids(SAMPLE, &BAG), Ok(8));
+        assert_eq!(sum_powers(SAMPLE), Ok(2286));
+        let errors = (sum_possible_ids(&broken, &BAG).unwrap_err(), sum_powers(&broken).unwrap_err());
+        assert!(matches!(errors, (GameParseError::Syntax(ref a), GameParseError::Syntax(ref b)) if a.line == 3 && b.line == 3));
+    }
+
+    #[test]
+    fn test_broken_input() {
+        let broken = SAMPLE.replace("Game 3: 8 green", "Game 3: 8 gr3en");
+        let err = Day02::try_run(&broken).unwrap_err();
+        let message = format!("{err:#}");
+        assert!(message.starts_with("Couldn't parse game: Line 3, column 13: unexpected \"3en"), "{message}");
+
+        let err = Day02::try_parse("Game x: 1 red").unwrap_err();
+        assert_eq!(format!("{err:#}"), "Couldn't parse game: Line 1, column 6: expected Digit at \"x: 1 red\"");
+    }
+}
@@ -1,56 +1,129 @@
-use nom::{branch::alt, bytes::complete::tag, combinator::map, multi::separated_list0, sequence::terminated, IResult};
-
-use crate::{CubeSet, Game};
-
-enum CubeCount {
-    Red(u32),
-    Green(u32),
-    Blue(u32),
-}
-
-fn parse_cube_set(i: &str) -> IResult<&str, CubeSet> {
-    map(
-        separated_list0(
-            tag(", "),
-            alt((
-                map(terminated(nom::character::complete::u32, tag(" red")), CubeCount::Red),
-                map(terminated(nom::character::complete::u32, tag(" blue")), CubeCount::Blue),
-                map(terminated(nom::character::complete::u32, tag(" green")), CubeCount::Green),
-            )),
-        ),
-        |entries| {
-            let mut res = CubeSet { red: 0, green: 0, blue: 0 };
-            for count in entries {
-                match count {
-                    CubeCount::Red(n) => res.red += n,
-                    CubeCount::Green(n) => res.green += n,
-                    CubeCount::Blue(n) => res.blue += n,
-                }
-            }
-            res
+use std::fmt::Display;
+
+use aoc::parse::{parse_line, LineError};
+use nom::{bytes::complete::tag, character::complete::alpha1, multi::separated_list1, sequence::separated_pair, IResult};
+
+use crate::{ColorCounts, Game};
+
+/// An error parsing the games, with the 1-based number of the line it's on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameParseError {
+    /// The line isn't a game, or has text after it.
+    Syntax(LineError),
+    /// A round counts the same color twice.
+    DuplicateColor { line: usize, text: String, color: String },
+}
+
+impl Display for GameParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Syntax(e) => write!(f, "Couldn't parse game: {e}"),
+            Self::DuplicateColor { line, text, color } => write!(f, "Couldn't parse line {line}: {text:?}, {color} appears twice in one round"),
+        }
+    }
+}
+
+impl std::error::Error for GameParseError {}
+
+/// Parses every line of the input as a game.
+pub fn parse_games(input: &str) -> Result<Vec<Game>, GameParseError> {
+    games(input).collect()
+}
+
+/// Lazily parses each line of the input as a game, so callers can stop at the first error.
+pub fn games(input: &str) -> impl Iterator<Item = Result<Game, GameParseError>> + '_ {
+    input.lines()
+        .enumerate()
+        .map(|(n, line)| parse_game(line, n + 1))
+}
+
+/// Parses a game, which must take up the whole line.
+fn parse_game(text: &str, line: usize) -> Result<Game, GameParseError> {
+    let (id, rounds) = parse_line(text, line, &mut game).map_err(GameParseError::Syntax)?;
+    let rounds = rounds.into_iter()
+        .map(cube_set)
+        .collect::<Result<_, _>>()
+        .map_err(|color| GameParseError::DuplicateColor { line, text: text.to_string(), color })?;
+    Ok(Game { id, rounds })
+}
+
+/// Collects a round's counts, or returns the first color that's counted twice.
+fn cube_set(counts: Counts) -> Result<ColorCounts, String> {
+    let mut res = ColorCounts::default();
+    for (n, color) in counts {
+        if res.insert(color, n).is_some() {
+            return Err(color.to_string());
         }
+    }
+    Ok(res)
+}
+
+/// The counts in a round, before checking for repeated colors.
+type Counts<'a> = Vec<(u32, &'a str)>;
+
+//  3 blue, 4 red
+fn round(i: &str) -> IResult<&str, Counts<'_>> {
+    separated_list1(
+        tag(", "),
+        separated_pair(nom::character::complete::u32, tag(" "), alpha1),
     )(i)
 }
 
 //  Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
-pub fn parse_game(i: &str) -> IResult<&str, Game> {
+fn game(i: &str) -> IResult<&str, (u32, Vec<Counts<'_>>)> {
     let (i, _) = tag("Game ")(i)?;
     let (i, id) = nom::character::complete::u32(i)?;
     let (i, _) = tag(": ")(i)?;
-    let (i, rounds) = separated_list0(tag("; "), parse_cube_set)(i)?;
+    let (i, rounds) = separated_list1(tag("; "), round)(i)?;
 
-    Ok((i, Game { id, rounds }))
+    Ok((i, (id, rounds)))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn cubes(line: &str) -> Vec<ColorCounts> {
+        parse_game(line, 1).unwrap().rounds
+    }
+
+    #[test]
+    fn test_parse_game() {
+        assert_eq!(cubes("Game 1: 4 red"), vec![ColorCounts::from_iter([("red", 4)])]);
+        assert_eq!(cubes("Game 1: 4 green, 2 blue"), vec![ColorCounts::from_iter([("green", 4), ("blue", 2)])]);
+        assert_eq!(cubes("Game 1: 12 blue, 8 red; 1 green, 2 red, 3 blue"), vec![
+            ColorCounts::from_iter([("blue", 12), ("red", 8)]),
+            ColorCounts::from_iter([("green", 1), ("red", 2), ("blue", 3)]),
+        ]);
+        assert_eq!(cubes("Game 1: 3 yellow, 1 red"), vec![ColorCounts::from_iter([("yellow", 3), ("red", 1)])]);
+        assert_eq!(parse_game("Game 7: 1 red", 1).unwrap().id, 7);
+    }
+
+    #[test]
+    fn test_trailing_garbage() {
+        let err = parse_games("Game 1: 3 blue\nGame 2: 3 blue; 1 red!").unwrap_err();
+        let GameParseError::Syntax(line_error) = &err else { panic!("{err:?}") };
+        assert_eq!((line_error.line, line_error.column), (2, 22));
+        assert_eq!(err.to_string(), r#"Couldn't parse game: Line 2, column 22: unexpected "!""#);
+        assert!(parse_games("Game 1: 3 blue;").is_err());
+        assert!(parse_games("Game 1: 3 blue ").is_err());
+    }
+
     #[test]
-    fn test_parse_cube_set() {
-        assert_eq!(parse_cube_set("4 red"), Ok(("", CubeSet { red: 4, green: 0, blue: 0 })));
-        assert_eq!(parse_cube_set("4 green, 2 blue"), Ok(("", CubeSet { red: 0, green: 4, blue: 2 })));
-        assert_eq!(parse_cube_set("12 blue, 8 red"), Ok(("", CubeSet { red: 8, green: 0, blue: 12 })));
-        assert_eq!(parse_cube_set("1 green, 2 red, 3 blue"), Ok(("", CubeSet { red: 2, green: 1, blue: 3 })));
+    fn test_empty_rounds() {
+        let err = parse_games("Game 1: ").unwrap_err();
+        assert_eq!(err.to_string(), r#"Couldn't parse game: Line 1, column 9: expected Digit at """#);
+        assert!(parse_games("Game 1: 3 blue; ; 2 red").is_err());
+        assert!(parse_games("Game 1: ; ; ").is_err());
+        assert!(parse_games("Game 1: 3 blue\nGame 2:").is_err());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_duplicate_color() {
+        let err = parse_games("Game 1: 3 blue, 3 blue").unwrap_err();
+        assert_eq!(err, GameParseError::DuplicateColor { line: 1, text: "Game 1: 3 blue, 3 blue".to_string(), color: "blue".to_string() });
+        assert!(err.to_string().ends_with("blue appears twice in one round"), "{err}");
+        // Colors can repeat across rounds
+        assert!(parse_games("Game 1: 3 blue; 3 blue").is_ok());
+    }
+}
@@ -0,0 +1,216 @@
+mod parse;
+
+pub use parse::{parse_schematic, SchematicParseError};
+
+use aoc::*;
+use aoc::grid::*;
+
+/// A number in the schematic, spanning `len` digits of `row` from `start_col`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Number {
+    pub value: u32,
+    pub row: usize,
+    pub start_col: usize,
+    pub len: usize,
+}
+
+impl Number {
+    /// Returns whether `point` is next to one of the number's digits, including diagonally.
+    pub fn is_adjacent_to(&self, point: Point) -> bool {
+        point.y + 1 >= self.row && point.y <= self.row + 1
+            && point.x + 1 >= self.start_col && point.x <= self.start_col + self.len
+    }
+
+    /// Returns whether any symbol of the schematic is next to the number, making it a part number.
+    pub fn is_adjacent_to_symbol(&self, schematic: &Schematic) -> bool {
+        schematic.symbols.iter().any(|&(point, _)| self.is_adjacent_to(point))
+    }
+}
+
+/// The numbers and symbols of an engine schematic, in reading order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Schematic {
+    pub numbers: Vec<Number>,
+    pub symbols: Vec<(Point, char)>,
+}
+
+impl Schematic {
+    /// Iterates over the numbers next to `point`, each once however many of its digits touch it.
+    pub fn numbers_adjacent_to(&self, point: Point) -> impl Iterator<Item = &Number> {
+        self.numbers.iter().filter(move |number| number.is_adjacent_to(point))
+    }
+
+    /// Iterates over the part numbers, the numbers next to any symbol, in reading order.
+    pub fn part_numbers(&self) -> impl Iterator<Item = &Number> {
+        self.numbers.iter().filter(|number| number.is_adjacent_to_symbol(self))
+    }
+
+    /// Iterates over the gears, `*` symbols next to exactly two numbers, with those numbers in
+    /// reading order.
+    pub fn gears(&self) -> impl Iterator<Item = (Point, [&Number; 2])> {
+        self.symbols.iter()
+            .filter(|&&(_, c)| c == '*')
+            .filter_map(|&(point, _)| {
+                let mut numbers = self.numbers_adjacent_to(point);
+                match (numbers.next(), numbers.next(), numbers.next()) {
+                    (Some(n), Some(m), None) => Some((point, [n, m])),
+                    _ => None,
+                }
+            })
+    }
+}
+
+pub struct Day03;
+impl Problem for Day03 {
+    type Solution1 = u32;
+    type Solution2 = u32;
+    type Parsed = Schematic;
+
+    fn parse(input: &str) -> Self::Parsed {
+        Self::try_parse(input).unwrap_or_else(|e| panic!("{e:#}"))
+    }
+
+    fn try_parse(input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(parse_schematic(input)?)
+    }
+
+    fn part_1(schematic: &Self::Parsed) -> Self::Solution1 {
+        schematic.part_numbers().map(|number| number.value).sum()
+    }
+
+    fn part_2(schematic: &Self::Parsed) -> Self::Solution2 {
+        schematic.gears().map(|(_, [n, m])| n.value * m.value).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*; 
+
+    const SAMPLE: &str = "\
+        467..114..\n\
+        ...*......\n\
+        ..35..633.\n\
+        ......#...\n\
+        617*......\n\
+        .....+.58.\n\
+        ..592.....\n\
+        ......755.\n\
+        ...$.*....\n\
+        .664.598..";
+
+    /// The char-by-char scanner that came before [parse_schematic], to check it reads schematics
+    /// the same way.
+    fn scan_schematic(input: &str) -> Schematic {
+        let mut schematic = Schematic::default();
+
+        for (y, line) in input.lines().enumerate() {
+            let mut current: Option<Number> = None;
+            for (x, c) in line.chars().enumerate() {
+                match c.to_digit(10) {
+                    Some(digit) => {
+                        let number = current.get_or_insert(Number { value: 0, row: y, start_col: x, len: 0 });
+                        number.value = number.value * 10 + digit;
+                        number.len += 1;
+                    },
+                    None => {
+                        schematic.numbers.extend(current.take());
+                        if c != '.' {
+                            schematic.symbols.push((Point { x, y }, c));
+                        }
+                    },
+                }
+            }
+            schematic.numbers.extend(current);
+        }
+        schematic
+    }
+
+    test_part_1!(Day03, SAMPLE, 4361);
+    test_part_2!(Day03, SAMPLE, 467835);
+
+    #[test]
+    fn test_same_as_scanner() {
+        let sample = aoc::testing::trim_sample(SAMPLE);
+        let schematic = parse_schematic(sample).unwrap();
+        assert_eq!(schematic, scan_schematic(sample));
+        assert_eq!(schematic.numbers.len(), 10);
+        assert_eq!(schematic.symbols.len(), 6);
+    }
+
+    #[test]
+    fn test_sample_gears_and_part_numbers() {
+        let schematic = Day03::parse(SAMPLE);
+        let gears: Vec<(Point, [u32; 2])> = schematic.gears()
+            .map(|(point, numbers)| (point, numbers.map(|number| number.value)))
+            .collect();
+        assert_eq!(gears, vec![(Point { x: 3, y: 1 }, [467, 35]), (Point { x: 5, y: 8 }, [755, 598])]);
+
+        let excluded: Vec<u32> = schematic.numbers.iter()
+            .filter(|&number| !schematic.part_numbers().any(|part| part == number))
+            .map(|number| number.value)
+            .collect();
+        assert_eq!(excluded, vec![114, 58]);
+    }
+
+    #[test]
+    fn test_equal_numbers_next_to_gear() {
+        let input = Day03::parse("117*117");
+        assert_eq!(Day03::part_1(&input), 234);
+        assert_eq!(Day03::part_2(&input), 117 * 117);
+
+        let stacked = Day03::parse("12.\n.*.\n12.");
+        assert_eq!(Day03::part_1(&stacked), 24);
+        assert_eq!(Day03::part_2(&stacked), 144);
+    }
+
+    #[test]
+    fn test_only_stars_are_gears() {
+        let input = Day03::parse("12+34\n.....\n56*78");
+        assert_eq!(Day03::part_1(&input), 12 + 34 + 56 + 78);
+        assert_eq!(Day03::part_2(&input), 56 * 78);
+    }
+
+    #[test]
+    fn test_repeated_number_values() {
+        // Only the 35 next to a symbol is a part number, and touching two symbols counts it once
+        let input = Day03::parse("35.....\n...#35*\n.......\n35.....");
+        assert_eq!(Day03::part_1(&input), 35);
+        // The gear only touches one number
+        assert_eq!(Day03::part_2(&input), 0);
+        let values: Vec<u32> = input.numbers.iter().map(|number| number.value).collect();
+        assert_eq!(values, vec![35, 35, 35]);
+    }
+
+    #[test]
+    fn test_numbers_at_line_ends() {
+        let schematic = parse_schematic("..12\n*..#\n7...").unwrap();
+        assert_eq!(schematic.numbers, vec![
+            Number { value: 12, row: 0, start_col: 2, len: 2 },
+            Number { value: 7, row: 2, start_col: 0, len: 1 },
+        ]);
+        assert_eq!(schematic.symbols, vec![(Point { x: 0, y: 1 }, '*'), (Point { x: 3, y: 1 }, '#')]);
+        assert_eq!(Day03::part_1(&schematic), 12 + 7);
+    }
+
+    #[test]
+    fn test_diagonal_adjacency() {
+        let schematic = parse_schematic("1...2\n.#.$.\n3...4").unwrap();
+        let touching: Vec<u32> = schematic.numbers_adjacent_to(Point { x: 1, y: 1 }).map(|number| number.value).collect();
+        assert_eq!(touching, vec![1, 3]);
+        assert!(schematic.numbers.iter().all(|number| number.is_adjacent_to_symbol(&schematic)));
+        assert_eq!(Day03::part_1(&schematic), 10);
+
+        // Two columns away isn't adjacent
+        let apart = parse_schematic("1..\n..*").unwrap();
+        assert!(!apart.numbers[0].is_adjacent_to_symbol(&apart));
+    }
+
+    #[test]
+    fn test_number_touching_two_symbols() {
+        let schematic = parse_schematic("#42*\n....").unwrap();
+        assert_eq!(schematic.numbers_adjacent_to(Point { x: 0, y: 0 }).count(), 1);
+        assert_eq!(schematic.numbers_adjacent_to(Point { x: 3, y: 0 }).count(), 1);
+        assert_eq!(Day03::part_1(&schematic), 42);
+    }
+}
@@ -0,0 +1,116 @@
+use std::fmt::Display;
+
+use nom::{branch::alt, bytes::complete::is_a, character::complete::{digit1, satisfy}, combinator::{map, map_res}, multi::many0, IResult};
+
+use aoc::{grid::Point, parse::{parse_line, LineError}};
+
+use crate::{Number, Schematic};
+
+/// An error parsing the schematic, with the 1-based line and column it's at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchematicParseError {
+    /// The line has something other than digits, `.` and ASCII symbols, or a number too big for a
+    /// `u32`.
+    Syntax(LineError),
+    /// The line isn't as long as the first line. Schematics are rectangular, so short lines aren't
+    /// padded with empty space.
+    Ragged { line: usize, column: usize, len: usize, expected: usize },
+}
+
+impl Display for SchematicParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Syntax(e) => write!(f, "Couldn't parse schematic: {e}"),
+            Self::Ragged { line, column, len, expected } => write!(f, "Couldn't parse line {line}, column {column}: it's {len} characters long, expected {expected} like the first line"),
+        }
+    }
+}
+
+impl std::error::Error for SchematicParseError {}
+
+/// Reads the numbers and symbols of a schematic, where `.` is empty space.
+///
+/// Every line must be as long as the first.
+pub fn parse_schematic(input: &str) -> Result<Schematic, SchematicParseError> {
+    let mut schematic = Schematic::default();
+    let mut width = None;
+
+    for (y, text) in input.lines().enumerate() {
+        let cells = parse_line(text, y + 1, &mut many0(cell)).map_err(SchematicParseError::Syntax)?;
+        let expected = *width.get_or_insert(text.len());
+        if text.len() != expected {
+            let column = text.len().min(expected) + 1;
+            return Err(SchematicParseError::Ragged { line: y + 1, column, len: text.len(), expected });
+        }
+
+        let mut x = 0;
+        for cell in cells {
+            match cell {
+                Cell::Number(value, len) => {
+                    schematic.numbers.push(Number { value, row: y, start_col: x, len });
+                    x += len;
+                },
+                Cell::Symbol(c) => {
+                    schematic.symbols.push((Point { x, y }, c));
+                    x += 1;
+                },
+                Cell::Empty(len) => x += len,
+            }
+        }
+    }
+    Ok(schematic)
+}
+
+/// A run of the schematic's line, with its length in columns.
+enum Cell {
+    Number(u32, usize),
+    Symbol(char),
+    Empty(usize),
+}
+
+//  467, *, or ..
+fn cell(i: &str) -> IResult<&str, Cell> {
+    alt((
+        map_res(digit1, |digits: &str| digits.parse().map(|value| Cell::Number(value, digits.len()))),
+        map(satisfy(|c| c.is_ascii_punctuation() && c != '.'), Cell::Symbol),
+        map(is_a("."), |dots: &str| Cell::Empty(dots.len())),
+    ))(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_schematic() {
+        let schematic = parse_schematic("467..\n..*.$").unwrap();
+        assert_eq!(schematic.numbers, vec![Number { value: 467, row: 0, start_col: 0, len: 3 }]);
+        assert_eq!(schematic.symbols, vec![(Point { x: 2, y: 1 }, '*'), (Point { x: 4, y: 1 }, '$')]);
+        assert_eq!(parse_schematic("").unwrap(), Schematic::default());
+    }
+
+    #[test]
+    fn test_multibyte_char() {
+        let err = parse_schematic("12..\n.é.*").unwrap_err();
+        let SchematicParseError::Syntax(line_error) = &err else { panic!("{err:?}") };
+        assert_eq!((line_error.line, line_error.column), (2, 2));
+        assert_eq!(err.to_string(), r#"Couldn't parse schematic: Line 2, column 2: unexpected "é.*""#);
+        // Letters and whitespace aren't symbols either
+        assert!(matches!(parse_schematic("1.a"), Err(SchematicParseError::Syntax(LineError { column: 3, .. }))));
+        assert!(matches!(parse_schematic("1. "), Err(SchematicParseError::Syntax(LineError { column: 3, .. }))));
+    }
+
+    #[test]
+    fn test_number_too_big() {
+        let err = parse_schematic("..99999999999").unwrap_err();
+        assert!(matches!(err, SchematicParseError::Syntax(LineError { line: 1, column: 3, .. })), "{err:?}");
+    }
+
+    #[test]
+    fn test_ragged_lines() {
+        let err = parse_schematic("1...\n..*\n....").unwrap_err();
+        assert_eq!(err, SchematicParseError::Ragged { line: 2, column: 4, len: 3, expected: 4 });
+        assert_eq!(err.to_string(), "Couldn't parse line 2, column 4: it's 3 characters long, expected 4 like the first line");
+        assert!(matches!(parse_schematic("1...\n....*"), Err(SchematicParseError::Ragged { line: 2, column: 5, .. })));
+    }
+}
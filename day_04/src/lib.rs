@@ -0,0 +1,238 @@
+use std::{collections::HashSet, fmt::Display};
+
+use aoc::{parse::{labeled, lexeme, lines_of, number_list, uint, LineError}, *};
+use nom::{character::complete::space1, IResult};
+
+#[derive(Debug)]
+pub struct Card {
+    id: u32,
+    winning_nums: HashSet<u32>,
+    nums: Vec<u32>,
+}
+
+impl Card {
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Counts the card's numbers that are winning numbers.
+    fn matching_nums(&self) -> usize {
+        self.nums.iter()
+            .filter(|n| self.winning_nums.contains(n))
+            .count()
+    }
+
+    /// Doubles the score for every match after the first. Saturates at `u64::MAX` from 65 matches.
+    fn score(&self) -> u64 {
+        match self.matching_nums() {
+            0 => 0,
+            matches => 1_u64.checked_shl(matches as u32 - 1).unwrap_or(u64::MAX),
+        }
+    }
+}
+
+// Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+fn parse_card(i: &str) -> IResult<&str, Card> {
+    let (i, id) = labeled("Card", uint::<u32>())(i)?;
+    let (i, winning_nums) = labeled(":", lexeme(number_list(space1)))(i)?;
+    let (i, nums) = labeled("|", number_list(space1))(i)?;
+
+    Ok((i, Card { id, winning_nums: winning_nums.into_iter().collect(), nums }))
+}
+
+/// Parses every line of the input as a card.
+pub fn parse_cards(input: &str) -> Result<Vec<Card>, LineError> {
+    lines_of(input, parse_card)
+}
+
+/// A card that's out of order. Part 2's copies go to the cards after each one, so the cards must
+/// be numbered 1, 2, 3, ... in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CardIdError {
+    /// The 1-based position of the card in the input
+    pub position: usize,
+    pub id: u32,
+}
+
+impl Display for CardIdError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Card {} is number {} in the input, expected cards numbered in order from 1", self.id, self.position)
+    }
+}
+
+impl std::error::Error for CardIdError {}
+
+/// Returns how many of each card there are once every card's matches have won copies of the
+/// cards after it, counting the original.
+///
+/// Copies past the last card are lost, as there's no card to copy. Counts saturate at `u64::MAX`.
+pub fn card_counts(cards: &[Card]) -> Vec<u64> {
+    let mut counts = vec![1_u64; cards.len()];
+    for (i, card) in cards.iter().enumerate() {
+        let won = i + 1..(i + 1 + card.matching_nums()).min(cards.len());
+        for j in won {
+            counts[j] = counts[j].saturating_add(counts[i]);
+        }
+    }
+    counts
+}
+
+/// Counts all the cards from [card_counts], checking that the cards are in order first.
+pub fn total_cards(cards: &[Card]) -> Result<u64, CardIdError> {
+    if let Some((position, card)) = (1..).zip(cards).find(|&(position, card)| card.id as usize != position) {
+        return Err(CardIdError { position, id: card.id });
+    }
+    Ok(card_counts(cards).into_iter().fold(0, u64::saturating_add))
+}
+
+pub struct Day04;
+impl Problem for Day04 {
+    type Solution1 = u64;
+    type Solution2 = u64;
+    type Parsed = Vec<Card>;
+
+    fn parse(input: &str) -> Self::Parsed {
+        Self::try_parse(input).unwrap_or_else(|e| panic!("{e:#}"))
+    }
+
+    fn try_parse(input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(parse_cards(input)?)
+    }
+
+    fn part_1(cards: &Self::Parsed) -> Self::Solution1 {
+        cards.iter().map(Card::score).fold(0, u64::saturating_add)
+    }
+
+    fn part_2(cards: &Self::Parsed) -> Self::Solution2 {
+        total_cards(cards).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    fn try_part_2(cards: &Self::Parsed) -> anyhow::Result<Self::Solution2> {
+        Ok(total_cards(cards)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*; 
+
+    const SAMPLE: &str = "\
+        Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53\n\
+        Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19\n\
+        Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1\n\
+        Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83\n\
+        Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36\n\
+        Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    test_part_1!(Day04, SAMPLE, 13);
+    test_part_2!(Day04, SAMPLE, 30);
+
+    #[test]
+    fn test_parse_error_position() {
+        let broken = SAMPLE.replace("Card 4: 41 92", "Card 4: 41 9x2");
+        let err = Day04::try_parse(&broken).unwrap_err();
+        assert_eq!(err.to_string(), "Line 4, column 13: expected Tag at \"x2 73 84 69 | 59 84 76 51 58  5 54 83\"");
+    }
+
+    #[test]
+    fn test_card_counts() {
+        let counts = card_counts(&Day04::parse(SAMPLE));
+        assert_eq!(counts, vec![1, 2, 4, 8, 14, 1]);
+        assert_eq!(counts.iter().sum::<u64>(), 30);
+    }
+
+    #[test]
+    fn test_matches_past_last_card() {
+        // Card 2 has 3 matches but only one card follows it
+        let cards = Day04::parse("\
+            Card 1: 1 2 | 1 5\n\
+            Card 2: 1 2 3 | 1 2 3\n\
+            Card 3: 4 | 5");
+        assert_eq!(card_counts(&cards), vec![1, 2, 3]);
+        assert_eq!(Day04::part_2(&cards), 6);
+    }
+
+    /// A card with `matches` matching numbers, plus one more number on each side that doesn't match.
+    fn card(id: u32, matches: u32) -> String {
+        let join = |extra: u32| (1..=matches).chain([extra]).map(|n| n.to_string()).collect::<Vec<_>>().join(" ");
+        format!("Card {id}: {} | {}", join(1000), join(2000))
+    }
+
+    #[test]
+    fn test_large_scores() {
+        let cards = Day04::parse(&[card(1, 40), card(2, 64)].join("\n"));
+        assert_eq!(cards[0].score(), 1 << 39);
+        assert_eq!(cards[1].score(), 1 << 63);
+        assert_eq!(Day04::part_1(&cards), (1 << 63) + (1 << 39));
+
+        let cards = Day04::parse(&[card(1, 65), card(2, 1)].join("\n"));
+        assert_eq!(cards[0].score(), u64::MAX);
+        assert_eq!(Day04::part_1(&cards), u64::MAX);
+    }
+
+    #[test]
+    fn test_large_counts() {
+        // Every card wins a copy of each card after it, doubling the copies down the list
+        let cards: Vec<String> = (1..=34).map(|id| card(id, 34 - id)).collect();
+        let cards = Day04::parse(&cards.join("\n"));
+        let counts = card_counts(&cards);
+        assert_eq!(counts[33], 1 << 33);
+        assert!(counts[33] > u32::MAX as u64);
+        assert_eq!(Day04::part_2(&cards), (1 << 34) - 1);
+
+        let cards: Vec<String> = (1..=70).map(|id| card(id, 70 - id)).collect();
+        assert_eq!(Day04::part_2(&Day04::parse(&cards.join("\n"))), u64::MAX);
+    }
+
+    #[test]
+    fn test_card_ids() {
+        let cards = Day04::parse(SAMPLE);
+        assert_eq!(cards.iter().map(Card::id).collect::<Vec<_>>(), vec![1, 2, 3, 4, 5, 6]);
+
+        let swapped = SAMPLE.replace("Card 2", "Card 0").replace("Card 3", "Card 2").replace("Card 0", "Card 3");
+        let err = total_cards(&Day04::parse(&swapped)).unwrap_err();
+        assert_eq!(err, CardIdError { position: 2, id: 3 });
+        assert_eq!(err.to_string(), "Card 3 is number 2 in the input, expected cards numbered in order from 1");
+        let cards = Day04::parse(&swapped);
+        assert!(Day04::try_part_2(&cards).is_err());
+        // Part 1 doesn't care about the order
+        assert_eq!(Day04::try_part_1(&cards).unwrap(), 13);
+
+        let skipped = SAMPLE.replace("Card 1:", "Card 0:");
+        assert_eq!(total_cards(&Day04::parse(&skipped)), Err(CardIdError { position: 1, id: 0 }));
+    }
+
+    #[test]
+    fn test_try_run_out_of_order() {
+        let swapped = SAMPLE.replace("Card 2", "Card 0").replace("Card 3", "Card 2").replace("Card 0", "Card 3");
+        let err = Day04::try_run_with(&swapped, &RunConfig::default()).unwrap_err();
+        assert_eq!(err.downcast_ref(), Some(&CardIdError { position: 2, id: 3 }));
+        let report = Day04::try_run_with(&swapped, &RunConfig { parts: Parts::Part1 }).unwrap();
+        assert_eq!(report.part1.unwrap().0, "13");
+    }
+
+    #[test]
+    fn test_leftover_text() {
+        let err = Day04::try_parse("Card 1: 41 48 | 83 86 x").unwrap_err();
+        assert_eq!(err.to_string(), "Line 1, column 22: unexpected \" x\"");
+        assert!(Day04::try_parse("Card: 41 48 | 83 86").is_err());
+        assert!(Day04::try_parse("Cards 1: 41 48 | 83 86").is_err());
+    }
+
+    #[test]
+    fn test_many_numbers() {
+        // Card 1 has 10 matches and every other card has none
+        let join = |nums: std::ops::Range<u32>| nums.map(|n| n.to_string()).collect::<Vec<_>>().join(" ");
+        let input: Vec<String> = (1..=200)
+            .map(|id| {
+                let nums = if id == 1 { 490..990 } else { 500..1000 };
+                format!("Card {id}: {} | {}", join(0..500), join(nums))
+            })
+            .collect();
+        let cards = parse_cards(&input.join("\n")).unwrap();
+        assert_eq!(cards[0].matching_nums(), 10);
+        assert_eq!(cards[1].matching_nums(), 0);
+        assert_eq!(Day04::part_1(&cards), 512);
+        assert_eq!(Day04::part_2(&cards), 210);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,224 @@
+mod parse;
+
+use std::{marker::PhantomData, ops::Range};
+use itertools::Itertools;
+use aoc::*;
+
+/// A category of value in the almanac, named as in the map headers.
+pub trait Category {
+    const NAME: &'static str;
+}
+
+macro_rules! categories {
+    ($( $category:ident => $name:literal ),+) => {
+        $(
+            #[derive(Debug)]
+            pub enum $category {}
+
+            impl Category for $category {
+                const NAME: &'static str = $name;
+            }
+        )+
+    };
+}
+
+categories!(
+    Seed => "seed",
+    Soil => "soil",
+    Fertilizer => "fertilizer",
+    Water => "water",
+    Light => "light",
+    Temperature => "temperature",
+    Humidity => "humidity",
+    Location => "location"
+);
+
+/// A number tagged with the category it belongs to, so values can't be fed into the wrong map.
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct Value<C>(u64, PhantomData<C>);
+
+impl<C> Value<C> {
+    fn new(num: u64) -> Self {
+        Value(num, PhantomData)
+    }
+}
+
+// Manual impls, since deriving would needlessly require the same traits on the category
+impl<C> Clone for Value<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C> Copy for Value<C> {}
+
+impl<C> PartialEq for Value<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<C> Eq for Value<C> {}
+
+impl<C> PartialOrd for Value<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C> Ord for Value<C> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+#[derive(Debug)]
+struct RangeMap<From, To> {
+    dest_range: Range<u64>,
+    source_range: Range<u64>,
+    categories: PhantomData<(From, To)>,
+}
+
+impl<From, To> RangeMap<From, To> {
+    fn new(dest_start: u64, source_start: u64, len: u64) -> Self {
+        RangeMap { 
+            dest_range: dest_start..dest_start+len,
+            source_range: source_start..source_start+len,
+            categories: PhantomData,
+        }
+    }
+
+    fn get(&self, num: Value<From>) -> Option<Value<To>> {
+        if self.source_range.contains(&num.0) {
+            let idx = num.0 - self.source_range.start;
+            Some(Value::new(self.dest_range.start + idx))
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Almanac<From, To> {
+    maps: Vec<RangeMap<From, To>>,
+}
+
+impl<From, To> Almanac<From, To> {
+    fn new(maps: Vec<RangeMap<From, To>>) -> Self {
+        Almanac { maps }
+    }
+
+    fn get(&self, num: Value<From>) -> Value<To> {
+        self.maps.iter()
+            .find_map(|map| map.get(num))
+            .unwrap_or(Value::new(num.0))
+    }
+}
+
+/// Every almanac from seed to location, in the order they must be applied.
+#[derive(Debug)]
+struct AlmanacChain {
+    seed_to_soil: Almanac<Seed, Soil>,
+    soil_to_fertilizer: Almanac<Soil, Fertilizer>,
+    fertilizer_to_water: Almanac<Fertilizer, Water>,
+    water_to_light: Almanac<Water, Light>,
+    light_to_temperature: Almanac<Light, Temperature>,
+    temperature_to_humidity: Almanac<Temperature, Humidity>,
+    humidity_to_location: Almanac<Humidity, Location>,
+}
+
+impl AlmanacChain {
+    fn location(&self, seed: Value<Seed>) -> Value<Location> {
+        let soil = self.seed_to_soil.get(seed);
+        let fertilizer = self.soil_to_fertilizer.get(soil);
+        let water = self.fertilizer_to_water.get(fertilizer);
+        let light = self.water_to_light.get(water);
+        let temperature = self.light_to_temperature.get(light);
+        let humidity = self.temperature_to_humidity.get(temperature);
+        self.humidity_to_location.get(humidity)
+    }
+}
+
+pub struct Day05;
+impl Problem for Day05 {
+    type Solution1 = u64;
+    type Solution2 = u64;
+    type Parsed = String;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input.to_string()
+    }
+
+    fn part_1(input: &Self::Parsed) -> Self::Solution1 {
+        let (_, (seeds, chain)) = parse::parse_input(input).unwrap();
+        seeds.into_iter()
+            .map(|seed| chain.location(Value::<Seed>::new(seed)))
+            .min().unwrap().0
+    }
+
+    fn part_2(input: &Self::Parsed) -> Self::Solution2 {
+        // This is bad and slow but I'm too lazy to think of a
+        // more clever solution
+        let (_, (seeds, chain)) = parse::parse_input(input).unwrap();
+        seeds.into_iter()
+            .tuples()
+            .map(|(start, length)| (Value::<Seed>::new(start), length))
+            .flat_map(|(start, length)| (start.0..start.0+length).map(Value::<Seed>::new))
+            .map(|seed| chain.location(seed))
+            .min().unwrap().0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*; 
+
+    const SAMPLE: &str = "\
+        seeds: 79 14 55 13\n\
+        \n\
+        seed-to-soil map:\n\
+        50 98 2\n\
+        52 50 48\n\
+        \n\
+        soil-to-fertilizer map:\n\
+        0 15 37\n\
+        37 52 2\n\
+        39 0 15\n\
+        \n\
+        fertilizer-to-water map:\n\
+        49 53 8\n\
+        0 11 42\n\
+        42 0 7\n\
+        57 7 4\n\
+        \n\
+        water-to-light map:\n\
+        88 18 7\n\
+        18 25 70\n\
+        \n\
+        light-to-temperature map:\n\
+        45 77 23\n\
+        81 45 19\n\
+        68 64 13\n\
+        \n\
+        temperature-to-humidity map:\n\
+        0 69 1\n\
+        1 0 69\n\
+        \n\
+        humidity-to-location map:\n\
+        60 56 37\n\
+        56 93 4";
+
+    test_part_1!(Day05, SAMPLE, 35);
+    test_part_2!(Day05, SAMPLE, 46);
+
+    #[test]
+    fn test_maps_out_of_order() {
+        // Swap the first two maps, which no longer chain from seed to location
+        let (head, rest) = SAMPLE.split_once("seed-to-soil").unwrap();
+        let (seed_to_soil, rest) = rest.split_once("soil-to-fertilizer").unwrap();
+        let (soil_to_fertilizer, rest) = rest.split_once("fertilizer-to-water").unwrap();
+        let swapped = format!("{head}soil-to-fertilizer{soil_to_fertilizer}seed-to-soil{seed_to_soil}fertilizer-to-water{rest}");
+        assert!(parse::parse_input(&swapped).is_err());
+    }
+}
@@ -1,33 +1,46 @@
-use nom::{bytes::complete::{tag, take_till}, character::complete::{multispace1, space1}, combinator::map, multi::separated_list1, sequence::{preceded, tuple}, AsChar, IResult};
+use aoc::parse::{labeled, lexeme, number_list, uint};
+use nom::{bytes::complete::tag, character::complete::{multispace1, space1}, combinator::map, multi::separated_list1, sequence::{preceded, tuple}, IResult};
 
-use crate::{Almanac, RangeMap};
+use crate::{Almanac, AlmanacChain, Category, RangeMap};
 
-fn parse_range_map(i: &str) -> IResult<&str, RangeMap> {
+fn parse_range_map<From, To>(i: &str) -> IResult<&str, RangeMap<From, To>> {
     map(
-        tuple((
-            nom::character::complete::u64,
-            preceded(space1, nom::character::complete::u64),
-            preceded(space1, nom::character::complete::u64),
-        )),
+        tuple((lexeme(uint()), lexeme(uint()), uint())),
         |(dest_start, source_start, len)| { RangeMap::new(dest_start, source_start, len) }
     )(i)
 }
 
-fn parse_almanac(i: &str) -> IResult<&str, Almanac> {
-    let (i, _) = take_till(AsChar::is_dec_digit)(i)?;
+// seed-to-soil map:
+// 50 98 2
+// 52 50 48
+fn parse_almanac<From: Category, To: Category>(i: &str) -> IResult<&str, Almanac<From, To>> {
+    let (i, _) = tuple((tag(From::NAME), tag("-to-"), tag(To::NAME), tag(" map:"), multispace1))(i)?;
     let (i, maps) = separated_list1(multispace1, parse_range_map)(i)?;
     Ok((i, Almanac::new(maps)))
 }
 
 // seeds: 79 14 55 13
-
+//
 // seed-to-soil map:
-// 50 98 2
-// 52 50 48
-
-pub fn parse_input(i: &str) -> IResult<&str, (Vec<u64>, Vec<Almanac>)> {
-    let (i, _) = tag("seeds: ")(i)?;
-    let (i, seeds) = separated_list1(space1, nom::character::complete::u64)(i)?;
-    let (i, almanacs) = separated_list1(multispace1, parse_almanac)(i)?;
-    Ok((i, (seeds, almanacs)))
-}
\ No newline at end of file
+// ...
+/// Parses the numbers on the seed line as plain numbers, since part 2 reads every other one as a
+/// range length rather than a seed.
+pub fn parse_input(i: &str) -> IResult<&str, (Vec<u64>, AlmanacChain)> {
+    let (i, seeds) = labeled("seeds:", number_list(space1))(i)?;
+    let (i, seed_to_soil) = preceded(multispace1, parse_almanac)(i)?;
+    let (i, soil_to_fertilizer) = preceded(multispace1, parse_almanac)(i)?;
+    let (i, fertilizer_to_water) = preceded(multispace1, parse_almanac)(i)?;
+    let (i, water_to_light) = preceded(multispace1, parse_almanac)(i)?;
+    let (i, light_to_temperature) = preceded(multispace1, parse_almanac)(i)?;
+    let (i, temperature_to_humidity) = preceded(multispace1, parse_almanac)(i)?;
+    let (i, humidity_to_location) = preceded(multispace1, parse_almanac)(i)?;
+    Ok((i, (seeds, AlmanacChain {
+        seed_to_soil,
+        soil_to_fertilizer,
+        fertilizer_to_water,
+        water_to_light,
+        light_to_temperature,
+        temperature_to_humidity,
+        humidity_to_location,
+    })))
+}
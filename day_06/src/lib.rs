@@ -0,0 +1,108 @@
+use aoc::*;
+
+#[derive(Debug)]
+struct Race {
+    duration: usize,
+    record_dist: usize,
+}
+
+impl Race {
+    fn wins(&self, hold_frames: usize) -> bool {
+        hold_frames * (self.duration - hold_frames) > self.record_dist
+    }
+
+    /// Counts the winning runs by solving `hold * (duration - hold) > record` for `hold`. The
+    /// float roots are only an estimate, so the lowest win is corrected by checking neighbours.
+    fn record_winning_runs(&self) -> usize {
+        // Winning runs are symmetric around the longest distance, at half the duration
+        let half = self.duration / 2;
+        if !self.wins(half) {
+            return 0;
+        }
+        let (duration, record) = (self.duration as f64, self.record_dist as f64);
+        let root = (duration - (duration * duration - 4.0 * record).sqrt()) / 2.0;
+        let mut lowest = (root.max(0.0) as usize).min(half);
+        while lowest > 0 && self.wins(lowest - 1) {
+            lowest -= 1;
+        }
+        while !self.wins(lowest) {
+            lowest += 1;
+        }
+        self.duration - 2 * lowest + 1
+    }
+
+    /// Counts the winning runs by trying every hold time.
+    #[cfg(test)]
+    fn record_winning_runs_brute_force(&self) -> usize {
+        (0..=self.duration)
+            .filter(|hold_frames| {
+                let remaining_frames = self.duration - hold_frames;
+                let dist = hold_frames * remaining_frames;
+                dist > self.record_dist
+            })
+            .count()
+    }
+}
+
+fn parse_input_1(input: &str) -> Vec<Race> {
+    let mut lines = input.lines();
+    let times = lines.next().unwrap().extract_ints();
+    let records = lines.next().unwrap().extract_ints();
+    times.into_iter().zip(records).map(|(duration, record_dist)| Race { duration, record_dist }).collect()
+}
+
+fn parse_input_2(input: &str) -> Race {
+    // Part 2 ignores the spaces between digits
+    let [duration, record_dist] = input.replace(' ', "").extract_ints()[..] else {
+        panic!("Expected a time and a distance");
+    };
+    Race { duration, record_dist }
+}
+
+pub struct Day06;
+impl Problem for Day06 {
+    type Solution1 = usize;
+    type Solution2 = usize;
+    type Parsed = String;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input.to_string()
+    }
+
+    fn part_1(input: &Self::Parsed) -> Self::Solution1 {
+        let races = parse_input_1(input);
+        races.iter()
+            .map(|race| race.record_winning_runs())
+            .product()
+    }
+
+    fn part_2(input: &Self::Parsed) -> Self::Solution2 {
+        let race = parse_input_2(input);
+        race.record_winning_runs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::stress::Rng;
+
+    use super::*; 
+
+    const SAMPLE: &str = "\
+        Time:      7  15   30\n\
+        Distance:  9  40  200";
+
+    test_part_1!(Day06, SAMPLE, 288);
+    test_part_2!(Day06, SAMPLE, 71503);
+
+    #[test]
+    fn test_closed_form_matches_brute_force() {
+        let gen = |rng: &mut aoc::stress::StdRng| {
+            let duration = rng.gen_range(0..200);
+            // Up to a bit past the longest possible distance, so some races can't be won
+            let record_dist = rng.gen_range(0..duration * duration / 4 + 5);
+            Race { duration, record_dist }
+        };
+        aoc::stress::check(gen, Race::record_winning_runs, Race::record_winning_runs_brute_force, 2000);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,87 @@
+use aoc::*;
+use itertools::Itertools;
+
+/// Extrapolates both the next and previous values of a sequence from a single difference table.
+/// 
+/// Returns `(next, previous)`.
+fn extrapolate(nums: &[i64]) -> (i64, i64) {
+    let mut firsts = vec![];
+    let mut next = 0;
+    let mut row = nums.to_vec();
+    while !row.iter().all(|&n| n == 0) {
+        firsts.push(row[0]);
+        next += row[row.len() - 1];
+        row = differences(&row);
+    }
+    // Previous value is the alternating sum of the first elements of each row
+    let previous = firsts.iter().rev().fold(0, |acc, first| first - acc);
+    (next, previous)
+}
+
+fn differences(nums: &[i64]) -> Vec<i64> {
+    nums.iter().tuple_windows()
+        .map(|(a, b)| b - a)
+        .collect()
+}
+
+pub struct Day09;
+impl Problem for Day09 {
+    type Solution1 = i64;
+    type Solution2 = i64;
+    type Parsed = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input.lines()
+            .map(|line| line.extract_ints())
+            .collect()
+    }
+
+    fn part_1(sequences: &Self::Parsed) -> Self::Solution1 {
+        Self::part_1_prepared(&Self::prepare(sequences))
+    }
+
+    fn part_2(sequences: &Self::Parsed) -> Self::Solution2 {
+        Self::part_2_prepared(&Self::prepare(sequences))
+    }
+
+    /// Extrapolates each sequence once for both parts.
+    fn solve(input: &str) -> (Self::Solution1, Option<Self::Solution2>) {
+        solve_prepared::<Self>(input)
+    }
+}
+
+impl PreparedProblem for Day09 {
+    type Intermediate = Vec<(i64, i64)>;
+
+    fn prepare(sequences: &Self::Parsed) -> Self::Intermediate {
+        sequences.iter()
+            .map(|nums| extrapolate(nums))
+            .collect()
+    }
+
+    fn part_1_prepared(extrapolated: &Self::Intermediate) -> Self::Solution1 {
+        extrapolated.iter().map(|(next, _)| next).sum()
+    }
+
+    fn part_2_prepared(extrapolated: &Self::Intermediate) -> Self::Solution2 {
+        extrapolated.iter().map(|(_, previous)| previous).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*; 
+
+    const SAMPLE: &str = "\
+        0 3 6 9 12 15\n\
+        1 3 6 10 15 21\n\
+        10 13 16 21 30 45";
+
+    test_part_1!(Day09, SAMPLE, 114);
+    test_part_2!(Day09, SAMPLE, 2);
+
+    #[test]
+    fn test_extrapolate() {
+        assert_eq!(extrapolate(&[10, 13, 16, 21, 30, 45]), (68, 5));
+    }
+}
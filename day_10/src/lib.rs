@@ -0,0 +1,184 @@
+use std::collections::HashSet;
+
+use aoc::*;
+use aoc::grid::{count_interior, Crossing, Direction, Grid, GridIterator};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PipeGridCell {
+    Start,
+    Pipe(Direction, Direction),
+}
+
+impl PipeGridCell {
+    fn next_from(&self, dir: Direction) -> Option<&Direction> {
+        match self {
+            PipeGridCell::Pipe(dir_1, dir_2) => {
+                if *dir_1 == dir.opposite() {
+                    Some(dir_2)
+                } else if *dir_2 == dir.opposite() {
+                    Some(dir_1)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<char> for PipeGridCell {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        use PipeGridCell as C;
+        use Direction as D;
+        match c {
+            '|' => Ok(C::Pipe(D::North, D::South)),
+            '-' => Ok(C::Pipe(D::West, D::East)),
+            'L' => Ok(C::Pipe(D::North, D::East)),
+            'J' => Ok(C::Pipe(D::North, D::West)),
+            '7' => Ok(C::Pipe(D::South, D::West)),
+            'F' => Ok(C::Pipe(D::South, D::East)),
+            'S' => Ok(C::Start),
+            _ => Err(format!("No cell corresponds to character '{}'", c))
+        }
+    }
+}
+
+impl std::fmt::Display for PipeGridCell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use PipeGridCell as C;
+        use Direction as D;
+        let c = match self {
+            C::Start => '█',
+            C::Pipe(D::North, D::South) => '║',
+            C::Pipe(D::West, D::East) => '═',
+            C::Pipe(D::North, D::East) => '╚',
+            C::Pipe(D::North, D::West) => '╝',
+            C::Pipe(D::South, D::West) => '╗',
+            C::Pipe(D::South, D::East) => '╔',
+            _ => ' ',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+pub struct Day10;
+impl Problem for Day10 {
+    type Solution1 = usize;
+    type Solution2 = usize;
+    type Parsed = String;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input.to_string()
+    }
+
+    fn part_1(input: &Self::Parsed) -> Self::Solution1 {
+        use PipeGridCell as C;
+        let grid: Grid<C> = input.into();
+
+        let start_point = grid.iter().indexed()
+            .find_map(|(p, s)| {
+                if s == &C::Start { Some(p) } else { None }
+            }).unwrap();
+        let (mut current_point, mut current_dir) = grid.neighbor_entries(start_point)
+            .find_map(|(dir, next_point, next_cell)| Some((next_point, *next_cell.next_from(dir)?)))
+            .unwrap();
+
+        let mut len = 1;
+        loop {
+            len += 1;
+            current_point = current_point.offset_by(current_dir.vector()).expect("Pipe path went out of bounds");
+            match grid.get(current_point) {
+                Some(C::Start) => { break; },
+                Some(pipe) => {
+                    current_dir = *pipe.next_from(current_dir).expect("Pipe path ended unexpectedly");
+                }
+                _ => panic!("Pipe path ended unexpectedly")
+            }
+        }
+        len / 2
+    }
+
+    fn part_2(input: &Self::Parsed) -> Self::Solution2 {
+        use PipeGridCell as C;
+        let grid: Grid<C> = input.into();
+
+        let start_point = grid.iter().indexed()
+            .find_map(|(p, s)| {
+                if s == &C::Start { Some(p) } else { None }
+            }).unwrap();
+        // The start is connected to the neighbors whose pipes lead back into it
+        let start_dirs: Vec<Direction> = grid.neighbor_entries(start_point)
+            .filter_map(|(dir, _, next_cell)| next_cell.next_from(dir).map(|_| dir))
+            .collect();
+
+        let mut boundary = HashSet::from([start_point]);
+        let (mut current_point, mut current_dir) = (start_point, start_dirs[0]);
+        loop {
+            current_point = current_point.offset_by(current_dir.vector()).expect("Pipe path went out of bounds");
+            match grid.get(current_point) {
+                Some(C::Start) => { break; },
+                Some(pipe) => {
+                    boundary.insert(current_point);
+                    current_dir = *pipe.next_from(current_dir).expect("Pipe path ended unexpectedly");
+                }
+                _ => panic!("Pipe path ended unexpectedly")
+            }
+        }
+
+        count_interior(&boundary, |point| {
+            let connects = |dir| match grid.get(point) {
+                Some(C::Pipe(dir_1, dir_2)) => *dir_1 == dir || *dir_2 == dir,
+                _ => start_dirs.contains(&dir),
+            };
+            match (connects(Direction::North), connects(Direction::South)) {
+                (true, true) => Crossing::Vertical,
+                (true, false) => Crossing::North,
+                (false, true) => Crossing::South,
+                (false, false) => Crossing::Horizontal,
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*; 
+
+    const SAMPLE_1: &str = "\
+        7-F7-\n\
+        .FJ|7\n\
+        SJLL7\n\
+        |F--J\n\
+        LJ.LJ";
+
+    const SAMPLE_2: &str = "\
+        FF7FSF7F7F7F7F7F---7\n\
+        L|LJ||||||||||||F--J\n\
+        FL-7LJLJ||||||LJL-77\n\
+        F--JF--7||LJLJ7F7FJ-\n\
+        L---JF-JLJ.||-FJLJJ7\n\
+        |F|F-JF---7F7-L7L|7|\n\
+        |FFJF7L7F-JF7|JL---7\n\
+        7-L-JL7||F7|L7F-7F7|\n\
+        L.L7LFJ|||||FJL7||LJ\n\
+        L7JLJL-JLJLJL--JLJ.L";
+
+    const SAMPLE_3: &str = "\
+        .F----7F7F7F7F-7....\n\
+        .|F--7||||||||FJ....\n\
+        .||.FJ||||||||L7....\n\
+        FJL7L7LJLJ||LJ.L-7..\n\
+        L--J.L7...LJS7F-7L7.\n\
+        ....F-J..F7FJ|L7L7L7\n\
+        ....L7.F7||L7|.L7L7|\n\
+        .....|FJLJ|FJ|F7|.LJ\n\
+        ....FJL-7.||.||||...\n\
+        ....L---J.LJ.LJLJ...";
+
+    test_part_1!(Day10, SAMPLE_1, 8);
+
+    test_part_2!(Day10, SAMPLE_2, 10, SAMPLE_3, 8);
+
+}
\ No newline at end of file
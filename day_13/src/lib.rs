@@ -0,0 +1,56 @@
+use aoc::{grid::{Grid, Reflection}, EnumFromChar, Problem};
+
+#[derive(Debug, PartialEq, Eq, EnumFromChar)]
+enum Cell {
+    #[char = '.'] Ash,
+    #[char = '#'] Rock,
+}
+
+fn summarize(grid: &Grid<Cell>, smudges: usize) -> usize {
+    grid.mirror_axes(smudges).into_iter()
+        .map(|axis| match axis {
+            Reflection::Vertical(cols) => cols,
+            Reflection::Horizontal(rows) => 100 * rows,
+        })
+        .sum()
+}
+
+pub struct Day13;
+impl Problem for Day13 {
+    type Solution1 = usize;
+    type Solution2 = usize;
+    type Parsed = String;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input.to_string()
+    }
+
+    fn part_1(input: &Self::Parsed) -> Self::Solution1 {
+        input.split("\n\n")
+            .map(Grid::from)
+            .map(|grid| summarize(&grid, 0))
+            .sum()
+    }
+
+    fn part_2(input: &Self::Parsed) -> Self::Solution2 {
+        input.split("\n\n")
+            .map(Grid::from)
+            .map(|grid| summarize(&grid, 1))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::{test_part_1_file, test_part_2_file, test_real_input, test_samples_file};
+
+    use super::*; 
+
+    test_part_1_file!(Day13, "samples/sample.txt", 405);
+
+    test_part_2_file!(Day13, "samples/sample.txt", 400);
+
+    test_samples_file!(Day13, "samples/samples.manifest");
+
+    test_real_input!(Day13);
+}
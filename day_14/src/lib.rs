@@ -0,0 +1,265 @@
+use std::{collections::HashMap, fmt::Display, hash::Hash};
+
+use aoc::{EnumFromChar, grid::Direction, Problem};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumFromChar)]
+#[enum_from_char(roundtrip_test)]
+enum Cell {
+    #[char = '.'] Empty,
+    #[char = 'O'] Round,
+    #[char = '#'] Square,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Dish {
+    cells: Vec<Cell>,
+    width: usize,
+    height: usize,
+}
+
+impl Display for Dish {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, cell) in self.cells.iter().enumerate() {
+            write!(f, "{}", char::from(cell))?;
+            if i % self.width == self.width - 1 {
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Dish {
+    fn new(s: &str) -> Self {
+        let mut cells = vec![];
+        let mut width = 0;
+        let mut height = 0;
+        for line in s.lines() {
+            width = width.max(line.chars().count());
+            height += 1;
+            for c in line.chars().map(|c| c.try_into().unwrap()) {
+                cells.push(c);
+            }
+        }
+        Dish { cells, width, height }
+    }
+
+    fn shift(&mut self, dir: Direction) {
+        // Create a list of slice of mutable references for the row or col we want to shift
+        // Area we're shifting to should be at front of the slice
+        let slices: Vec<Vec<_>> = match dir {
+            Direction::North => {
+                (0..self.width).map(|x| {
+                    (0..self.height)
+                        .map(|y| y * self.width + x)
+                        // Each index is unique, so we can get a mut ref to each safely
+                        .map(|index| unsafe {
+                            &mut *(self.cells.get_unchecked_mut(index) as *mut _)
+                        }).collect()
+                }).collect()
+            },
+            Direction::South => {
+                (0..self.width).map(|x| {
+                    // South simply reverses column order from North
+                    (0..self.height).rev()
+                        .map(|y| y * self.width + x)
+                        // Each index is unique, so we can get a mut ref to each safely
+                        .map(|index| unsafe {
+                            &mut *(self.cells.get_unchecked_mut(index) as *mut _)
+                        }).collect()
+                }).collect()
+            },
+            Direction::West => {
+                (0..self.height).map(|y| {
+                    (y * self.width .. y * self.width + self.width)
+                        // Each index is unique, so we can get a mut ref to each safely
+                        .map(|index| unsafe {
+                            &mut *(self.cells.get_unchecked_mut(index) as *mut _)
+                        }).collect()
+                }).collect()
+            },
+            Direction::East => {
+                (0..self.height).map(|y| {
+                    // East simply reverse row order from West
+                    (y * self.width .. y * self.width + self.width).rev()
+                        // Each index is unique, so we can get a mut ref to each safely
+                        .map(|index| unsafe {
+                            &mut *(self.cells.get_unchecked_mut(index) as *mut _)
+                        }).collect()
+                }).collect()
+            },
+        };
+
+        for mut slice in slices {
+            // Split slices at square rocks
+            for sub_slice in slice.split_mut(|c| *c == &Cell::Square) {
+                // Count round rocks in each subslice
+                let round_rocks = sub_slice.iter().filter(|&c| *c == &Cell::Round).count();
+                // Place the right amount of round rocks at the front, and rest of subslice is empty
+                for (i, cell) in sub_slice.iter_mut().enumerate() {
+                    if i < round_rocks {
+                        **cell = Cell::Round;
+                    } else {
+                        **cell = Cell::Empty;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Indices of each row or column to shift, ordered from the side rocks are shifted toward.
+    fn lines(&self, dir: Direction) -> Vec<Vec<usize>> {
+        let (width, height) = (self.width, self.height);
+        match dir {
+            Direction::North => (0..width).map(|x| (0..height).map(|y| y * width + x).collect()).collect(),
+            Direction::South => (0..width).map(|x| (0..height).rev().map(|y| y * width + x).collect()).collect(),
+            Direction::West => (0..height).map(|y| (y * width..y * width + width).collect()).collect(),
+            Direction::East => (0..height).map(|y| (y * width..y * width + width).rev().collect()).collect(),
+        }
+    }
+
+    /// Same as [Dish::shift], without unsafe: each round rock is moved to the first free cell
+    /// after the last square rock or round rock before it.
+    fn shift_safe(&mut self, dir: Direction) {
+        for line in self.lines(dir) {
+            let mut free = 0;
+            for (pos, &index) in line.iter().enumerate() {
+                match self.cells[index] {
+                    Cell::Square => free = pos + 1,
+                    Cell::Round => {
+                        self.cells[index] = Cell::Empty;
+                        self.cells[line[free]] = Cell::Round;
+                        free += 1;
+                    },
+                    Cell::Empty => (),
+                }
+            }
+        }
+    }
+
+    fn spin_cycle(&mut self, shift: fn(&mut Dish, Direction)) {
+        for dir in [Direction::North, Direction::West, Direction::South, Direction::East] {
+            shift(self, dir);
+        }
+    }
+
+    fn load(&self) -> usize {
+        self.cells.iter().enumerate()
+            .filter(|(_, &c)| c == Cell::Round)
+            .map(|(i, _)| self.height - (i / self.width) )
+            .sum()
+    }
+}
+
+fn north_load(input: &str, shift: fn(&mut Dish, Direction)) -> usize {
+    let mut dish = Dish::new(input);
+    shift(&mut dish, Direction::North);
+    dish.load()
+}
+
+fn spin_cycle_load(input: &str, shift: fn(&mut Dish, Direction)) -> usize {
+    let mut dish = Dish::new(input);
+    let mut dish_map: HashMap<Dish, usize> = HashMap::new();
+    let mut cur = 0;
+    let remaining = loop {
+        dish.spin_cycle(shift);
+        cur += 1;
+        // Once the dish has been inserted into our map more than once, we found a cycle
+        // Cycle length is current iteration - the iteration it was previously inserted at
+        // Calculate many more iterations we must do for it to be equivalent to state
+        // after 1_000_000_000 iterations
+        if let Some(prev) = dish_map.insert(dish.clone(), cur) {
+            let cycle_len = cur - prev;
+            break (1_000_000_000 - cur) % cycle_len;
+        }
+        if cur == 1_000_000_000 {
+            panic!("Couldn't find a cycle");
+        }
+    };
+    for _ in 0..remaining {
+        dish.spin_cycle(shift);
+    }
+    dish.load()
+}
+
+pub struct Day14;
+impl Problem for Day14 {
+    type Solution1 = usize;
+    type Solution2 = usize;
+    type Parsed = String;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input.to_string()
+    }
+
+    fn part_1(input: &Self::Parsed) -> Self::Solution1 {
+        north_load(input, Dish::shift)
+    }
+
+    fn part_2(input: &Self::Parsed) -> Self::Solution2 {
+        spin_cycle_load(input, Dish::shift)
+    }
+}
+
+/// [Day14] using [Dish::shift_safe], for comparing against the unsafe shift.
+pub struct Day14Safe;
+impl Problem for Day14Safe {
+    type Solution1 = usize;
+    type Solution2 = usize;
+    type Parsed = String;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input.to_string()
+    }
+
+    fn part_1(input: &Self::Parsed) -> Self::Solution1 {
+        north_load(input, Dish::shift_safe)
+    }
+
+    fn part_2(input: &Self::Parsed) -> Self::Solution2 {
+        spin_cycle_load(input, Dish::shift_safe)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::{test_part_1, test_part_2};
+
+    use super::*; 
+
+    const SAMPLE: &str = "\
+        O....#....\n\
+        O.OO#....#\n\
+        .....##...\n\
+        OO.#O....O\n\
+        .O.....O#.\n\
+        O.#..O.#.#\n\
+        ..O..#O..O\n\
+        .......O..\n\
+        #....###..\n\
+        #OO..#....";
+
+    test_part_1!(Day14, SAMPLE, 136);
+
+    test_part_2!(Day14, SAMPLE, 64);
+
+    #[test]
+    fn test_safe_shift_equivalent() {
+        let shifted = "\
+            OOOO.#.O..\n\
+            OO..#....#\n\
+            OO..O##..O\n\
+            O..#.OO...\n\
+            ........#.\n\
+            ..#....#.#\n\
+            ..O..#.O.O\n\
+            ..O.......\n\
+            #....###..\n\
+            #....#....";
+        let mut dish = Dish::new(SAMPLE);
+        dish.shift_safe(Direction::North);
+        assert_eq!(dish.to_string().trim_end(), shifted);
+
+        aoc::compare::assert_equivalent::<Day14, Day14Safe>(&[SAMPLE, "#O.\n.O#\nO.O", "O"]);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,123 @@
+use anyhow::anyhow;
+use aoc::Problem;
+
+enum Label {
+    Add(String, u64),
+    Remove(String),
+}
+
+impl Label {
+    fn hash(&self) -> u64 {
+        match self {
+            Label::Add(s, _) => hash(s),
+            Label::Remove(s) => hash(s),
+        }
+    }
+}
+
+impl std::str::FromStr for Label {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, val) = s.split_once(['=', '-']).ok_or(anyhow!("Label parse error"))?;
+        match val {
+            "" => Ok(Label::Remove(name.to_string())),
+            v => Ok(Label::Add(name.to_string(), v.parse()?)),
+        }
+    }
+}
+
+struct Box(Vec<(String, u64)>);
+
+impl Box {
+    fn new() -> Box {
+        Box(vec![])
+    }
+
+    fn apply_label(&mut self, label: Label) {
+        match label {
+            Label::Add(s, v) => {
+                if let Some(index) = self.0.iter().position(|(name, _)| *name == s) {
+                    self.0[index].1 = v;
+                } else {
+                    self.0.push((s, v));
+                }
+            },
+            Label::Remove(s) => {
+                if let Some(index) = self.0.iter().position(|(name, _)| *name == s) {
+                    self.0.remove(index);
+                }
+            },
+        }
+    }
+
+    fn power(&self, box_num: u64) -> u64 {
+        self.0.iter().enumerate()
+            .map(|(i, (_, focal_len))| box_num * (i as u64 + 1) * focal_len)
+            .sum()
+    }
+}
+
+fn hash(s: &str) -> u64 {
+    s.as_bytes().iter()
+        .fold(0, |acc, &c| {
+            (acc + c as u64) * 17 % 256
+        })
+}
+
+pub struct Day15;
+impl Problem for Day15 {
+    type Solution1 = u64;
+    type Solution2 = u64;
+    type Parsed = String;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input.to_string()
+    }
+
+    fn part_1(input: &Self::Parsed) -> Self::Solution1 {
+        input.split(',')
+            .map(hash)
+            .sum()
+    }
+
+    fn part_2(input: &Self::Parsed) -> Self::Solution2 {
+        let mut boxes: [Box; 256] = core::array::from_fn(|_| Box::new());
+
+        input.split(',')
+            .map(|s| s.parse().unwrap())
+            .for_each(|label: Label| {
+                let index = label.hash() as usize;
+                boxes[index].apply_label(label);
+            });
+
+        boxes.iter().enumerate()
+            .map(|(i, b)| b.power(i as u64 + 1))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::{test_part_1, test_part_2};
+
+    use super::*; 
+
+    const SAMPLE: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+    test_part_1!(Day15, SAMPLE, 1320);
+
+    test_part_2!(Day15, SAMPLE, 145);
+
+    #[test]
+    fn test_trailing_newline() {
+        let saved = format!("{SAMPLE}\r\n");
+        // The newline would otherwise end up in the last step's hash
+        assert_ne!(Day15::part_1(&Day15::parse(&saved)), 1320);
+        assert_eq!(Day15::part_1(&Day15::parse(&aoc::input::normalize(&saved))), 1320);
+
+        let report = Day15::run(&saved);
+        assert_eq!(report.part1.unwrap().0, "1320");
+        assert_eq!(report.part2.unwrap().0, "145");
+    }
+}
\ No newline at end of file
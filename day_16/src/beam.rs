@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+
+use aoc::grid::{Direction, Grid, Point, Vector2D};
+
+use crate::Cell;
+
+/// Counts the cells energized by a beam entering the grid at `start_point`, travelling in `start_dir`.
+///
+/// The starting cell is always energized, even if it immediately redirects the beam off the grid.
+pub fn count_energized(grid: &Grid<Cell>, start_point: Point, start_dir: Direction) -> usize {
+    let mut frontier = vec![];
+    // Figure out the exit direction from the first cell
+    let origin_cell = grid.get(start_point).unwrap();
+    let origin = Vector2D::try_from(start_point).unwrap();
+    for dir in origin_cell.next_dirs(start_dir) {
+        frontier.push((origin, dir))
+    }
+
+    let mut visited: HashMap<Vector2D, Vec<Direction>> = HashMap::new();
+
+    while let Some((curr, dir)) = frontier.pop() {
+        if let Some(dir_visits) = visited.get_mut(&curr) {
+            if !dir_visits.contains(&dir) {
+                // Add the direction to the list of visited directions
+                (*dir_visits).push(dir);
+                // Add to the frontier
+                let next = curr + dir.vector();
+                if let Some(next_cell) = grid.get_signed(next) {
+                    for next_dir in next_cell.next_dirs(dir) {
+                        frontier.push((next, next_dir));
+                    }
+                }
+            }
+            // If the cell was already visited from current dir, do nothing
+        } else {
+            // Insert into the visited map
+            visited.insert(curr, vec![dir]);
+            // Add to the frontier
+            let next = curr + dir.vector();
+            if let Some(next_cell) = grid.get_signed(next) {
+                for next_dir in next_cell.next_dirs(dir) {
+                    frontier.push((next, next_dir));
+                }
+            }
+        }
+    }
+    visited.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mirror_in_corner() {
+        // Beam is turned down the first column
+        let grid: Grid<Cell> = "\\..\n...\n...".into();
+        assert_eq!(count_energized(&grid, Point { x: 0, y: 0 }, Direction::East), 3);
+
+        // Beam is immediately turned off the grid, but still energizes the start cell
+        let grid: Grid<Cell> = "/..\n...\n...".into();
+        assert_eq!(count_energized(&grid, Point { x: 0, y: 0 }, Direction::East), 1);
+    }
+
+    #[test]
+    fn test_aligned_splitter() {
+        // Splitters aligned with the beam are passed through
+        let grid: Grid<Cell> = "-..\n...".into();
+        assert_eq!(count_energized(&grid, Point { x: 0, y: 0 }, Direction::East), 3);
+
+        let grid: Grid<Cell> = "|..\n...".into();
+        assert_eq!(count_energized(&grid, Point { x: 0, y: 0 }, Direction::South), 2);
+    }
+
+    #[test]
+    fn test_single_cell() {
+        let grid: Grid<Cell> = ".".into();
+        assert_eq!(count_energized(&grid, Point { x: 0, y: 0 }, Direction::East), 1);
+
+        let grid: Grid<Cell> = "-".into();
+        assert_eq!(count_energized(&grid, Point { x: 0, y: 0 }, Direction::South), 1);
+    }
+}
@@ -0,0 +1,101 @@
+use aoc::{grid::{Direction, Grid, Point}, EnumFromChar, Problem};
+
+use beam::count_energized;
+
+mod beam;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumFromChar)]
+enum Cell {
+    #[char = '.'] Empty,
+    #[char = '/'] ForwMirror,
+    #[char = '\\'] BackMirror,
+    #[char = '-'] HorSplit,
+    #[char = '|'] VerSplit,
+}
+
+impl Cell {
+    fn next_dirs(&self, from: Direction) -> Vec<Direction> {
+        use Direction as D;
+        match (self, from) {
+            (Cell::ForwMirror, D::North) => vec![D::East],
+            (Cell::ForwMirror, D::South) => vec![D::West],
+            (Cell::ForwMirror, D::East ) => vec![D::North],
+            (Cell::ForwMirror, D::West ) => vec![D::South],
+            (Cell::BackMirror, D::North) => vec![D::West],
+            (Cell::BackMirror, D::South) => vec![D::East],
+            (Cell::BackMirror, D::East ) => vec![D::South],
+            (Cell::BackMirror, D::West ) => vec![D::North],
+            (Cell::HorSplit,   D::North) => vec![D::West, D::East],
+            (Cell::HorSplit,   D::South) => vec![D::West, D::East],
+            (Cell::VerSplit,   D::East ) => vec![D::North, D::South],
+            (Cell::VerSplit,   D::West ) => vec![D::North, D::South],
+            // In all other cases it continues on its existing path
+            (_, current) => vec![current],
+        }
+    }
+}
+
+pub struct Day16;
+impl Problem for Day16 {
+    type Solution1 = usize;
+    type Solution2 = usize;
+    type Parsed = String;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input.to_string()
+    }
+
+    fn part_1(input: &Self::Parsed) -> Self::Solution1 {
+        let grid: Grid<Cell> = input.into();
+        count_energized(&grid, Point { x: 0, y: 0 }, Direction::East)
+    }
+
+    fn part_2(input: &Self::Parsed) -> Self::Solution2 {
+        let grid: Grid<Cell> = input.into();
+        // Assemble an iterator of all entry points, corners can be entered from two directions
+        grid.border_points()
+            .flat_map(|point| {
+                [
+                    (point.y == 0, Direction::South),
+                    (point.y == grid.height() - 1, Direction::North),
+                    (point.x == 0, Direction::East),
+                    (point.x == grid.width() - 1, Direction::West),
+                ].into_iter()
+                    .filter(|(on_edge, _)| *on_edge)
+                    .map(move |(_, dir)| (point, dir))
+            })
+            // Count energized tiles and return the max
+            .map(|(start_point, start_dir)| count_energized(&grid, start_point, start_dir))
+            .max().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::{test_part_1, test_part_2};
+
+    use super::*; 
+
+    const SAMPLE: &str =
+r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....";
+
+    #[test]
+    fn test_from_ascii_matches_str() {
+        let from_str: Grid<Cell> = SAMPLE.into();
+        let from_ascii = Grid::from_ascii(SAMPLE.as_bytes(), |b| Cell::try_from(char::from(b)).ok());
+        assert_eq!(from_ascii, from_str);
+    }
+
+    test_part_1!(Day16, SAMPLE, 46);
+
+    test_part_2!(Day16, SAMPLE, 51);
+}
\ No newline at end of file
@@ -0,0 +1,3 @@
+use day_17::Day17;
+
+aoc::criterion_day!(Day17);
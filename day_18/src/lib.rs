@@ -0,0 +1,64 @@
+use aoc::{geometry::dig_area, Problem};
+use parse::{parse_line_part_1, parse_line_part_2};
+
+mod parse;
+
+pub struct Day18;
+impl Problem for Day18 {
+    type Solution1 = i64;
+    type Solution2 = i64;
+    type Parsed = String;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input.to_string()
+    }
+
+    fn part_1(input: &Self::Parsed) -> Self::Solution1 {
+        let steps: Vec<_> = input.lines().map(|line| parse_line_part_1(line).unwrap().1).collect();
+        dig_area(&steps)
+    }
+
+    fn part_2(input: &Self::Parsed) -> Self::Solution2 {
+        let steps: Vec<_> = input.lines().map(|line| parse_line_part_2(line).unwrap().1).collect();
+        dig_area(&steps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::{test_part_1, test_part_2};
+
+    use super::*; 
+
+    const SAMPLE: &str = "\
+        R 6 (#70c710)\n\
+        D 5 (#0dc571)\n\
+        L 2 (#5713f0)\n\
+        D 2 (#d2c081)\n\
+        R 2 (#59c680)\n\
+        D 2 (#411b91)\n\
+        L 5 (#8ceee2)\n\
+        U 2 (#caa173)\n\
+        L 1 (#1b58a2)\n\
+        U 2 (#caa171)\n\
+        R 2 (#7807d2)\n\
+        U 3 (#a77fa3)\n\
+        L 2 (#015232)\n\
+        U 2 (#7a21e3)";
+
+    const SAMPLE_2: &str = "\
+        D 10 (#00D8D8)\n\
+        R 3 (#00D8D8)\n\
+        U 3 (#00D8D8)\n\
+        R 4 (#00D8D8)\n\
+        D 3 (#00D8D8)\n\
+        R 5 (#00D8D8)\n\
+        U 7 (#00D8D8)\n\
+        L 6 (#00D8D8)\n\
+        U 3 (#00D8D8)\n\
+        L 6 (#00D8D8)";
+
+    test_part_1!(Day18, "sample" => SAMPLE, 62, "concave" => SAMPLE_2, 116);
+
+    test_part_2!(Day18, SAMPLE, 952408144115);
+}
\ No newline at end of file
@@ -0,0 +1,303 @@
+use std::{collections::HashMap, ops::Range};
+
+use aoc::{ranges, solve_prepared, PreparedProblem, Problem};
+
+mod parse;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Part {
+    x: u64,
+    m: u64,
+    a: u64,
+    s: u64,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct PartRange {
+    x: Range<u64>,
+    m: Range<u64>,
+    a: Range<u64>,
+    s: Range<u64>,
+}
+
+impl PartRange {
+    fn combinations(&self) -> u64 {
+        ranges::len(&self.x)
+        * ranges::len(&self.m)
+        * ranges::len(&self.a)
+        * ranges::len(&self.s)
+    }
+
+    fn contains(&self, part: &Part) -> bool {
+        self.x.contains(&part.x)
+            && self.m.contains(&part.m)
+            && self.a.contains(&part.a)
+            && self.s.contains(&part.s)
+    }
+
+    fn get(&self, attr: &Attribute) -> &Range<u64> {
+        match attr {
+            Attribute::X => &self.x,
+            Attribute::M => &self.m,
+            Attribute::A => &self.a,
+            Attribute::S => &self.s,
+        }
+    }
+
+    /// Returns a copy of the range with `attr` replaced
+    fn with(&self, attr: &Attribute, range: Range<u64>) -> PartRange {
+        let mut part_range = self.clone();
+        match attr {
+            Attribute::X => part_range.x = range,
+            Attribute::M => part_range.m = range,
+            Attribute::A => part_range.a = range,
+            Attribute::S => part_range.s = range,
+        }
+        part_range
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Attribute {
+    X,
+    M,
+    A,
+    S,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Check {
+    LessThan,
+    GreaterThan,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Res {
+    Accept,
+    Reject,
+    Send(String),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Rule(Attribute, Check, u64, Res);
+
+impl Rule {
+    fn apply(&self, part: &Part) -> Option<&Res> {
+        let Rule(attr, check, target, res) = self;
+        let val = match attr {
+            Attribute::X => part.x,
+            Attribute::M => part.m,
+            Attribute::A => part.a,
+            Attribute::S => part.s,
+        };
+        match check {
+            Check::LessThan => {
+                if val < *target { return Some(res); }
+            },
+            Check::GreaterThan => {
+                if val > *target { return Some(res); }
+            },
+        }
+        None
+    }
+
+    fn apply_range(&self, part_range: &PartRange) -> (Option<(&Res, PartRange)>, Option<PartRange>) {
+        // Splits range into accepted section and rejected section
+        // Returns result of accepted section
+        let Rule(attr, check, target, res) = self;
+        let matching = match check {
+            Check::LessThan => 0..*target,
+            Check::GreaterThan => *target+1..u64::MAX,
+        };
+
+        let val_range = part_range.get(attr);
+        let accepted = ranges::intersect(val_range, &matching)
+            .map(|accepted| (res, part_range.with(attr, accepted)));
+        // Matching ranges are unbounded on one side, so at most one piece is rejected
+        let rejected = ranges::subtract(val_range, &matching).pop()
+            .map(|rejected| part_range.with(attr, rejected));
+        (accepted, rejected)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Workflow {
+    rules: Vec<Rule>,
+    fallback: Res,
+}
+
+impl Workflow {
+    fn apply(&self, part: &Part) -> &Res {
+        self.rules.iter()
+            .find_map(|rule| rule.apply(part))
+            .unwrap_or(&self.fallback)
+    }
+
+    fn apply_range(&self, part_range: PartRange) -> Vec<(&Res, PartRange)> {
+        // Returns a set of ranges that result from applying workflow to range
+        let mut output = vec![];
+        let mut curr_range = Some(part_range);
+        for rule in &self.rules {
+            let Some(range) = curr_range else { break };
+            let (accepted, rejected) = rule.apply_range(&range);
+            output.extend(accepted);
+            curr_range = rejected;
+        }
+        output.extend(curr_range.map(|range| (&self.fallback, range)));
+        output
+    }
+}
+
+fn test_part(workflow_map: &HashMap<String, Workflow>, name: &str, part: &Part) -> Res {
+    let workflow = workflow_map.get(name).unwrap_or_else(|| panic!("Couldn't find workflow {name}"));
+    match workflow.apply(part) {
+        Res::Accept => Res::Accept,
+        Res::Reject => Res::Reject,
+        Res::Send(name) => test_part(workflow_map, name, part),
+    }
+}
+
+/// Parsed workflows and parts, shared between both parts of the problem.
+pub struct System {
+    workflow_map: HashMap<String, Workflow>,
+    parts: Vec<Part>,
+}
+
+impl System {
+    /// Returns the disjoint ranges of ratings that the workflows accept, starting from `in`.
+    fn accepted_ranges(&self) -> Vec<PartRange> {
+        let mut accepted = vec![];
+        let mut range_frontier = vec![("in", PartRange { x: 1..4001, m: 1..4001, a: 1..4001, s: 1..4001 })];
+
+        while let Some((name, part_range)) = range_frontier.pop() {
+            let workflow = self.workflow_map.get(name).unwrap_or_else(|| panic!("Couldn't find workflow {name}"));
+            for (res, out_range) in workflow.apply_range(part_range) {
+                match res {
+                    Res::Accept => accepted.push(out_range),
+                    Res::Reject => (),
+                    Res::Send(name) => { range_frontier.push((name, out_range))},
+                }
+            }
+        }
+
+        accepted
+    }
+}
+
+/// The ranges the workflows accept, worked out once so part 1 can check parts against them
+/// instead of running each part through the workflows.
+pub struct AcceptedRanges {
+    ranges: Vec<PartRange>,
+    parts: Vec<Part>,
+}
+
+pub struct Day19;
+impl Problem for Day19 {
+    type Solution1 = u64;
+    type Solution2 = u64;
+    type Parsed = System;
+
+    fn parse(input: &str) -> Self::Parsed {
+        let (workflow_str, part_str) = input.split_once("\n\n").unwrap();
+
+        let mut workflow_map = HashMap::new();
+        for line in workflow_str.lines() {
+            let (_, (name, workflow)) = parse::parse_workflow(line).unwrap();
+            workflow_map.insert(name, workflow);
+        }
+
+        let parts = part_str.lines()
+            .map(|line| parse::parse_part(line).unwrap().1)
+            .collect();
+
+        System { workflow_map, parts }
+    }
+
+    fn solve(input: &str) -> (Self::Solution1, Option<Self::Solution2>) {
+        solve_prepared::<Self>(input)
+    }
+
+    fn part_1(system: &Self::Parsed) -> Self::Solution1 {
+        system.parts.iter()
+            .filter(|part| test_part(&system.workflow_map, "in", part) == Res::Accept)
+            .map(|part| part.x + part.m + part.a + part.s )
+            .sum()
+    }
+
+    fn part_2(system: &Self::Parsed) -> Self::Solution2 {
+        Self::part_2_prepared(&Self::prepare(system))
+    }
+}
+
+impl PreparedProblem for Day19 {
+    type Intermediate = AcceptedRanges;
+
+    fn prepare(system: &Self::Parsed) -> Self::Intermediate {
+        AcceptedRanges { ranges: system.accepted_ranges(), parts: system.parts.clone() }
+    }
+
+    fn part_1_prepared(accepted: &Self::Intermediate) -> Self::Solution1 {
+        accepted.parts.iter()
+            .filter(|part| accepted.ranges.iter().any(|range| range.contains(part)))
+            .map(|part| part.x + part.m + part.a + part.s )
+            .sum()
+    }
+
+    fn part_2_prepared(accepted: &Self::Intermediate) -> Self::Solution2 {
+        accepted.ranges.iter().map(PartRange::combinations).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::{test_part_1, test_part_2};
+
+    use super::*; 
+
+    const SAMPLE: &str = "\
+        px{a<2006:qkq,m>2090:A,rfg}\n\
+        pv{a>1716:R,A}\n\
+        lnx{m>1548:A,A}\n\
+        rfg{s<537:gd,x>2440:R,A}\n\
+        qs{s>3448:A,lnx}\n\
+        qkq{x<1416:A,crn}\n\
+        crn{x>2662:A,R}\n\
+        in{s<1351:px,qqz}\n\
+        qqz{s>2770:qs,m<1801:hdj,R}\n\
+        gd{a>3333:R,R}\n\
+        hdj{m>838:A,pv}\n\
+        \n\
+        {x=787,m=2655,a=1222,s=2876}\n\
+        {x=1679,m=44,a=2067,s=496}\n\
+        {x=2036,m=264,a=79,s=2244}\n\
+        {x=2461,m=1339,a=466,s=291}\n\
+        {x=2127,m=1623,a=2188,s=1013}";
+
+    test_part_1!(Day19, SAMPLE, 19114);
+
+    test_part_2!(Day19, SAMPLE, 167409079868000);
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(Day19::solve(SAMPLE), (19114, Some(167409079868000)));
+    }
+
+    #[test]
+    fn test_prepared_matches_standalone() {
+        // Part 1 runs each part through the workflows on its own, but checks them against the
+        // accepted ranges when prepared
+        assert_eq!(Day19::solve(SAMPLE), (19114, Some(167409079868000)));
+        let system = Day19::parse(SAMPLE);
+        let accepted = Day19::prepare(&system);
+        assert_eq!(Day19::part_1_prepared(&accepted), Day19::part_1(&system));
+        assert_eq!(Day19::part_2_prepared(&accepted), Day19::part_2(&system));
+    }
+
+    #[test]
+    fn test_parsed_once_for_both_parts() {
+        let system = Day19::parse(SAMPLE);
+        assert_eq!(system.workflow_map.len(), 11);
+        assert_eq!(system.parts.len(), 5);
+        assert_eq!((Day19::part_1(&system), Day19::part_2(&system)), (19114, 167409079868000));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,318 @@
+use std::{collections::VecDeque, fmt::Display, ops::{Add, Div, Mul, Sub}, str::FromStr};
+use anyhow::{anyhow, Result};
+
+use aoc::{graph::{DiGraph, NodeIndex}, solve_prepared, PreparedProblem, Problem};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Point(i64, i64, i64);
+
+impl Point {
+    fn vec_length(&self) -> i64 {
+        self.0.max(self.1).max(self.2)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Point(self.0 + rhs.0, self.1 + rhs.1, self.2 + rhs.2)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point(self.0 - rhs.0, self.1 - rhs.1, self.2 - rhs.2)
+    }
+}
+
+impl Div<i64> for Point {
+    type Output = Point;
+
+    fn div(self, rhs: i64) -> Self::Output {
+        Point(self.0 / rhs, self.1 / rhs, self.2 / rhs)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Point(self.0 * rhs, self.1 * rhs, self.2 * rhs)
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{},{}", self.0, self.1, self.2)
+    }
+}
+
+impl FromStr for Point {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut coords = s.split(',').map(|s| s.parse());
+        let x = coords.next().ok_or(anyhow!("Not enough point components"))??;
+        let y = coords.next().ok_or(anyhow!("Not enough point components"))??;
+        let z = coords.next().ok_or(anyhow!("Not enough point components"))??;
+        Ok(Point(x, y, z))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Brick {
+    start: Point,
+    dir_vector: Point,
+    /// Position of the brick's line in the puzzle input
+    input_index: usize,
+}
+
+impl Brick {
+    /// Parses the brick on line `input_index` of the puzzle input.
+    fn parse(s: &str, input_index: usize) -> Result<Self> {
+        let (start, end) = s.split_once('~').ok_or(anyhow!("Missing delimiter '~'"))?;
+        let start = start.parse()?;
+        let dir_vector = end.parse::<Point>()? - start;
+        if dir_vector.0 < 0 || dir_vector.1 < 0 || dir_vector.2 < 0 {
+            Err(anyhow!("Brick dir vector had negative components"))
+        } else {
+            Ok(Brick { start, dir_vector, input_index })
+        }
+    }
+
+    fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        let vec_length = self.dir_vector.vec_length();
+        let unit_vec = if vec_length == 0 {
+            Point(0, 0, 0)
+        } else {
+            self.dir_vector / vec_length
+        };
+
+        (0..=vec_length).map(move |n| self.start + unit_vec * n)
+    }
+}
+
+impl Display for Brick {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}~{}", self.start, self.start + self.dir_vector)
+    }
+}
+
+/// Edges point from each brick to the bricks resting on it.
+pub type SupportGraph = DiGraph<Brick>;
+
+/// Returns the node for the brick on line `input_index` of the puzzle input
+pub fn node_for_input_index(graph: &SupportGraph, input_index: usize) -> Option<NodeIndex> {
+    graph.nodes().find(|(_, brick)| brick.input_index == input_index).map(|(i, _)| i)
+}
+
+/// Returns true if the brick can be removed without any other bricks falling
+pub fn is_nonsupporting(graph: &SupportGraph, node: NodeIndex) -> bool {
+    graph.successors(node)
+        .all(|successor| graph.count_predecessors(successor) > 1)
+}
+
+/// Return a count of blocks which can be removed without any other blocks falling
+pub fn count_nonsupporting_bricks(graph: &SupportGraph) -> usize {
+    (0..graph.node_count())
+        .filter(|i| is_nonsupporting(graph, *i))
+        .count()
+}
+
+/// Returns the number of other bricks that would fall if the brick was removed
+pub fn count_falling(graph: &SupportGraph, node: NodeIndex) -> usize {
+    let mut is_falling = vec![false; graph.node_count()];
+    let mut queue = VecDeque::new();
+    let mut count = 0;
+
+    is_falling[node] = true;
+    queue.push_back(node);
+
+    while let Some(brick) = queue.pop_front() {
+        for child in graph.successors(brick) {
+            // If it's not already falling, and all its predecessors are falling 
+            if !is_falling[child] && graph.predecessors(child).all(|pred| is_falling[pred]) {
+                // Set it to fall and add it to queue
+                is_falling[child] = true;
+                queue.push_back(child);
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+pub fn count_supported_bricks(graph: &SupportGraph) -> usize {
+    (0..graph.node_count())
+        .map(|brick| count_falling(graph, brick))
+        .sum()
+}
+
+#[derive(Debug, Clone)]
+pub struct BrickStack {
+    bricks: Vec<Brick>,
+}
+
+impl BrickStack {
+    fn new(input: &str) -> Self {
+        let mut bricks: Vec<Brick> = input.lines()
+            .enumerate()
+            .map(|(input_index, s)| Brick::parse(s, input_index).unwrap())
+            .collect();
+        // Sort bricks in ascending elevation
+        bricks.sort_unstable_by_key(|brick| brick.start.2);
+        BrickStack { bricks }
+    }
+
+    fn apply_gravity(&mut self) {
+        let mut new_bricks: Vec<Brick> = vec![];
+
+        for brick in self.bricks.iter() {
+            let brick_z = brick.start.2;
+            // println!("{brick:?}");
+            let new_z = brick.points().map(|point| {
+                (1..brick_z).rev()
+                    .find(|z| {
+                        let search_point = Point(point.0, point.1, *z);
+                        new_bricks.iter()
+                            .flat_map(|brick| brick.points())
+                            .any(|p| p == search_point)
+                    }).unwrap_or(0) + 1
+            }).max().unwrap();
+            let mut new_brick = brick.clone();
+            new_brick.start.2 = new_z;
+            // println!("{brick:?} -> {new_brick:?}");
+            new_bricks.push(new_brick);
+        }
+        // Sanity check that we didn't lose or gain any bricks
+        assert_eq!(self.bricks.len(), new_bricks.len());
+
+        self.bricks = new_bricks;
+    }
+
+    fn get_brick_at(&self, point: Point) -> Option<&Brick> {
+        self.bricks.iter().find(|brick| brick.points().any(|p| p == point))
+    }
+
+    fn get_support_graph(&self) -> SupportGraph {
+        let mut graph = SupportGraph::new();
+        // Because bricks are iterated over from bottom up, we can always be sure that supporting
+        // Bricks will already be in the graph
+        for brick in self.bricks.iter() {
+            // Add the brick to the graph
+            let node_index = graph.add_node(brick.clone());
+            // Look for any bricks underneath it, and add edges
+            brick.points()
+                .filter_map(|point| self.get_brick_at(point - Point(0, 0, 1)))
+                .filter(|support| *support != brick) // Make sure bricks can't support themselves
+                .for_each(|parent| {
+                    if let Some(parent_index) = graph.find_node(parent) {
+                        graph.add_edge(parent_index, node_index)
+                    }
+                });
+        }
+        graph
+    }
+}
+
+pub struct Day22;
+impl Problem for Day22 {
+    type Solution1 = usize;
+    type Solution2 = usize;
+    type Parsed = BrickStack;
+
+    fn parse(input: &str) -> Self::Parsed {
+        BrickStack::new(input)
+    }
+
+    fn part_1(bricks: &Self::Parsed) -> Self::Solution1 {
+        Self::part_1_prepared(&Self::prepare(bricks))
+    }
+
+    fn part_2(bricks: &Self::Parsed) -> Self::Solution2 {
+        Self::part_2_prepared(&Self::prepare(bricks))
+    }
+
+    /// Settles the bricks once for both parts.
+    fn solve(input: &str) -> (Self::Solution1, Option<Self::Solution2>) {
+        solve_prepared::<Self>(input)
+    }
+}
+
+impl PreparedProblem for Day22 {
+    type Intermediate = (BrickStack, SupportGraph);
+
+    fn prepare(bricks: &Self::Parsed) -> Self::Intermediate {
+        let mut brick_stack = bricks.clone();
+        brick_stack.apply_gravity();
+        let graph = brick_stack.get_support_graph();
+        (brick_stack, graph)
+    }
+
+    fn part_1_prepared((_, graph): &Self::Intermediate) -> Self::Solution1 {
+        count_nonsupporting_bricks(graph)
+    }
+
+    fn part_2_prepared((_, graph): &Self::Intermediate) -> Self::Solution2 {
+        count_supported_bricks(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::{test_part_1, test_part_2};
+
+    use super::*; 
+
+    const SAMPLE: &str = "\
+        1,0,1~1,2,1\n\
+        0,0,2~2,0,2\n\
+        0,2,3~2,2,3\n\
+        0,0,4~0,2,4\n\
+        2,0,5~2,2,5\n\
+        0,1,6~2,1,6\n\
+        1,1,8~1,1,9";
+
+    test_part_1!(Day22, SAMPLE, 5);
+
+    test_part_2!(Day22, SAMPLE, 7);
+
+    /// Bricks are named by input order in the puzzle description
+    fn brick_name(input_index: usize) -> char {
+        char::from(b'A' + input_index as u8)
+    }
+
+    #[test]
+    fn test_brick_display() {
+        let brick_stack = BrickStack::new(SAMPLE);
+        assert_eq!(brick_stack.bricks[0].to_string(), "1,0,1~1,2,1");
+        assert_eq!(brick_stack.bricks[6].to_string(), "1,1,8~1,1,9");
+    }
+
+    #[test]
+    fn test_bricks_by_name() {
+        let (_, graph) = Day22::prepare(&Day22::parse(SAMPLE));
+
+        let safe: Vec<char> = (0..7)
+            .filter(|&i| is_nonsupporting(&graph, node_for_input_index(&graph, i).unwrap()))
+            .map(brick_name)
+            .collect();
+        assert_eq!(safe, vec!['B', 'C', 'D', 'E', 'G']);
+
+        let falling: Vec<(char, usize)> = (0..7)
+            .map(|i| (brick_name(i), count_falling(&graph, node_for_input_index(&graph, i).unwrap())))
+            .filter(|&(_, count)| count > 0)
+            .collect();
+        assert_eq!(falling, vec![('A', 6), ('F', 1)]);
+
+        assert_eq!(node_for_input_index(&graph, 7), None);
+    }
+
+    #[test]
+    fn test_prepared_answers() {
+        assert_eq!(Day22::solve(SAMPLE), (5, Some(7)));
+    }
+}
\ No newline at end of file
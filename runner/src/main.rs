@@ -0,0 +1,402 @@
+use std::{
+    io::Read,
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc::{registry::Registry, BenchmarkReport};
+
+/// Registers each day with its input, `src/input.txt` in the day's crate. Days marked `prepared`
+/// are registered as a [aoc::PreparedProblem], so their prepare step is timed on its own.
+macro_rules! days {
+    ($( $number:literal => $( $word:ident )+ :: $problem:ident ),* $(,)?) => {{
+        let builder = Registry::builder();
+        $( let builder = day!(builder, $number => $( $word )+ :: $problem); )*
+        builder
+    }};
+}
+
+/// Registers one entry of [days].
+macro_rules! day {
+    ($builder:expr, $number:literal => prepared $krate:ident :: $problem:ident) => {
+        aoc::register_day!($builder, $number, prepared $krate::$problem, input = input_path!($krate))
+    };
+    ($builder:expr, $number:literal => $krate:ident :: $problem:ident) => {
+        aoc::register_day!($builder, $number, $krate::$problem, input = input_path!($krate))
+    };
+}
+
+/// The path of a day crate's `src/input.txt`, which sits next to the runner's crate.
+macro_rules! input_path {
+    ($krate:ident) => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../", stringify!($krate), "/src/input.txt")
+    };
+}
+
+/// Every day, with a second implementation of day 14 checked against the first by `--compare`.
+fn registry() -> Registry {
+    let builder = days![
+        1 => day_01::Day01,
+        2 => day_02::Day02,
+        3 => day_03::Day03,
+        4 => day_04::Day04,
+        5 => day_05::Day05,
+        6 => day_06::Day06,
+        7 => day_07::Day07,
+        8 => day_08::Day08,
+        9 => prepared day_09::Day09,
+        10 => day_10::Day10,
+        11 => day_11::Day11,
+        12 => day_12::Day12,
+        13 => day_13::Day13,
+        14 => day_14::Day14,
+        15 => day_15::Day15,
+        16 => day_16::Day16,
+        17 => day_17::Day17,
+        18 => day_18::Day18,
+        19 => prepared day_19::Day19,
+        20 => day_20::Day20,
+        21 => day_21::Day21,
+        22 => prepared day_22::Day22,
+    ];
+    builder.register_alternative::<day_14::Day14, day_14::Day14Safe>(14).build()
+}
+
+const USAGE: &str = "\
+Usage: runner [--day N] [--skip-slow] [--threshold-ms MS] [--compare]
+
+    --day N            Only run day N
+    --skip-slow        Skip days that take longer than the threshold, stopping them
+    --threshold-ms MS  Threshold for --skip-slow in milliseconds (default 1000)
+    --compare          Check days with two implementations give the same answers on their samples
+                       and real input, and compare their speed";
+
+#[derive(Debug, PartialEq, Eq)]
+struct Args {
+    day: Option<u8>,
+    skip_slow: bool,
+    threshold: Duration,
+    compare: bool,
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Args, String> {
+    let mut parsed = Args { day: None, skip_slow: false, threshold: Duration::from_millis(1000), compare: false };
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" => {
+                let value = args.next().ok_or("--day needs a value")?;
+                parsed.day = Some(value.parse().map_err(|_| format!("Invalid day {value:?}"))?);
+            },
+            "--skip-slow" => parsed.skip_slow = true,
+            "--compare" => parsed.compare = true,
+            "--threshold-ms" => {
+                let value = args.next().ok_or("--threshold-ms needs a value")?;
+                let ms = value.parse().map_err(|_| format!("Invalid threshold {value:?}"))?;
+                parsed.threshold = Duration::from_millis(ms);
+            },
+            _ => return Err(format!("Unknown argument {arg:?}")),
+        }
+    }
+    Ok(parsed)
+}
+
+enum Outcome {
+    /// The timed run's report, with the answers from the day's `solve`, and how long `solve` took.
+    Done(Box<BenchmarkReport>, Duration),
+    MissingInput,
+    Panicked,
+    Slow,
+}
+
+/// Hidden argument the runner re-runs itself with to solve a single day, see [solve_day].
+const SOLVE_DAY_FLAG: &str = "--solve-day";
+
+/// Line [solve_day] prints before its report, so the report can't be confused with anything the
+/// day prints itself.
+const REPORT_MARKER: &str = "--- aoc runner report ---";
+
+fn run_day(registry: &Registry, day: u8, args: &Args) -> Outcome {
+    match registry.input_path(day).map(std::fs::read_to_string) {
+        Some(Ok(input)) if !input.trim().is_empty() => (),
+        _ => return Outcome::MissingInput,
+    }
+    // Run in a separate process so a panicking day doesn't stop the others, and a slow one can
+    // be killed rather than left running alongside the days after it
+    let exe = std::env::current_exe().expect("Couldn't find the runner executable");
+    let mut child = Command::new(exe)
+        .args([SOLVE_DAY_FLAG, &day.to_string()])
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Couldn't start the runner for a day");
+    // Read output as it comes, so a day that prints a lot can't block on a full pipe
+    let mut stdout = child.stdout.take().expect("stdout is piped");
+    let reader = thread::spawn(move || {
+        let mut out = String::new();
+        let _ = stdout.read_to_string(&mut out);
+        out
+    });
+
+    let deadline = args.skip_slow.then(|| Instant::now() + args.threshold);
+    let status = loop {
+        if let Some(status) = child.try_wait().expect("Couldn't wait for a day") {
+            break Some(status);
+        }
+        if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            let _ = child.kill();
+            let _ = child.wait();
+            break None;
+        }
+        thread::sleep(Duration::from_millis(5));
+    };
+    let out = reader.join().unwrap_or_default();
+    match status {
+        None => Outcome::Slow,
+        Some(status) if status.success() => match parse_report(&out) {
+            Some((report, solve)) => Outcome::Done(Box::new(report), solve),
+            None => Outcome::Panicked,
+        },
+        Some(_) => Outcome::Panicked,
+    }
+}
+
+/// Solves `day` for [run_day] with [Registry::run], which uses the day's `solve`, then times each
+/// part with [Registry::try_run]. Prints the timed [BenchmarkReport], holding `solve`'s answers, as
+/// a line of JSON after [REPORT_MARKER], followed by the time `solve` took in nanoseconds.
+fn solve_day(registry: &Registry, day: u8) {
+    let path = registry.input_path(day).expect("Day isn't registered");
+    let input = std::fs::read_to_string(path).expect("Couldn't read input");
+    let fail = |e: &dyn std::fmt::Display| -> ! {
+        eprintln!("Day {day:02}: {e:#}");
+        std::process::exit(1);
+    };
+    let start = Instant::now();
+    let (part1, part2) = registry.run(day, &input).unwrap_or_else(|e| fail(&e));
+    let solve = start.elapsed();
+    let mut report = registry.try_run(day, &input).unwrap_or_else(|e| fail(&e));
+    // A day without part 2 has no time for it, so it stays missing
+    for (timed, answer) in [(&mut report.part1, part1), (&mut report.part2, part2)] {
+        if let Some((solution, _)) = timed {
+            *solution = answer;
+        }
+    }
+    println!("{REPORT_MARKER}\n{}\n{}", report.to_json(), solve.as_nanos());
+}
+
+/// Reads the report and the time `solve` took from the output of [solve_day], ignoring anything
+/// the day printed.
+fn parse_report(out: &str) -> Option<(BenchmarkReport, Duration)> {
+    let mut lines = out.lines().skip_while(|line| *line != REPORT_MARKER).skip(1);
+    let report = BenchmarkReport::from_json(lines.next()?).ok()?;
+    let solve = Duration::from_nanos(lines.next()?.parse().ok()?);
+    Some((report, solve))
+}
+
+/// The sample inputs shared with the `integration` tests.
+const SAMPLES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../tests/samples");
+
+/// Reads `day`'s samples from [SAMPLES_DIR], named `day_NN.txt` or `day_NN_*.txt`, in name order.
+fn sample_inputs(day: u8) -> Vec<String> {
+    let prefix = format!("day_{day:02}");
+    let Ok(entries) = std::fs::read_dir(SAMPLES_DIR) else { return Vec::new() };
+    let mut paths: Vec<_> = entries
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .filter(|path| {
+            let name = path.file_stem().and_then(|stem| stem.to_str()).unwrap_or_default();
+            path.extension().is_some_and(|ext| ext == "txt")
+                && (name == prefix || name.strip_prefix(&prefix).is_some_and(|rest| rest.starts_with('_')))
+        })
+        .collect();
+    paths.sort();
+    paths.iter()
+        .filter_map(|path| std::fs::read_to_string(path).ok())
+        .map(|sample| aoc::input::normalize(&sample).into_owned())
+        .collect()
+}
+
+/// Compares each alternative implementation on its day's samples, then its real input, returning
+/// false if any disagree.
+fn run_comparisons(registry: &Registry, args: &Args) -> bool {
+    let mut equivalent = true;
+    for (day, compare) in registry.alternatives().filter(|(day, _)| args.day.is_none_or(|n| n == *day)) {
+        let mut inputs = sample_inputs(day);
+        let samples = inputs.len();
+        match registry.input_path(day).map(std::fs::read_to_string) {
+            Some(Ok(input)) if !input.trim().is_empty() => inputs.push(aoc::input::normalize(&input).into_owned()),
+            _ if samples == 0 => {
+                println!("Day {day:02}: (no input)");
+                continue;
+            },
+            _ => println!("Day {day:02}: (no input, comparing samples only)"),
+        }
+        let inputs: Vec<&str> = inputs.iter().map(String::as_str).collect();
+        match compare(&inputs) {
+            Ok(comparison) => println!("Day {day:02}: {comparison}"),
+            Err(divergence) => {
+                println!("Day {day:02}: {divergence}");
+                equivalent = false;
+            },
+        }
+    }
+    equivalent
+}
+
+fn print_table(rows: &[[String; 7]]) {
+    let header = ["Day", "Part 1", "Time", "Part 2", "Time", "Prepare", "Total"].map(String::from);
+    let mut widths = header.clone().map(|h| h.len());
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    for row in std::iter::once(&header).chain(rows) {
+        let cells: Vec<String> = row.iter().zip(widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        println!("{}", cells.join(" | ").trim_end());
+    }
+}
+
+fn main() {
+    let cli: Vec<String> = std::env::args().skip(1).collect();
+    if let [flag, day] = cli.as_slice() {
+        if flag == SOLVE_DAY_FLAG {
+            solve_day(&registry(), day.parse().expect("Invalid day"));
+            return;
+        }
+    }
+
+    let args = match parse_args(cli) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{e}\n\n{USAGE}");
+            std::process::exit(2);
+        },
+    };
+
+    let registry = registry();
+    if args.compare {
+        let equivalent = run_comparisons(&registry, &args);
+        std::process::exit(if equivalent { 0 } else { 1 });
+    }
+
+    let rows: Vec<[String; 7]> = registry.days()
+        .filter(|day| args.day.is_none_or(|n| n == *day))
+        .map(|day| {
+            let number = format!("{day:02}");
+            let skipped = |reason: &str| [number.clone(), reason.to_string(), "".into(), "".into(), "".into(), "".into(), "".into()];
+            // A day without part 2 has no time for it
+            let part = |part: &Option<(String, Duration)>| match part {
+                Some((solution, time)) => (solution.clone(), format!("{time:.2?}")),
+                None => ("-".to_string(), String::new()),
+            };
+            match run_day(&registry, day, &args) {
+                Outcome::Done(report, solve) => {
+                    let ((part1, part1_time), (part2, part2_time)) = (part(&report.part1), part(&report.part2));
+                    // Only prepared days have a prepare step
+                    let prepare = report.prepare.map_or(String::new(), |prepare| format!("{prepare:.2?}"));
+                    // The total is the time to get both answers from `solve`, which shares work
+                    // between the parts for days that override it
+                    [number.clone(), part1, part1_time, part2, part2_time, prepare, format!("{solve:.2?}")]
+                },
+                Outcome::MissingInput => skipped("(no input)"),
+                Outcome::Panicked => skipped("(panicked)"),
+                Outcome::Slow => skipped(&format!("(skipped, over {:.2?})", args.threshold)),
+            }
+        })
+        .collect();
+
+    if rows.is_empty() {
+        eprintln!("No day matches {:?}", args.day);
+        std::process::exit(1);
+    }
+    print_table(&rows);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Result<Args, String> {
+        parse_args(args.iter().map(|s| s.to_string()))
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(args(&[]), Ok(Args { day: None, skip_slow: false, threshold: Duration::from_millis(1000), compare: false }));
+        assert_eq!(
+            args(&["--day", "7", "--skip-slow", "--threshold-ms", "250"]),
+            Ok(Args { day: Some(7), skip_slow: true, threshold: Duration::from_millis(250), compare: false }),
+        );
+        assert_eq!(args(&["--compare", "--day", "14"]).map(|args| (args.compare, args.day)), Ok((true, Some(14))));
+        assert!(args(&["--day"]).is_err());
+        assert!(args(&["--day", "x"]).is_err());
+        assert!(args(&["--fast"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_report() {
+        let report = BenchmarkReport {
+            parse: Duration::from_micros(100),
+            prepare: None,
+            parts: aoc::Parts::Both,
+            part1: Some(("136".to_string(), Duration::from_micros(500))),
+            part2: Some(("\"two\\nlines\"".to_string(), Duration::from_micros(900))),
+            part1_allocs: None,
+            part2_allocs: None,
+            part1_peak_bytes: None,
+            part2_peak_bytes: None,
+            part1_timeout: None,
+            part2_timeout: None,
+        };
+        let solve = Duration::from_micros(1200);
+        let out = format!("{REPORT_MARKER}\n{}\n1200000\n", report.to_json());
+        assert_eq!(parse_report(&out), Some((report.clone(), solve)));
+        // Anything the day prints before or after the report is ignored
+        let out = format!("step 65: 3\n{REPORT_MARKER}\n{}\n1200000\n...#\n", report.to_json());
+        assert_eq!(parse_report(&out), Some((report.clone(), solve)));
+        assert_eq!(parse_report("136\n500000\n64\n900000\n1500000\n"), None);
+        assert_eq!(parse_report(&format!("{REPORT_MARKER}\n")), None);
+        // The time solve took is required
+        assert_eq!(parse_report(&format!("{REPORT_MARKER}\n{}\n", report.to_json())), None);
+        assert_eq!(parse_report(""), None);
+    }
+
+    #[test]
+    fn test_all_days_registered() {
+        let registry = registry();
+        assert_eq!(registry.days().collect::<Vec<_>>(), (1..=22).collect::<Vec<_>>());
+        assert!(registry.days().all(|day| registry.input_path(day).is_some()));
+        let manifest = std::path::Path::new(registry.input_path(17).unwrap()).with_file_name("../Cargo.toml");
+        assert!(manifest.exists(), "{manifest:?}");
+        assert_eq!(registry.alternatives().map(|(day, _)| day).collect::<Vec<_>>(), [14]);
+        // Prepared days report their prepare step, even when there's nothing to prepare
+        assert!(registry.try_run(22, "").unwrap().prepare.is_some());
+        assert!(registry.try_run(1, "").unwrap().prepare.is_none());
+    }
+
+    #[test]
+    fn test_timed_answers_match_solve() {
+        // The runner shows the answers from Problem::solve next to the times from Registry::try_run,
+        // which must agree, including for prepared days that override solve
+        let registry = registry();
+        for day in [9, 19, 22] {
+            for input in sample_inputs(day) {
+                let report = registry.try_run(day, &input).unwrap();
+                let timed = (report.part1.unwrap().0, report.part2.unwrap().0);
+                assert_eq!(registry.run(day, &input).unwrap(), timed, "day {day}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_sample_inputs() {
+        assert_eq!(sample_inputs(14).len(), 1);
+        assert_eq!(sample_inputs(18).len(), 2);
+        assert!(sample_inputs(2).is_empty());
+        assert!(sample_inputs(14)[0].starts_with("O....#...."));
+        // Every alternative has samples to compare without the real input
+        assert!(registry().alternatives().all(|(day, _)| !sample_inputs(day).is_empty()));
+    }
+}
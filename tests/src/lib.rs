@@ -0,0 +1,107 @@
+//! Cross-day regression tests.
+//! 
+//! Runs every day that exposes its solution as a library against its sample inputs, so a change to
+//! the shared `aoc` library gets immediate feedback from the days that use it.
+//! Run with `cargo test -p integration`.
+
+#[cfg(test)]
+mod tests {
+    use aoc::Problem;
+
+    /// A sample input and its expected answer for one part of a day.
+    struct Fixture {
+        day: u8,
+        part: u8,
+        sample: &'static str,
+        expected: &'static str,
+    }
+
+    macro_rules! fixture {
+        ($day:literal, $part:literal, $sample:literal, $expected:literal) => {
+            Fixture { day: $day, part: $part, sample: include_str!(concat!("../samples/", $sample, ".txt")), expected: $expected }
+        };
+    }
+
+    const FIXTURES: &[Fixture] = &[
+        fixture!(1, 1, "day_01_part_1", "142"),
+        fixture!(1, 2, "day_01_part_2", "281"),
+        fixture!(5, 1, "day_05", "35"),
+        fixture!(5, 2, "day_05", "46"),
+        fixture!(8, 1, "day_08_part_1", "6"),
+        fixture!(8, 2, "day_08_part_2", "6"),
+        fixture!(9, 1, "day_09", "114"),
+        fixture!(9, 2, "day_09", "2"),
+        fixture!(12, 1, "day_12", "21"),
+        fixture!(12, 2, "day_12", "525152"),
+        fixture!(14, 1, "day_14", "136"),
+        fixture!(14, 2, "day_14", "64"),
+        fixture!(16, 1, "day_16", "46"),
+        fixture!(16, 2, "day_16", "51"),
+        fixture!(18, 1, "day_18", "62"),
+        fixture!(18, 1, "day_18_extra", "116"),
+        fixture!(18, 2, "day_18", "952408144115"),
+        fixture!(19, 1, "day_19", "19114"),
+        fixture!(19, 2, "day_19", "167409079868000"),
+        fixture!(22, 1, "day_22", "5"),
+        fixture!(22, 2, "day_22", "7"),
+    ];
+
+    type Solver = fn(&str) -> String;
+
+    fn part_1<P: Problem>(input: &str) -> String {
+        format!("{:?}", P::part_1(&P::parse(input)))
+    }
+
+    fn part_2<P: Problem>(input: &str) -> String {
+        format!("{:?}", P::part_2(&P::parse(input)))
+    }
+
+    /// Returns the part 1 and part 2 solvers for a day, or `None` if the day has no library target yet.
+    fn solvers(day: u8) -> Option<(Solver, Solver)> {
+        macro_rules! solvers {
+            ($problem:ty) => { Some((part_1::<$problem>, part_2::<$problem>)) };
+        }
+        match day {
+            1 => solvers!(day_01::Day01),
+            5 => solvers!(day_05::Day05),
+            8 => solvers!(day_08::Day08),
+            9 => solvers!(day_09::Day09),
+            12 => solvers!(day_12::Day12),
+            14 => solvers!(day_14::Day14),
+            16 => solvers!(day_16::Day16),
+            18 => solvers!(day_18::Day18),
+            19 => solvers!(day_19::Day19),
+            22 => solvers!(day_22::Day22),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_samples() {
+        let mut failures = vec![];
+        for fixture in FIXTURES {
+            let Some((part_1, part_2)) = solvers(fixture.day) else {
+                println!("Skipping day {}, it has no library target", fixture.day);
+                continue;
+            };
+            let solver = if fixture.part == 1 { part_1 } else { part_2 };
+            let answer = solver(fixture.sample);
+            if answer != fixture.expected {
+                failures.push(format!(
+                    "Day {} part {}: expected {}, got {}", fixture.day, fixture.part, fixture.expected, answer,
+                ));
+            }
+        }
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+
+    #[test]
+    fn test_fixture_coverage() {
+        let mut days: Vec<u8> = FIXTURES.iter()
+            .map(|fixture| fixture.day)
+            .filter(|day| solvers(*day).is_some())
+            .collect();
+        days.dedup();
+        assert!(days.len() >= 8, "Only {} days are covered", days.len());
+    }
+}